@@ -42,18 +42,12 @@ impl Cursor {
             return Token::EOF;
         };
 
-        let token = match token_type {
+        match token_type {
             TokenType::StringLiteral => {
                 let mut literal_value = String::new();
                 let mut terminated = false;
                 self.consume();
-                loop {
-                    let ch = if let Some(ch) = self.peek() {
-                        ch
-                    } else {
-                        break;
-                    };
-
+                while let Some(ch) = self.peek() {
                     if ch == '"' {
                         terminated = true;
                         self.consume();
@@ -73,13 +67,7 @@ impl Cursor {
                 let mut literal_value = String::new();
                 let mut decimal = false;
 
-                loop {
-                    let ch = if let Some(ch) = self.peek() {
-                        ch
-                    } else {
-                        break;
-                    };
-
+                while let Some(ch) = self.peek() {
                     if tokenizer::is_numeric(ch) {
                         literal_value.push(ch);
                         self.consume();
@@ -89,7 +77,7 @@ impl Cursor {
                             continue;
                         }
                         if ch == '.' {
-                            if decimal == false {
+                            if !decimal {
                                 decimal = true;
                                 literal_value.push(ch);
                                 self.consume();
@@ -111,13 +99,7 @@ impl Cursor {
             TokenType::Ident => {
                 let mut value = String::new();
 
-                loop {
-                    let ch = if let Some(ch) = self.peek() {
-                        ch
-                    } else {
-                        break;
-                    };
-
+                while let Some(ch) = self.peek() {
                     if tokenizer::is_whitespace(ch) || tokenizer::is_terminator(ch) {
                         break;
                     }
@@ -126,24 +108,16 @@ impl Cursor {
                     self.consume();
                 }
 
-                let token = if tokenizer::is_keyword(&value) {
+                if tokenizer::is_keyword(&value) {
                     Token::Keyword(value)
                 } else {
                     Token::Ident(value)
-                };
-
-                return token;
+                }
             }
             TokenType::Operator => {
                 let mut value = String::new();
 
-                loop {
-                    let ch = if let Some(ch) = self.peek() {
-                        ch
-                    } else {
-                        break;
-                    };
-
+                while let Some(ch) = self.peek() {
                     if !tokenizer::is_operator(ch) {
                         break;
                     }
@@ -152,7 +126,6 @@ impl Cursor {
                     self.consume();
                 }
 
-                println!("operator: {}", value);
                 match value.as_str() {
                     "=" => Token::Operator(OperatorKind::Assignment),
                     "==" => Token::Operator(OperatorKind::Equals),
@@ -163,19 +136,17 @@ impl Cursor {
                 self.consume();
                 Token::Semicolon
             }
-        };
-
-        return token;
+        }
     }
 
     pub fn identify_token_type(ch: char) -> TokenType {
-        return match ch {
+        match ch {
             '"' => TokenType::StringLiteral,
             ';' => TokenType::Semicolon,
             x if tokenizer::is_numeric(x) => TokenType::NumericLiteral,
             x if tokenizer::is_operator(x) => TokenType::Operator,
             _ => TokenType::Ident,
-        };
+        }
     }
 
     pub fn peek(&self) -> Option<char> {
@@ -190,7 +161,7 @@ impl Cursor {
     }
 
     pub fn step(&mut self) {
-        self.pos = self.pos + 1;
+        self.pos += 1;
     }
 }
 
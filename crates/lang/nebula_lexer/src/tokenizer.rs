@@ -15,7 +15,7 @@ pub fn tokenize(data: String) -> Vec<Token> {
         tokens.push(next_token);
     }
 
-    return tokens;
+    tokens
 }
 
 pub fn is_terminator(ch: char) -> bool {
@@ -35,5 +35,5 @@ pub fn is_operator(ch: char) -> bool {
 }
 
 pub fn is_numeric(ch: char) -> bool {
-    ch.is_digit(10)
+    ch.is_ascii_digit()
 }
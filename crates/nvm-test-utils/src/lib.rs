@@ -22,9 +22,17 @@ pub fn machine_test(_args: TokenStream, input: TokenStream) -> TokenStream {
                 let segments = &expr_path.path.segments;
                 if segments.len() == 2 && segments[0].ident == "Register" {
                     let variant = &segments[1].ident;
+                    let name = variant.to_string();
 
-                    quote! {
-                        machine.set_register(Register::#variant, #right);
+                    // EAX..EDI take the full 32bit value
+                    if name.len() == 3 && name.starts_with('E') {
+                        quote! {
+                            machine.set_register32(Register::#variant, #right);
+                        }
+                    } else {
+                        quote! {
+                            machine.set_register(Register::#variant, #right);
+                        }
                     }
                 } else if segments.len() == 2 && segments[0].ident == "Flag" {
                     let variant = &segments[1].ident;
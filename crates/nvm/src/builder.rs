@@ -0,0 +1,122 @@
+use crate::cpu::CpuModel;
+use crate::error::BuildError;
+use crate::machine::Machine;
+use crate::memory::{LinearMemory, ADDRESS_SPACE_SIZE, MEMORY_SIZE};
+use crate::register::Register;
+
+// Configures a machine before it starts running: amount of RAM, where the program
+// is placed and which register values it starts with. The stack starts at the top of RAM.
+pub struct MachineBuilder {
+    memory_size: usize,
+    program: Vec<u8>,
+    load_address: (u16, u16),
+    entry_point: Option<(u16, u16)>,
+    registers: Vec<(Register, u16)>,
+    cpu_model: CpuModel,
+}
+
+impl MachineBuilder {
+    pub fn new() -> Self {
+        Self {
+            memory_size: MEMORY_SIZE,
+            program: Vec::new(),
+            load_address: (0, Machine::DEFAULT_LOAD_OFFSET),
+            entry_point: None,
+            registers: Vec::new(),
+            cpu_model: CpuModel::default(),
+        }
+    }
+
+    pub fn memory_size(mut self, size: usize) -> Self {
+        self.memory_size = size;
+        self
+    }
+
+    pub fn program(mut self, program: impl Into<Vec<u8>>) -> Self {
+        self.program = program.into();
+        self
+    }
+
+    // SEGMENT:OFFSET the program bytes are copied to, defaults to 0000:0100
+    pub fn load_address(mut self, segment: u16, offset: u16) -> Self {
+        self.load_address = (segment, offset);
+        self
+    }
+
+    // CS:IP execution starts at, defaults to the load address
+    pub fn entry_point(mut self, segment: u16, offset: u16) -> Self {
+        self.entry_point = Some((segment, offset));
+        self
+    }
+
+    pub fn cpu_model(mut self, model: CpuModel) -> Self {
+        self.cpu_model = model;
+        self
+    }
+
+    // applied in order after the entry point, so it can also override CS and IP
+    pub fn register(mut self, register: Register, value: u16) -> Self {
+        self.registers.push((register, value));
+        self
+    }
+
+    pub fn build(self) -> Result<Machine, BuildError> {
+        if self.memory_size > ADDRESS_SPACE_SIZE {
+            return Err(BuildError::MemoryTooLarge(self.memory_size));
+        }
+
+        let (segment, offset) = self.load_address;
+        let address = ((segment as usize) << 4) + offset as usize;
+        if address + self.program.len() > self.memory_size {
+            return Err(BuildError::ProgramTooLarge {
+                address,
+                size: self.program.len(),
+                memory_size: self.memory_size,
+            });
+        }
+
+        let mut machine = Machine::with_memory(LinearMemory::new(self.memory_size));
+        machine.set_cpu_model(self.cpu_model);
+        machine.memory_mut().data[address..address + self.program.len()].copy_from_slice(&self.program);
+
+        let (cs, ip) = self.entry_point.unwrap_or(self.load_address);
+        machine.set_register(Register::CS, cs);
+        machine.set_register(Register::IP, ip);
+
+        let (ss, sp) = Self::top_of_memory(self.memory_size);
+        machine.set_register(Register::SS, ss);
+        machine.set_register(Register::SP, sp);
+
+        for (register, value) in self.registers {
+            machine.set_register(register, value);
+        }
+
+        // the first PUSH must not overwrite the program
+        let stack = Self::stack_top(machine.get_register(Register::SS), machine.get_register(Register::SP)) - 2;
+        if !self.program.is_empty() && address < stack + 2 && address + self.program.len() > stack {
+            return Err(BuildError::ProgramOverlapsStack { address, size: self.program.len(), stack });
+        }
+
+        Ok(machine)
+    }
+
+    // SS:SP pointing past the last byte of RAM, an SP of 0 wraps to 0xFFFE on the first PUSH
+    fn top_of_memory(memory_size: usize) -> (u16, u16) {
+        if memory_size >= 0x10000 {
+            (((memory_size - 0x10000) >> 4) as u16, 0)
+        } else {
+            (0, (memory_size & !1) as u16)
+        }
+    }
+
+    fn stack_top(ss: u16, sp: u16) -> usize {
+        let sp = if sp == 0 { 0x10000 } else { sp as usize };
+        ((ss as usize) << 4) + sp
+    }
+}
+
+impl Default for MachineBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
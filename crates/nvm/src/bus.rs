@@ -0,0 +1,132 @@
+use crate::error::MachineError;
+use crate::memory::LinearMemory;
+use std::ops::Range;
+
+// Anything that can be mapped into the physical address space. Addresses passed
+// to the device are relative to the start of the range it is mapped at.
+pub trait MemoryDevice {
+    fn read_byte(&mut self, address: usize) -> u8;
+
+    fn write_byte(&mut self, address: usize, value: u8);
+}
+
+impl MemoryDevice for LinearMemory {
+    fn read_byte(&mut self, address: usize) -> u8 {
+        LinearMemory::read_byte(self, address)
+    }
+
+    fn write_byte(&mut self, address: usize, value: u8) {
+        LinearMemory::write_byte(self, address, value);
+    }
+}
+
+// Read only memory, writes are silently dropped
+pub struct Rom {
+    data: Vec<u8>,
+}
+
+impl Rom {
+    pub fn new(data: Vec<u8>) -> Self {
+        Self { data }
+    }
+}
+
+impl MemoryDevice for Rom {
+    fn read_byte(&mut self, address: usize) -> u8 {
+        self.data.get(address).copied().unwrap_or(0xFF)
+    }
+
+    fn write_byte(&mut self, _address: usize, _value: u8) {}
+}
+
+// Device backed by plain read/write callbacks, handy for simple MMIO peripherals
+pub struct CallbackDevice<R, W>
+where
+    R: FnMut(usize) -> u8,
+    W: FnMut(usize, u8),
+{
+    read: R,
+    write: W,
+}
+
+impl<R, W> CallbackDevice<R, W>
+where
+    R: FnMut(usize) -> u8,
+    W: FnMut(usize, u8),
+{
+    pub fn new(read: R, write: W) -> Self {
+        Self { read, write }
+    }
+}
+
+impl<R, W> MemoryDevice for CallbackDevice<R, W>
+where
+    R: FnMut(usize) -> u8,
+    W: FnMut(usize, u8),
+{
+    fn read_byte(&mut self, address: usize) -> u8 {
+        (self.read)(address)
+    }
+
+    fn write_byte(&mut self, address: usize, value: u8) {
+        (self.write)(address, value);
+    }
+}
+
+// Physical address space. Mapped devices take precedence over RAM, the device
+// mapped first wins when ranges overlap. Accessing an address nobody answers is an error.
+pub struct MemoryBus {
+    ram: LinearMemory,
+    devices: Vec<(Range<usize>, Box<dyn MemoryDevice>)>,
+}
+
+impl MemoryBus {
+    pub fn new(ram: LinearMemory) -> Self {
+        Self {
+            ram,
+            devices: Vec::new(),
+        }
+    }
+
+    pub fn map(&mut self, range: Range<usize>, device: Box<dyn MemoryDevice>) {
+        self.devices.push((range, device));
+    }
+
+    pub fn read_byte(&mut self, address: usize) -> Result<u8, MachineError> {
+        if let Some((range, device)) = self.devices.iter_mut().find(|(range, _)| range.contains(&address)) {
+            return Ok(device.read_byte(address - range.start));
+        }
+
+        if address < self.ram.data.len() {
+            Ok(self.ram.read_byte(address))
+        } else {
+            Err(MachineError::MemoryOutOfRange(address))
+        }
+    }
+
+    pub fn write_byte(&mut self, address: usize, value: u8) -> Result<(), MachineError> {
+        if let Some((range, device)) = self.devices.iter_mut().find(|(range, _)| range.contains(&address)) {
+            device.write_byte(address - range.start, value);
+        } else if address < self.ram.data.len() {
+            self.ram.write_byte(address, value);
+        } else {
+            return Err(MachineError::MemoryOutOfRange(address));
+        }
+
+        Ok(())
+    }
+
+    pub fn ram(&self) -> &LinearMemory {
+        &self.ram
+    }
+
+    pub fn ram_mut(&mut self) -> &mut LinearMemory {
+        &mut self.ram
+    }
+}
+
+impl Default for MemoryBus {
+    fn default() -> Self {
+        Self::new(LinearMemory::default())
+    }
+}
@@ -0,0 +1,13 @@
+// Processor generation the machine emulates, decides which opcodes can be decoded
+// and what happens with the ones that cannot
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum CpuModel {
+    // opcodes added by the 80186 decode as the 8086 instruction they alias
+    I8086,
+    // undefined opcodes raise the invalid opcode fault (INT 6)
+    #[default]
+    I80186,
+    // adds EAX..EDI, the operand size prefix (66) and MOVZX/MOVSX on top of the 80186,
+    // addressing stays 16bit real mode
+    I80386,
+}
@@ -0,0 +1,112 @@
+use std::fmt;
+use std::io;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum DecodeError {
+    InvalidOpcode(u8),
+    // encoding continues past the end of the available bytes
+    TruncatedInstruction,
+    InvalidRegisterCode(u8),
+    InvalidSegmentRegisterCode(u8),
+    DuplicateSegmentOverride,
+    DuplicateRepeatPrefix,
+    // ModR/M selects a register where the instruction requires a memory operand
+    InvalidOperand,
+    // operand size prefix in front of an instruction without a 32bit form
+    InvalidOperandSizePrefix,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::InvalidOpcode(opcode) => write!(f, "Invalid opcode {:#04x}", opcode),
+            DecodeError::TruncatedInstruction => write!(f, "Instruction is truncated"),
+            DecodeError::InvalidRegisterCode(code) => write!(f, "Invalid register code {}", code),
+            DecodeError::InvalidSegmentRegisterCode(code) => write!(f, "Invalid segment register code {}", code),
+            DecodeError::DuplicateSegmentOverride => write!(f, "Duplicate segment override prefix"),
+            DecodeError::DuplicateRepeatPrefix => write!(f, "Duplicate repeat prefix"),
+            DecodeError::InvalidOperand => write!(f, "Instruction requires a memory operand"),
+            DecodeError::InvalidOperandSizePrefix => write!(f, "Operand size prefix applied to an instruction without 32bit form"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum MachineError {
+    // interrupt was raised but the IVT entry for the vector is empty (0000:0000)
+    UnhandledInterrupt(u8),
+    // bytes at CS:IP do not form a valid instruction
+    InvalidInstruction { segment: u16, offset: u16, error: DecodeError },
+    // physical address is neither backed by RAM nor by a mapped device
+    MemoryOutOfRange(usize),
+    // push or pop touched a physical address outside of memory
+    StackFault(usize),
+}
+
+impl fmt::Display for MachineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MachineError::UnhandledInterrupt(vector) => {
+                write!(f, "Unhandled interrupt {:#04x}, no handler installed in the IVT", vector)
+            }
+            MachineError::InvalidInstruction { segment, offset, error } => {
+                write!(f, "{} at {:04x}:{:04x}", error, segment, offset)
+            }
+            MachineError::MemoryOutOfRange(address) => {
+                write!(f, "Memory access at {:#07x} is out of range", address)
+            }
+            MachineError::StackFault(address) => {
+                write!(f, "Stack access at {:#07x} is out of range", address)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MachineError {}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum BuildError {
+    // requested RAM does not fit into the 20bit physical address space
+    MemoryTooLarge(usize),
+    // program loaded at `address` runs past the end of RAM
+    ProgramTooLarge { address: usize, size: usize, memory_size: usize },
+    // program covers the word the first PUSH writes to
+    ProgramOverlapsStack { address: usize, size: usize, stack: usize },
+    // program file could not be read
+    Io(io::ErrorKind),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::MemoryTooLarge(size) => {
+                write!(f, "Memory size of {} bytes exceeds the 1 MiB address space", size)
+            }
+            BuildError::ProgramTooLarge { address, size, memory_size } => {
+                write!(
+                    f,
+                    "Program of {} bytes loaded at {:#07x} does not fit into {} bytes of memory",
+                    size, address, memory_size
+                )
+            }
+            BuildError::ProgramOverlapsStack { address, size, stack } => {
+                write!(
+                    f,
+                    "Program of {} bytes loaded at {:#07x} overlaps the initial stack at {:#07x}",
+                    size, address, stack
+                )
+            }
+            BuildError::Io(kind) => write!(f, "Cannot read program: {}", kind),
+        }
+    }
+}
+
+impl std::error::Error for BuildError {}
+
+impl From<io::Error> for BuildError {
+    fn from(err: io::Error) -> Self {
+        BuildError::Io(err.kind())
+    }
+}
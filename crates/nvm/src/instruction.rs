@@ -1,15 +1,38 @@
-use crate::modrm::{decode_operand_from_single_mod_rm_opcode, decode_operands_from_mod_rm_opcode, Operand};
+use crate::cpu::CpuModel;
+use crate::error::DecodeError;
+use crate::modrm::{byte_at, decode_operand_from_single_mod_rm_opcode, decode_operands_from_mod_rm_opcode, dword_at, word_at, Operand};
 use crate::register::Register;
 
 #[allow(non_camel_case_types)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum Opcode {
     NOOP = 0x90, // NOP
-    PUSH, // 50 - 57, PUSH reg
-    POP,  // 58 - 5F, POP reg
+    PUSH = 0x50, // 50 - 57, PUSH reg
+    POP = 0x58,  // 58 - 5F, POP reg
     MOV_IMM = 0xB0, // B0 - BF, MOV reg, imm8/16 (immediate to register)
     MOV_REG_MEM = 0x88, // 88 - 8B, MOV r/m, r || MOV r, r/m
     MOV_ACC_MEM = 0xA0, // A0 - A3, MOV AL/AX <-> [imm16]
+    MOV_SREG = 0x8C, // 8C / 8E, MOV r/m16, Sreg || MOV Sreg, r/m16
+    MOV_RM_IMM = 0xC6, // C6 - C7, MOV r/m, imm8/16 (immediate to register or memory)
+    PUSH_SREG = 0x06, // 06 / 0E / 16 / 1E, PUSH ES/CS/SS/DS
+    POP_SREG = 0x07, // 07 / 17 / 1F, POP ES/SS/DS
+    PUSH_IMM_16 = 0x68, // PUSH imm16 (80186)
+    PUSH_IMM_8 = 0x6A, // PUSH imm8 (sign extended) (80186)
+    PUSHA = 0x60, // (80186)
+    POPA = 0x61, // (80186)
+    SEGMENT_OVERRIDE = 0x26, // prefix, 26 / 2E / 36 / 3E, ES:/CS:/SS:/DS:
+    OPERAND_SIZE = 0x66, // prefix, 32bit operands for the following ALU/MOV instruction (80386)
+    TWO_BYTE = 0x0F, // escape, 0F B6 / B7 MOVZX || 0F BE / BF MOVSX (80386)
+    LEA = 0x8D, // LEA r16, m
+    LES = 0xC4, // LES r16, m16:16
+    LDS = 0xC5, // LDS r16, m16:16
+    XCHG = 0x86, // 86 - 87, XCHG r/m, r
+    XCHG_ACC = 0x91, // 91 - 97, XCHG AX, r16 (90 is NOP)
+    XLAT = 0xD7, // AL = [BX + AL]
+    CBW = 0x98,
+    CWD = 0x99,
+    SAHF = 0x9E,
+    LAHF = 0x9F,
     ADD = 0x00, // 00 - 03, ADD r/m, r || ADD r, r/m
     ADD_ACC_8 = 0x04, // ADD AL, imm8
     ADD_ACC_16 = 0x05, // ADD AX, imm16
@@ -18,8 +41,22 @@ pub enum Opcode {
     SUB_ACC_16 = 0x2D, // SUB AX, imm16
     INC = 0x40, // 40 - 47, INC r
     DEC = 0x48, // 48 - 4F, DEC r
-    MUL_DIV_8 = 0xF6, // MUL/DIV BYTE r/m ----- DIV if reg part (bit 2-4) of r/m is 0b110
-    MUL_DIV_16 = 0xF7, // MUL/DIV BYTE r/m ----- DIV if reg part (bit 2-4) of r/m is 0b110
+    SHIFT_8 = 0xD0, // D0 / D2, ROL/ROR/RCL/RCR/SHL/SHR/SAR r/m8, 1 || r/m8, CL ----- selected by reg part (bit 2-4) of r/m
+    SHIFT_16 = 0xD1, // D1 / D3, ROL/ROR/RCL/RCR/SHL/SHR/SAR r/m16, 1 || r/m16, CL
+    SHIFT_IMM_8 = 0xC0, // ROL/ROR/RCL/RCR/SHL/SHR/SAR r/m8, imm8 (80186)
+    SHIFT_IMM_16 = 0xC1, // ROL/ROR/RCL/RCR/SHL/SHR/SAR r/m16, imm8 (80186)
+    IMUL_IMM_16 = 0x69, // IMUL r16, r/m16, imm16 (80186)
+    IMUL_IMM_8 = 0x6B, // IMUL r16, r/m16, imm8 (sign extended) (80186)
+    DAA = 0x27,
+    DAS = 0x2F,
+    AAA = 0x37,
+    AAS = 0x3F,
+    AAM = 0xD4, // AAM imm8 (base, 0A for decimal)
+    AAD = 0xD5, // AAD imm8 (base, 0A for decimal)
+    MUL_DIV_8 = 0xF6, // TEST/NOT/NEG/MUL/IMUL/DIV/IDIV BYTE r/m ----- selected by reg part (bit 2-4) of r/m
+    MUL_DIV_16 = 0xF7, // TEST/NOT/NEG/MUL/IMUL/DIV/IDIV WORD r/m ----- selected by reg part (bit 2-4) of r/m
+    INC_DEC_8 = 0xFE, // INC/DEC BYTE r/m ----- selected by reg part (bit 2-4) of r/m
+    INC_DEC_16 = 0xFF, // INC/DEC/CALL/CALL FAR/JMP/JMP FAR/PUSH WORD r/m ----- selected by reg part (bit 2-4) of r/m
 
     AND = 0x20, // 20 - 23, AND r/m, r || AND r, r/m
     AND_ACC_8 = 0x24, // AND AL, imm8
@@ -27,61 +64,364 @@ pub enum Opcode {
     OR = 0x08, // 08 - 0B, OR r/m, r || OR r, r/m
     OR_ACC_8 = 0x0C, // OR AL, imm8
     OR_ACC_16 = 0x0D, // OR AX, imm16
+    ALU_IMM_8 = 0x80, // 80 / 82, ADD/OR/ADC/SBB/AND/SUB/XOR/CMP r/m8, imm8 ----- selected by reg part (bit 2-4) of r/m
+    ALU_IMM_16 = 0x81, // ADD/OR/ADC/SBB/AND/SUB/XOR/CMP r/m16, imm16
+    ALU_IMM_16_SIGN_EXT = 0x83, // ADD/OR/ADC/SBB/AND/SUB/XOR/CMP r/m16, imm8 (sign extended)
+    XOR = 0x30, // 30 - 33, XOR r/m, r || XOR r, r/m
+    XOR_ACC_8 = 0x34, // XOR AL, imm8
+    XOR_ACC_16 = 0x35, // XOR AX, imm16
+    ADC = 0x10, // 10 - 13, ADC r/m, r || ADC r, r/m
+    ADC_ACC_8 = 0x14, // ADC AL, imm8
+    ADC_ACC_16 = 0x15, // ADC AX, imm16
+    SBB = 0x18, // 18 - 1B, SBB r/m, r || SBB r, r/m
+    SBB_ACC_8 = 0x1C, // SBB AL, imm8
+    SBB_ACC_16 = 0x1D, // SBB AX, imm16
+    CMP = 0x38, // 38 - 3B, CMP r/m, r || CMP r, r/m
+    CMP_ACC_8 = 0x3C, // CMP AL, imm8
+    CMP_ACC_16 = 0x3D, // CMP AX, imm16
+    TEST = 0x84, // 84 - 85, TEST r/m, r
+    TEST_ACC_8 = 0xA8, // TEST AL, imm8
+    TEST_ACC_16 = 0xA9, // TEST AX, imm16
+
+    CALL = 0xE8, // CALL rel16
+    CALL_FAR = 0x9A, // CALL ptr16:16
+    RET = 0xC3,
+    RET_IMM = 0xC2, // RET imm16
+    RETF = 0xCB,
+    RETF_IMM = 0xCA, // RETF imm16
+    ENTER = 0xC8, // ENTER imm16, imm8 (80186)
+    LEAVE = 0xC9, // (80186)
+    BOUND = 0x62, // BOUND r16, m16:16 (80186)
+
+    MOVS = 0xA4, // A4 - A5, MOVSB || MOVSW
+    CMPS = 0xA6, // A6 - A7, CMPSB || CMPSW
+    STOS = 0xAA, // AA - AB, STOSB || STOSW
+    LODS = 0xAC, // AC - AD, LODSB || LODSW
+    SCAS = 0xAE, // AE - AF, SCASB || SCASW
+    INS = 0x6C, // 6C - 6D, INSB || INSW (80186)
+    OUTS = 0x6E, // 6E - 6F, OUTSB || OUTSW (80186)
+    REPNE = 0xF2, // prefix, REPNE/REPNZ
+    REP = 0xF3, // prefix, REP/REPE/REPZ
+
+    IN_IMM = 0xE4, // E4 - E5, IN AL/AX, imm8
+    OUT_IMM = 0xE6, // E6 - E7, OUT imm8, AL/AX
+    IN_DX = 0xEC, // EC - ED, IN AL/AX, DX
+    OUT_DX = 0xEE, // EE - EF, OUT DX, AL/AX
+
+    INT3 = 0xCC,
+    INT = 0xCD, // INT imm8
+    INTO = 0xCE,
+    IRET = 0xCF,
+
+    HLT = 0xF4,
+
+    CMC = 0xF5,
+    CLC = 0xF8,
+    STC = 0xF9,
+    CLI = 0xFA,
+    STI = 0xFB,
+    CLD = 0xFC,
+    STD = 0xFD,
+    PUSHF = 0x9C,
+    POPF = 0x9D,
 
     JMP = 0xE9,
     JMP_FAR = 0xEA,
     JMP_SHORT = 0xEB,
+    JO = 0x70,
+    JNO = 0x71,
+    JC = 0x72, // JC/JB/JNAE
+    JNC = 0x73, // JNC/JNB/JAE
     JZ = 0x74,
     JNZ = 0x75,
+    JBE = 0x76, // JBE/JNA
+    JA = 0x77, // JA/JNBE
+    JS = 0x78,
+    JNS = 0x79,
+    JP = 0x7A, // JP/JPE
+    JNP = 0x7B, // JNP/JPO
+    JL = 0x7C, // JL/JNGE
+    JGE = 0x7D, // JGE/JNL
+    JLE = 0x7E, // JLE/JNG
+    JG = 0x7F, // JG/JNLE
+    LOOPNZ = 0xE0, // LOOPNZ/LOOPNE
+    LOOPZ = 0xE1, // LOOPZ/LOOPE
+    LOOP = 0xE2,
+    JCXZ = 0xE3,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum MovMemOperand {
     Register(Register),
     MemoryPtr(u16),
+    // [Sreg:imm16], set by a segment override prefix
+    SegmentedMemoryPtr(Register, u16),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ShiftCount {
+    One,
+    CL,
+    Immediate(u8),
+}
+
+impl MovMemOperand {
+    // bytes added in front of the opcode, the operand size or segment override prefix
+    fn prefix_size(&self) -> u16 {
+        match self {
+            Self::Register(reg) => reg.is_32bit() as u16,
+            Self::MemoryPtr(_) => 0,
+            Self::SegmentedMemoryPtr(..) => 1,
+        }
+    }
+}
+
+impl ShiftCount {
+    fn encoded_size(&self) -> u16 {
+        match self {
+            Self::Immediate(_) => 1,
+            Self::One | Self::CL => 0,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum IoPort {
+    Immediate(u8),
+    DX,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum RepPrefix {
+    // F3, REP for MOVS/STOS/LODS, REPE/REPZ for CMPS/SCAS
+    Rep,
+    // F2, REPNE/REPNZ
+    Repne,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub enum Instruction {
+    // prefixes the instruction has no use for, like a segment override without
+    // memory operand. The CPU skips them, only their bytes count towards the size
+    IgnoredPrefixes(u16, Box<Instruction>),
     Noop,
     MovImm8(Register, u8),
     MovImm16(Register, u16),
+    MovImm32(Register, u32),
     // MOV between register and memory or register <-> register
     //  DEST      , SRC
     Mov(Operand, Operand),
-    // MOV AL/AX <-> [imm16]
+    Mov32(Operand, Operand),
+    // DEST is 16 or 32bit, the bool tells whether SRC is 8bit
+    Movzx(Register, Operand, bool),
+    Movsx(Register, Operand, bool),
+    // MOV AL/AX/EAX <-> [imm16]
     //  DEST      , SRC
     MovAccMem(MovMemOperand, MovMemOperand),
+    // PUSH/POP EAX.. take two words of stack
     Push(Register),
     Pop(Register),
+    // PUSH r/m16
+    PushRm(Operand),
+    // imm8 is sign extended
+    PushImm8(u8),
+    PushImm16(u16),
+    Pusha,
+    Popa,
+    // offset of the memory operand, memory is not accessed
+    Lea(Register, Operand),
+    // far pointer load, register gets the offset, DS/ES the segment
+    Lds(Register, Operand),
+    Les(Register, Operand),
+    Xchg(Operand, Operand, bool),
+    Xchg32(Operand, Operand),
+    // XCHG AX, reg or XCHG EAX, reg
+    XchgAcc(Register),
+    // segment override
+    Xlat(Option<Register>),
+    Cbw,
+    Cwd,
+    Cwde,
+    Cdq,
+    Lahf,
+    Sahf,
     Add(Operand, Operand, bool),
     AddAcc8(u8),
     AddAcc16(u16),
+    // 32bit forms are selected by the operand size prefix
+    Add32(Operand, Operand),
+    AddAcc32(u32),
     Sub(Operand, Operand, bool),
     SubAcc8(u8),
     SubAcc16(u16),
+    Sub32(Operand, Operand),
+    SubAcc32(u32),
     Inc(Register),
     Dec(Register),
+    Inc8(Operand),
+    Inc16(Operand),
+    Dec8(Operand),
+    Dec16(Operand),
+    Inc32(Operand),
+    Dec32(Operand),
+    Daa,
+    Das,
+    Aaa,
+    Aas,
+    Aam(u8),
+    Aad(u8),
     Mul8(Operand),
     Mul16(Operand),
     Div8(Operand),
     Div16(Operand),
-    And(Operand, Operand),
+    Imul8(Operand),
+    Imul16(Operand),
+    // DEST     , SRC    , imm8/imm16
+    ImulImm(Register, Operand, Operand),
+    Idiv8(Operand),
+    Idiv16(Operand),
+    // EDX:EAX
+    Mul32(Operand),
+    Div32(Operand),
+    Imul32(Operand),
+    Idiv32(Operand),
+    And(Operand, Operand, bool),
     AndAcc8(u8),
     AndAcc16(u16),
-    Or(Operand, Operand),
+    And32(Operand, Operand),
+    AndAcc32(u32),
+    Or(Operand, Operand, bool),
     OrAcc8(u8),
     OrAcc16(u16),
+    Or32(Operand, Operand),
+    OrAcc32(u32),
+    Xor(Operand, Operand, bool),
+    XorAcc8(u8),
+    XorAcc16(u16),
+    Xor32(Operand, Operand),
+    XorAcc32(u32),
+    Adc(Operand, Operand, bool),
+    AdcAcc8(u8),
+    AdcAcc16(u16),
+    Adc32(Operand, Operand),
+    AdcAcc32(u32),
+    Sbb(Operand, Operand, bool),
+    SbbAcc8(u8),
+    SbbAcc16(u16),
+    Sbb32(Operand, Operand),
+    SbbAcc32(u32),
+    Cmp(Operand, Operand, bool),
+    CmpAcc8(u8),
+    CmpAcc16(u16),
+    Cmp32(Operand, Operand),
+    CmpAcc32(u32),
+    Test(Operand, Operand, bool),
+    TestAcc8(u8),
+    TestAcc16(u16),
+    Test32(Operand, Operand),
+    TestAcc32(u32),
+    Not8(Operand),
+    Not16(Operand),
+    Neg8(Operand),
+    Neg16(Operand),
+    Not32(Operand),
+    Neg32(Operand),
+    Rol(Operand, ShiftCount, bool),
+    Ror(Operand, ShiftCount, bool),
+    Rcl(Operand, ShiftCount, bool),
+    Rcr(Operand, ShiftCount, bool),
+    Shl(Operand, ShiftCount, bool),
+    Shr(Operand, ShiftCount, bool),
+    Sar(Operand, ShiftCount, bool),
+    Rol32(Operand, ShiftCount),
+    Ror32(Operand, ShiftCount),
+    Rcl32(Operand, ShiftCount),
+    Rcr32(Operand, ShiftCount),
+    Shl32(Operand, ShiftCount),
+    Shr32(Operand, ShiftCount),
+    Sar32(Operand, ShiftCount),
+    // string instructions operate on [SI] and/or [DI]
+    // segment override only applies to the DS:SI source, ES:DI cannot be overridden
+    Movs(Option<RepPrefix>, Option<Register>, bool),
+    Cmps(Option<RepPrefix>, Option<Register>, bool),
+    Stos(Option<RepPrefix>, Option<Register>, bool),
+    Lods(Option<RepPrefix>, Option<Register>, bool),
+    Scas(Option<RepPrefix>, Option<Register>, bool),
+    // port is always DX
+    Ins(Option<RepPrefix>, Option<Register>, bool),
+    Outs(Option<RepPrefix>, Option<Register>, bool),
+    CallNear(i16),
+    //       SEGMENT, OFFSET
+    CallFar(u16, u16),
+    // target offset is read from the operand
+    CallNearIndirect(Operand),
+    // target offset and segment are read from the memory operand
+    CallFarIndirect(Operand),
+    Ret,
+    // number of bytes released from the stack after popping the return address
+    RetImm(u16),
+    RetFar,
+    RetFarImm(u16),
+    //    FRAME SIZE, NESTING LEVEL
+    Enter(u16, u8),
+    Leave,
+    // signed array index check against the lower and upper bound stored in memory
+    Bound(Register, Operand),
+    // port, is_8bit
+    In(IoPort, bool),
+    Out(IoPort, bool),
+    Int(u8),
+    Int3,
+    Into,
+    Iret,
+    Hlt,
+    Cmc,
+    Clc,
+    Stc,
+    Cli,
+    Sti,
+    Cld,
+    Std,
+    Pushf,
+    Popf,
     JmpNear(i16),
     JmpFar(u16, u16),
+    JmpNearIndirect(Operand),
+    JmpFarIndirect(Operand),
     JmpShort(i8),
+    Loopnz(i8),
+    Loopz(i8),
+    Loop(i8),
+    Jcxz(i8),
+    Jo(i8),
+    Jno(i8),
+    Jc(i8),
+    Jnc(i8),
     Jz(i8),
     Jnz(i8),
+    Jbe(i8),
+    Ja(i8),
+    Js(i8),
+    Jns(i8),
+    Jp(i8),
+    Jnp(i8),
+    Jl(i8),
+    Jge(i8),
+    Jle(i8),
+    Jg(i8),
 }
 
 impl Instruction {
-    pub fn from_bytes(opcode_byte: u8, memory_slice: &[u8]) -> Result<Self, String> {
-        let opcode = Opcode::try_from(opcode_byte)?;
+    pub fn from_bytes(opcode_byte: u8, memory_slice: &[u8]) -> Result<Self, DecodeError> {
+        Self::decode(opcode_byte, memory_slice, CpuModel::default())
+    }
+
+    pub fn decode(opcode_byte: u8, memory_slice: &[u8], model: CpuModel) -> Result<Self, DecodeError> {
+        let opcode = Opcode::from_byte(opcode_byte, model)?;
+        let opcode_byte = match model {
+            CpuModel::I8086 => Opcode::alias_8086(opcode_byte),
+            _ => opcode_byte,
+        };
 
         match opcode {
             Opcode::NOOP => Ok(Self::Noop),
@@ -91,12 +431,9 @@ impl Instruction {
                 let reg = Register::from_register_code(reg_bits, bits_8)?;
 
                 let instr = if bits_8 {
-                    let val = memory_slice[0];
-                    Self::MovImm8(reg, val)
+                    Self::MovImm8(reg, byte_at(memory_slice, 0)?)
                 } else {
-                    let upper = memory_slice[1] as u16;
-                    let lower = memory_slice[0] as u16;
-                    Self::MovImm16(reg, (upper << 8) | lower)
+                    Self::MovImm16(reg, word_at(memory_slice, 0)?)
                 };
 
                 Ok(instr)
@@ -115,16 +452,98 @@ impl Instruction {
                     Register::AX
                 };
 
-                let mem_ptr = ((memory_slice[1] as u16) << 8) | memory_slice[0] as u16;
+                let mem_ptr = word_at(memory_slice, 0)?;
                 if is_reg_target {
                     Ok(Self::MovAccMem(MovMemOperand::Register(register), MovMemOperand::MemoryPtr(mem_ptr)))
                 } else {
                     Ok(Self::MovAccMem(MovMemOperand::MemoryPtr(mem_ptr), MovMemOperand::Register(register)))
                 }
             }
+            Opcode::MOV_SREG => {
+                let is_sreg_target = opcode_byte & 0b00000010 != 0;
+                let sreg = Register::from_segment_register_code((byte_at(memory_slice, 0)? & 0b00011000) >> 3)?;
+                let operand = decode_operand_from_single_mod_rm_opcode(memory_slice, false)?;
+
+                if is_sreg_target {
+                    Ok(Self::Mov(Operand::Register(sreg), operand))
+                } else {
+                    Ok(Self::Mov(operand, Operand::Register(sreg)))
+                }
+            }
+            Opcode::MOV_RM_IMM => {
+                // the reg part of the ModR/M byte is ignored
+                let is_8bit = opcode_byte & 0b00000001 == 0;
+                let dest = decode_operand_from_single_mod_rm_opcode(memory_slice, is_8bit)?;
+
+                let imm_offset = 1 + dest.encoded_size() as usize;
+                let imm = if is_8bit {
+                    Operand::Immediate8(byte_at(memory_slice, imm_offset)?)
+                } else {
+                    Operand::Immediate16(word_at(memory_slice, imm_offset)?)
+                };
+
+                Ok(Self::Mov(dest, imm))
+            }
+            Opcode::PUSH_SREG => {
+                Ok(Self::Push(Register::from_segment_register_code((opcode_byte & 0b00011000) >> 3)?))
+            }
+            Opcode::POP_SREG => {
+                Ok(Self::Pop(Register::from_segment_register_code((opcode_byte & 0b00011000) >> 3)?))
+            }
+            Opcode::SEGMENT_OVERRIDE => {
+                let segment = Register::from_segment_register_code((opcode_byte & 0b00011000) >> 3)?;
+                Self::decode(byte_at(memory_slice, 0)?, &memory_slice[1..], model)?.with_segment_override(segment)
+            }
+            Opcode::OPERAND_SIZE => {
+                Self::decode(byte_at(memory_slice, 0)?, &memory_slice[1..], model)?.with_operand_size_32(memory_slice)
+            }
+            Opcode::TWO_BYTE => {
+                let extended_opcode = byte_at(memory_slice, 0)?;
+                let memory_slice = &memory_slice[1..];
+                if !matches!(extended_opcode, 0xB6 | 0xB7 | 0xBE | 0xBF) {
+                    return Err(DecodeError::InvalidOpcode(opcode_byte));
+                }
+
+                let is_8bit = extended_opcode & 0b00000001 == 0;
+                let reg = Register::from_register_code((byte_at(memory_slice, 0)? & 0b00111000) >> 3, false)?;
+                let src = decode_operand_from_single_mod_rm_opcode(memory_slice, is_8bit)?;
+
+                if extended_opcode & 0b00001000 == 0 {
+                    Ok(Self::Movzx(reg, src, is_8bit))
+                } else {
+                    Ok(Self::Movsx(reg, src, is_8bit))
+                }
+            }
+            Opcode::LEA | Opcode::LDS | Opcode::LES => {
+                let operand = decode_operand_from_single_mod_rm_opcode(memory_slice, false)?;
+                let reg = Register::from_register_code((byte_at(memory_slice, 0)? & 0b00111000) >> 3, false)?;
+
+                match (opcode, operand) {
+                    (_, Operand::Register(_)) => Err(DecodeError::InvalidOperand),
+                    (Opcode::LEA, _) => Ok(Self::Lea(reg, operand)),
+                    (Opcode::LDS, _) => Ok(Self::Lds(reg, operand)),
+                    _ => Ok(Self::Les(reg, operand)),
+                }
+            }
+            Opcode::XCHG => {
+                let (left, right, is_8bit) = decode_operands_from_mod_rm_opcode(opcode_byte, memory_slice)?;
+                Ok(Self::Xchg(left, right, is_8bit))
+            }
+            Opcode::XCHG_ACC => {
+                Ok(Self::XchgAcc(Register::from_register_code(opcode_byte & 0b00000111, false)?))
+            }
+            Opcode::XLAT => Ok(Self::Xlat(None)),
+            Opcode::CBW => Ok(Self::Cbw),
+            Opcode::CWD => Ok(Self::Cwd),
+            Opcode::SAHF => Ok(Self::Sahf),
+            Opcode::LAHF => Ok(Self::Lahf),
             Opcode::PUSH => {
                 Ok(Self::Push(Register::from_register_code(opcode_byte & 0b00000111, false)?))
             }
+            Opcode::PUSH_IMM_8 => Ok(Self::PushImm8(byte_at(memory_slice, 0)?)),
+            Opcode::PUSH_IMM_16 => Ok(Self::PushImm16(word_at(memory_slice, 0)?)),
+            Opcode::PUSHA => Ok(Self::Pusha),
+            Opcode::POPA => Ok(Self::Popa),
             Opcode::POP => {
                 Ok(Self::Pop(Register::from_register_code(opcode_byte & 0b00000111, false)?))
             }
@@ -133,10 +552,10 @@ impl Instruction {
                 Ok(Self::Add(left, right, is_8bit))
             }
             Opcode::ADD_ACC_8 => {
-                Ok(Self::AddAcc8(memory_slice[0]))
+                Ok(Self::AddAcc8(byte_at(memory_slice, 0)?))
             }
             Opcode::ADD_ACC_16 => {
-                let val = (memory_slice[1] as u16) << 8 | memory_slice[0] as u16;
+                let val = word_at(memory_slice, 0)?;
                 Ok(Self::AddAcc16(val))
             }
             Opcode::SUB => {
@@ -144,10 +563,10 @@ impl Instruction {
                 Ok(Self::Sub(left, right, is_8bit))
             }
             Opcode::SUB_ACC_8 => {
-                Ok(Self::SubAcc8(memory_slice[0]))
+                Ok(Self::SubAcc8(byte_at(memory_slice, 0)?))
             }
             Opcode::SUB_ACC_16 => {
-                let val = (memory_slice[1] as u16) << 8 | memory_slice[0] as u16;
+                let val = word_at(memory_slice, 0)?;
                 Ok(Self::SubAcc16(val))
             }
             Opcode::INC => {
@@ -159,133 +578,839 @@ impl Instruction {
                 Ok(Self::Dec(Register::from_register_code(reg_bits, false)?))
             }
             Opcode::AND => {
-                let operands = decode_operands_from_mod_rm_opcode(opcode_byte, memory_slice)?;
-                Ok(Self::And(operands.0, operands.1))
+                let (left, right, is_8bit) = decode_operands_from_mod_rm_opcode(opcode_byte, memory_slice)?;
+                Ok(Self::And(left, right, is_8bit))
             }
             Opcode::AND_ACC_8 => {
-                Ok(Self::AndAcc8(memory_slice[0]))
+                Ok(Self::AndAcc8(byte_at(memory_slice, 0)?))
             }
             Opcode::AND_ACC_16 => {
-                let val = (memory_slice[1] as u16) << 8 | memory_slice[0] as u16;
+                let val = word_at(memory_slice, 0)?;
                 Ok(Self::AndAcc16(val))
             }
             Opcode::OR => {
-                let operands = decode_operands_from_mod_rm_opcode(opcode_byte, memory_slice)?;
-                Ok(Self::Or(operands.0, operands.1))
+                let (left, right, is_8bit) = decode_operands_from_mod_rm_opcode(opcode_byte, memory_slice)?;
+                Ok(Self::Or(left, right, is_8bit))
             }
             Opcode::OR_ACC_8 => {
-                Ok(Self::OrAcc8(memory_slice[0]))
+                Ok(Self::OrAcc8(byte_at(memory_slice, 0)?))
             }
             Opcode::OR_ACC_16 => {
-                let val = (memory_slice[1] as u16) << 8 | memory_slice[0] as u16;
+                let val = word_at(memory_slice, 0)?;
                 Ok(Self::OrAcc16(val))
             }
+            Opcode::XOR => {
+                let (left, right, is_8bit) = decode_operands_from_mod_rm_opcode(opcode_byte, memory_slice)?;
+                Ok(Self::Xor(left, right, is_8bit))
+            }
+            Opcode::XOR_ACC_8 => {
+                Ok(Self::XorAcc8(byte_at(memory_slice, 0)?))
+            }
+            Opcode::XOR_ACC_16 => {
+                let val = word_at(memory_slice, 0)?;
+                Ok(Self::XorAcc16(val))
+            }
+            Opcode::ADC => {
+                let (left, right, is_8bit) = decode_operands_from_mod_rm_opcode(opcode_byte, memory_slice)?;
+                Ok(Self::Adc(left, right, is_8bit))
+            }
+            Opcode::ADC_ACC_8 => {
+                Ok(Self::AdcAcc8(byte_at(memory_slice, 0)?))
+            }
+            Opcode::ADC_ACC_16 => {
+                let val = word_at(memory_slice, 0)?;
+                Ok(Self::AdcAcc16(val))
+            }
+            Opcode::SBB => {
+                let (left, right, is_8bit) = decode_operands_from_mod_rm_opcode(opcode_byte, memory_slice)?;
+                Ok(Self::Sbb(left, right, is_8bit))
+            }
+            Opcode::SBB_ACC_8 => {
+                Ok(Self::SbbAcc8(byte_at(memory_slice, 0)?))
+            }
+            Opcode::SBB_ACC_16 => {
+                let val = word_at(memory_slice, 0)?;
+                Ok(Self::SbbAcc16(val))
+            }
+            Opcode::CMP => {
+                let (left, right, is_8bit) = decode_operands_from_mod_rm_opcode(opcode_byte, memory_slice)?;
+                Ok(Self::Cmp(left, right, is_8bit))
+            }
+            Opcode::CMP_ACC_8 => {
+                Ok(Self::CmpAcc8(byte_at(memory_slice, 0)?))
+            }
+            Opcode::CMP_ACC_16 => {
+                let val = word_at(memory_slice, 0)?;
+                Ok(Self::CmpAcc16(val))
+            }
+            Opcode::TEST => {
+                let (left, right, is_8bit) = decode_operands_from_mod_rm_opcode(opcode_byte, memory_slice)?;
+                Ok(Self::Test(left, right, is_8bit))
+            }
+            Opcode::TEST_ACC_8 => {
+                Ok(Self::TestAcc8(byte_at(memory_slice, 0)?))
+            }
+            Opcode::TEST_ACC_16 => {
+                let val = word_at(memory_slice, 0)?;
+                Ok(Self::TestAcc16(val))
+            }
+            Opcode::ALU_IMM_8 | Opcode::ALU_IMM_16 | Opcode::ALU_IMM_16_SIGN_EXT => {
+                let is_8bit = opcode == Opcode::ALU_IMM_8;
+                let dest = decode_operand_from_single_mod_rm_opcode(memory_slice, is_8bit)?;
+
+                // The immediate follows the ModR/M byte and the displacement
+                let imm_offset = 1 + dest.encoded_size() as usize;
+                let imm = if opcode == Opcode::ALU_IMM_16 {
+                    let val = word_at(memory_slice, imm_offset)?;
+                    Operand::Immediate16(val)
+                } else {
+                    Operand::Immediate8(byte_at(memory_slice, imm_offset)?)
+                };
+
+                match byte_at(memory_slice, 0)? & 0b00111000 {
+                    0b00000000 => Ok(Self::Add(dest, imm, is_8bit)),
+                    0b00001000 => Ok(Self::Or(dest, imm, is_8bit)),
+                    0b00010000 => Ok(Self::Adc(dest, imm, is_8bit)),
+                    0b00011000 => Ok(Self::Sbb(dest, imm, is_8bit)),
+                    0b00100000 => Ok(Self::And(dest, imm, is_8bit)),
+                    0b00101000 => Ok(Self::Sub(dest, imm, is_8bit)),
+                    0b00110000 => Ok(Self::Xor(dest, imm, is_8bit)),
+                    _ => Ok(Self::Cmp(dest, imm, is_8bit)),
+                }
+            }
+            Opcode::SHIFT_8 | Opcode::SHIFT_16 | Opcode::SHIFT_IMM_8 | Opcode::SHIFT_IMM_16 => {
+                let is_8bit = opcode_byte & 0b00000001 == 0;
+                let operand = decode_operand_from_single_mod_rm_opcode(memory_slice, is_8bit)?;
+
+                let count = match opcode {
+                    Opcode::SHIFT_IMM_8 | Opcode::SHIFT_IMM_16 => {
+                        ShiftCount::Immediate(byte_at(memory_slice, 1 + operand.encoded_size() as usize)?)
+                    }
+                    _ if opcode_byte & 0b00000010 != 0 => ShiftCount::CL,
+                    _ => ShiftCount::One,
+                };
+
+                match byte_at(memory_slice, 0)? & 0b00111000 {
+                    0b00000000 => Ok(Self::Rol(operand, count, is_8bit)),
+                    0b00001000 => Ok(Self::Ror(operand, count, is_8bit)),
+                    0b00010000 => Ok(Self::Rcl(operand, count, is_8bit)),
+                    0b00011000 => Ok(Self::Rcr(operand, count, is_8bit)),
+                    0b00101000 => Ok(Self::Shr(operand, count, is_8bit)),
+                    0b00111000 => Ok(Self::Sar(operand, count, is_8bit)),
+                    // 0b110 is an undocumented alias of SHL/SAL
+                    _ => Ok(Self::Shl(operand, count, is_8bit)),
+                }
+            }
+            Opcode::DAA => Ok(Self::Daa),
+            Opcode::DAS => Ok(Self::Das),
+            Opcode::AAA => Ok(Self::Aaa),
+            Opcode::AAS => Ok(Self::Aas),
+            Opcode::AAM => Ok(Self::Aam(byte_at(memory_slice, 0)?)),
+            Opcode::AAD => Ok(Self::Aad(byte_at(memory_slice, 0)?)),
             Opcode::MUL_DIV_8 => {
                 let operand = decode_operand_from_single_mod_rm_opcode(memory_slice, true)?;
-                if memory_slice[0] & 0b00111000 == 0b00110000 {
-                    Ok(Self::Div8(operand))
-                } else {
-                    Ok(Self::Mul8(operand))
+                match byte_at(memory_slice, 0)? & 0b00111000 {
+                    // 001 is an undocumented alias of TEST
+                    0b00000000 | 0b00001000 => {
+                        let imm = byte_at(memory_slice, 1 + operand.encoded_size() as usize)?;
+                        Ok(Self::Test(operand, Operand::Immediate8(imm), true))
+                    }
+                    0b00010000 => Ok(Self::Not8(operand)),
+                    0b00011000 => Ok(Self::Neg8(operand)),
+                    0b00100000 => Ok(Self::Mul8(operand)),
+                    0b00101000 => Ok(Self::Imul8(operand)),
+                    0b00110000 => Ok(Self::Div8(operand)),
+                    _ => Ok(Self::Idiv8(operand)),
                 }
             }
             Opcode::MUL_DIV_16 => {
                 let operand = decode_operand_from_single_mod_rm_opcode(memory_slice, false)?;
-                if memory_slice[0] & 0b00111000 == 0b00110000 {
-                    Ok(Self::Div16(operand))
+                match byte_at(memory_slice, 0)? & 0b00111000 {
+                    0b00000000 | 0b00001000 => {
+                        let imm_offset = 1 + operand.encoded_size() as usize;
+                        let imm = word_at(memory_slice, imm_offset)?;
+                        Ok(Self::Test(operand, Operand::Immediate16(imm), false))
+                    }
+                    0b00010000 => Ok(Self::Not16(operand)),
+                    0b00011000 => Ok(Self::Neg16(operand)),
+                    0b00100000 => Ok(Self::Mul16(operand)),
+                    0b00101000 => Ok(Self::Imul16(operand)),
+                    0b00110000 => Ok(Self::Div16(operand)),
+                    _ => Ok(Self::Idiv16(operand)),
+                }
+            }
+            Opcode::INC_DEC_8 => {
+                let operand = decode_operand_from_single_mod_rm_opcode(memory_slice, true)?;
+                match byte_at(memory_slice, 0)? & 0b00111000 {
+                    0b00000000 => Ok(Self::Inc8(operand)),
+                    0b00001000 => Ok(Self::Dec8(operand)),
+                    _ => Err(DecodeError::InvalidOpcode(opcode_byte)),
+                }
+            }
+            Opcode::INC_DEC_16 => {
+                let operand = decode_operand_from_single_mod_rm_opcode(memory_slice, false)?;
+                match (byte_at(memory_slice, 0)? & 0b00111000, operand) {
+                    (0b00000000, _) => Ok(Self::Inc16(operand)),
+                    (0b00001000, _) => Ok(Self::Dec16(operand)),
+                    (0b00010000, _) => Ok(Self::CallNearIndirect(operand)),
+                    (0b00100000, _) => Ok(Self::JmpNearIndirect(operand)),
+                    // far pointers can only be loaded from memory
+                    (0b00011000 | 0b00101000, Operand::Register(_)) => Err(DecodeError::InvalidOperand),
+                    (0b00011000, _) => Ok(Self::CallFarIndirect(operand)),
+                    (0b00101000, _) => Ok(Self::JmpFarIndirect(operand)),
+                    (0b00110000, _) => Ok(Self::PushRm(operand)),
+                    // 111 is an undocumented alias of PUSH on the 8086, later models fault
+                    _ if model == CpuModel::I8086 => Ok(Self::PushRm(operand)),
+                    _ => Err(DecodeError::InvalidOpcode(opcode_byte)),
+                }
+            }
+            Opcode::IMUL_IMM_8 | Opcode::IMUL_IMM_16 => {
+                let reg = Register::from_register_code((byte_at(memory_slice, 0)? & 0b00111000) >> 3, false)?;
+                let src = decode_operand_from_single_mod_rm_opcode(memory_slice, false)?;
+
+                let imm_offset = 1 + src.encoded_size() as usize;
+                let imm = if opcode == Opcode::IMUL_IMM_16 {
+                    Operand::Immediate16(word_at(memory_slice, imm_offset)?)
                 } else {
-                    Ok(Self::Mul16(operand))
+                    Operand::Immediate8(byte_at(memory_slice, imm_offset)?)
+                };
+
+                Ok(Self::ImulImm(reg, src, imm))
+            }
+            Opcode::BOUND => {
+                let operand = decode_operand_from_single_mod_rm_opcode(memory_slice, false)?;
+                let reg = Register::from_register_code((byte_at(memory_slice, 0)? & 0b00111000) >> 3, false)?;
+
+                match operand {
+                    Operand::Register(_) => Err(DecodeError::InvalidOperand),
+                    _ => Ok(Self::Bound(reg, operand)),
+                }
+            }
+            Opcode::MOVS => Ok(Self::Movs(None, None, opcode_byte & 1 == 0)),
+            Opcode::CMPS => Ok(Self::Cmps(None, None, opcode_byte & 1 == 0)),
+            Opcode::STOS => Ok(Self::Stos(None, None, opcode_byte & 1 == 0)),
+            Opcode::LODS => Ok(Self::Lods(None, None, opcode_byte & 1 == 0)),
+            Opcode::SCAS => Ok(Self::Scas(None, None, opcode_byte & 1 == 0)),
+            Opcode::INS => Ok(Self::Ins(None, None, opcode_byte & 1 == 0)),
+            Opcode::OUTS => Ok(Self::Outs(None, None, opcode_byte & 1 == 0)),
+            Opcode::REP | Opcode::REPNE => {
+                let prefix = if opcode == Opcode::REP { RepPrefix::Rep } else { RepPrefix::Repne };
+
+                match Self::decode(byte_at(memory_slice, 0)?, &memory_slice[1..], model)? {
+                    Self::Movs(None, segment, is_8bit) => Ok(Self::Movs(Some(prefix), segment, is_8bit)),
+                    Self::Cmps(None, segment, is_8bit) => Ok(Self::Cmps(Some(prefix), segment, is_8bit)),
+                    Self::Stos(None, segment, is_8bit) => Ok(Self::Stos(Some(prefix), segment, is_8bit)),
+                    Self::Lods(None, segment, is_8bit) => Ok(Self::Lods(Some(prefix), segment, is_8bit)),
+                    Self::Scas(None, segment, is_8bit) => Ok(Self::Scas(Some(prefix), segment, is_8bit)),
+                    Self::Ins(None, segment, is_8bit) => Ok(Self::Ins(Some(prefix), segment, is_8bit)),
+                    Self::Outs(None, segment, is_8bit) => Ok(Self::Outs(Some(prefix), segment, is_8bit)),
+                    Self::Movs(..) | Self::Cmps(..) | Self::Stos(..) | Self::Lods(..) | Self::Scas(..)
+                    | Self::Ins(..) | Self::Outs(..) => Err(DecodeError::DuplicateRepeatPrefix),
+                    // the CPU skips a repeat prefix in front of anything else
+                    instr => Ok(instr.with_ignored_prefixes(1)),
                 }
             }
+            Opcode::CALL => {
+                let offset = word_at(memory_slice, 0)? as i16;
+                Ok(Self::CallNear(offset))
+            }
+            Opcode::CALL_FAR => {
+                let offset = word_at(memory_slice, 0)?;
+                let segment = word_at(memory_slice, 2)?;
+                Ok(Self::CallFar(segment, offset))
+            }
+            Opcode::RET => Ok(Self::Ret),
+            Opcode::RET_IMM => {
+                let imm = word_at(memory_slice, 0)?;
+                Ok(Self::RetImm(imm))
+            }
+            Opcode::RETF => Ok(Self::RetFar),
+            Opcode::RETF_IMM => {
+                let imm = word_at(memory_slice, 0)?;
+                Ok(Self::RetFarImm(imm))
+            }
+            Opcode::ENTER => {
+                let size = word_at(memory_slice, 0)?;
+                let level = byte_at(memory_slice, 2)?;
+                Ok(Self::Enter(size, level))
+            }
+            Opcode::LEAVE => Ok(Self::Leave),
+            Opcode::IN_IMM => Ok(Self::In(IoPort::Immediate(byte_at(memory_slice, 0)?), opcode_byte & 1 == 0)),
+            Opcode::OUT_IMM => Ok(Self::Out(IoPort::Immediate(byte_at(memory_slice, 0)?), opcode_byte & 1 == 0)),
+            Opcode::IN_DX => Ok(Self::In(IoPort::DX, opcode_byte & 1 == 0)),
+            Opcode::OUT_DX => Ok(Self::Out(IoPort::DX, opcode_byte & 1 == 0)),
+            Opcode::INT => Ok(Self::Int(byte_at(memory_slice, 0)?)),
+            Opcode::INT3 => Ok(Self::Int3),
+            Opcode::INTO => Ok(Self::Into),
+            Opcode::IRET => Ok(Self::Iret),
+            Opcode::HLT => Ok(Self::Hlt),
+            Opcode::CMC => Ok(Self::Cmc),
+            Opcode::CLC => Ok(Self::Clc),
+            Opcode::STC => Ok(Self::Stc),
+            Opcode::CLI => Ok(Self::Cli),
+            Opcode::STI => Ok(Self::Sti),
+            Opcode::CLD => Ok(Self::Cld),
+            Opcode::STD => Ok(Self::Std),
+            Opcode::PUSHF => Ok(Self::Pushf),
+            Opcode::POPF => Ok(Self::Popf),
             Opcode::JMP => {
-                let offset = (memory_slice[1] as i16) << 8 | memory_slice[0] as i16;
+                let offset = word_at(memory_slice, 0)? as i16;
                 Ok(Self::JmpNear(offset))
             }
             Opcode::JMP_FAR => {
-                let offset = (memory_slice[1] as u16) << 8 | memory_slice[0] as u16;
-                let segment = (memory_slice[3] as u16) << 8 | memory_slice[2] as u16;
+                let offset = word_at(memory_slice, 0)?;
+                let segment = word_at(memory_slice, 2)?;
                 Ok(Self::JmpFar(segment, offset))
             }
+            Opcode::JO => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Jo(offset))
+            }
+            Opcode::JNO => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Jno(offset))
+            }
+            Opcode::JC => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Jc(offset))
+            }
+            Opcode::JNC => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Jnc(offset))
+            }
             Opcode::JZ => {
-                let offset = memory_slice[0] as i8;
+                let offset = byte_at(memory_slice, 0)? as i8;
                 Ok(Instruction::Jz(offset))
             }
             Opcode::JNZ => {
-                let offset = memory_slice[0] as i8;
+                let offset = byte_at(memory_slice, 0)? as i8;
                 Ok(Instruction::Jnz(offset))
             }
+            Opcode::JBE => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Jbe(offset))
+            }
+            Opcode::JA => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Ja(offset))
+            }
+            Opcode::JS => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Js(offset))
+            }
+            Opcode::JNS => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Jns(offset))
+            }
+            Opcode::JP => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Jp(offset))
+            }
+            Opcode::JNP => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Jnp(offset))
+            }
+            Opcode::JL => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Jl(offset))
+            }
+            Opcode::JGE => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Jge(offset))
+            }
+            Opcode::JLE => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Jle(offset))
+            }
+            Opcode::JG => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Jg(offset))
+            }
             Opcode::JMP_SHORT => {
-                let offset = memory_slice[0] as i8;
+                let offset = byte_at(memory_slice, 0)? as i8;
                 Ok(Instruction::JmpShort(offset))
             }
+            Opcode::LOOPNZ => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Loopnz(offset))
+            }
+            Opcode::LOOPZ => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Loopz(offset))
+            }
+            Opcode::LOOP => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Loop(offset))
+            }
+            Opcode::JCXZ => {
+                let offset = byte_at(memory_slice, 0)? as i8;
+                Ok(Instruction::Jcxz(offset))
+            }
         }
     }
 
     pub fn get_instr_size(&self) -> u16 {
         match self {
-            Self::Noop | Self::Push(_) | Self::Pop(_) | Self::Inc(_) | Self::Dec(_) => 1,
+            Self::IgnoredPrefixes(count, instr) => count + instr.get_instr_size(),
+            Self::Noop | Self::Ret | Self::RetFar
+            | Self::Int3 | Self::Into | Self::Iret | Self::Hlt | Self::Cbw | Self::Cwd
+            | Self::Lahf | Self::Sahf | Self::Cmc | Self::Clc | Self::Stc | Self::Cli | Self::Sti | Self::Cld
+            | Self::Std | Self::Pushf | Self::Popf | Self::Daa | Self::Das | Self::Aaa | Self::Aas
+            | Self::Pusha | Self::Popa | Self::Leave => 1,
+            Self::Inc(reg) | Self::Dec(reg) | Self::Push(reg) | Self::Pop(reg)
+            | Self::XchgAcc(reg) => 1 + reg.is_32bit() as u16,
+            Self::Cwde | Self::Cdq => 2,
             Self::MovImm8(..) | Self::AddAcc8(_) | Self::SubAcc8(_) | Self::AndAcc8(_) | Self::OrAcc8(_)
-            | Self::Jz(_) | Self::Jnz(_) | Self::JmpShort(_) => 2,
-            Self::MovImm16(..) | Self::MovAccMem(_, _) | Self::AddAcc16(_) | Self::SubAcc16(_)
-            | Self::AndAcc16(_) | Self::OrAcc16(_) | Self::JmpNear(_) => 3,
+            | Self::XorAcc8(_) | Self::AdcAcc8(_) | Self::SbbAcc8(_) | Self::CmpAcc8(_) | Self::TestAcc8(_)
+            | Self::Jo(_) | Self::Jno(_) | Self::Jc(_) | Self::Jnc(_) | Self::Jz(_) | Self::Jnz(_)
+            | Self::Jbe(_) | Self::Ja(_) | Self::Js(_) | Self::Jns(_) | Self::Jp(_) | Self::Jnp(_)
+            | Self::Jl(_) | Self::Jge(_) | Self::Jle(_) | Self::Jg(_) | Self::JmpShort(_)
+            | Self::Loopnz(_) | Self::Loopz(_) | Self::Loop(_) | Self::Jcxz(_) | Self::Int(_)
+            | Self::Aam(_) | Self::Aad(_) | Self::PushImm8(_) => 2,
+            Self::MovAccMem(dest, src) => 3 + dest.prefix_size() + src.prefix_size(),
+            Self::MovImm16(..) | Self::AddAcc16(_) | Self::SubAcc16(_)
+            | Self::AndAcc16(_) | Self::OrAcc16(_) | Self::XorAcc16(_) | Self::AdcAcc16(_)
+            | Self::SbbAcc16(_) | Self::CmpAcc16(_) | Self::TestAcc16(_) | Self::JmpNear(_)
+            | Self::CallNear(_) | Self::RetImm(_) | Self::RetFarImm(_) | Self::PushImm16(_) => 3,
+            Self::Enter(..) => 4,
+            // operand size prefix, opcode and imm32
+            Self::MovImm32(..) | Self::AddAcc32(_) | Self::SubAcc32(_) | Self::AndAcc32(_) | Self::OrAcc32(_)
+            | Self::XorAcc32(_) | Self::AdcAcc32(_) | Self::SbbAcc32(_) | Self::CmpAcc32(_)
+            | Self::TestAcc32(_) => 6,
             Self::Mov(operand1, operand2)
             | Self::Add(operand1, operand2, ..)
             | Self::Sub(operand1, operand2, ..)
-            | Self::And(operand1, operand2)
-            | Self::Or(operand1, operand2) => 2 +
-                if let Operand::Memory(mem_add) = operand1 {
-                    mem_add.displacement_size as u16
-                } else {
-                    0
-                } + if let Operand::Memory(mem_add) = operand2 {
-                mem_add.displacement_size as u16
+            | Self::And(operand1, operand2, ..)
+            | Self::Or(operand1, operand2, ..)
+            | Self::Xor(operand1, operand2, ..)
+            | Self::Adc(operand1, operand2, ..)
+            | Self::Sbb(operand1, operand2, ..)
+            | Self::Cmp(operand1, operand2, ..)
+            | Self::Test(operand1, operand2, ..)
+            | Self::Xchg(operand1, operand2, ..) => 2 + operand1.encoded_size() + operand2.encoded_size(),
+            Self::Mov32(operand1, operand2)
+            | Self::Add32(operand1, operand2)
+            | Self::Sub32(operand1, operand2)
+            | Self::And32(operand1, operand2)
+            | Self::Or32(operand1, operand2)
+            | Self::Xor32(operand1, operand2)
+            | Self::Adc32(operand1, operand2)
+            | Self::Sbb32(operand1, operand2)
+            | Self::Cmp32(operand1, operand2)
+            | Self::Test32(operand1, operand2)
+            | Self::Xchg32(operand1, operand2) => 3 + operand1.encoded_size() + operand2.encoded_size(),
+            // 0F escape, the operand size prefix is only present for a 32bit destination
+            Self::Movzx(dest, src, _) | Self::Movsx(dest, src, _) => 3 + src.encoded_size() + dest.is_32bit() as u16,
+            Self::Lea(_, operand) | Self::Lds(_, operand) | Self::Les(_, operand)
+            | Self::Bound(_, operand) => 2 + operand.encoded_size(),
+            Self::ImulImm(_, src, imm) => 2 + src.encoded_size() + imm.encoded_size(),
+            Self::Mul8(operand) | Self::Mul16(operand)
+            | Self::Div8(operand) | Self::Div16(operand)
+            | Self::Imul8(operand) | Self::Imul16(operand)
+            | Self::Idiv8(operand) | Self::Idiv16(operand)
+            | Self::Not8(operand) | Self::Not16(operand)
+            | Self::Neg8(operand) | Self::Neg16(operand)
+            | Self::Inc8(operand) | Self::Inc16(operand)
+            | Self::Dec8(operand) | Self::Dec16(operand)
+            | Self::CallNearIndirect(operand) | Self::CallFarIndirect(operand)
+            | Self::JmpNearIndirect(operand) | Self::JmpFarIndirect(operand)
+            | Self::PushRm(operand) => 2 + operand.encoded_size(),
+            Self::Inc32(operand) | Self::Dec32(operand)
+            | Self::Not32(operand) | Self::Neg32(operand)
+            | Self::Mul32(operand) | Self::Div32(operand)
+            | Self::Imul32(operand) | Self::Idiv32(operand) => 3 + operand.encoded_size(),
+            Self::Rol(operand, count, ..)
+            | Self::Ror(operand, count, ..)
+            | Self::Rcl(operand, count, ..)
+            | Self::Rcr(operand, count, ..)
+            | Self::Shl(operand, count, ..)
+            | Self::Shr(operand, count, ..)
+            | Self::Sar(operand, count, ..) => 2 + operand.encoded_size() + count.encoded_size(),
+            Self::Rol32(operand, count)
+            | Self::Ror32(operand, count)
+            | Self::Rcl32(operand, count)
+            | Self::Rcr32(operand, count)
+            | Self::Shl32(operand, count)
+            | Self::Shr32(operand, count)
+            | Self::Sar32(operand, count) => 3 + operand.encoded_size() + count.encoded_size(),
+            Self::JmpFar(..) | Self::CallFar(..) => 5,
+            Self::In(port, _) | Self::Out(port, _) => if let IoPort::Immediate(_) = port {
+                2
             } else {
-                0
+                1
             },
-            Self::Mul8(operand) | Self::Mul16(operand)
-            | Self::Div8(operand) | Self::Div16(operand) => 2 +
-                if let Operand::Memory(mem_add) = operand {
-                    mem_add.displacement_size as u16
-                } else {
-                    0
-                },
-            Self::JmpFar(..) => 4,
+            Self::Movs(rep, segment, _)
+            | Self::Cmps(rep, segment, _)
+            | Self::Stos(rep, segment, _)
+            | Self::Lods(rep, segment, _)
+            | Self::Scas(rep, segment, _)
+            | Self::Ins(rep, segment, _)
+            | Self::Outs(rep, segment, _) => 1 + rep.is_some() as u16 + segment.is_some() as u16,
+            Self::Xlat(segment) => 1 + segment.is_some() as u16,
+        }
+    }
+
+    fn with_segment_override(self, segment: Register) -> Result<Self, DecodeError> {
+        let instr = match self {
+            Self::IgnoredPrefixes(count, instr) => {
+                return Ok(instr.with_segment_override(segment)?.with_ignored_prefixes(count));
+            }
+            Self::Mov(dest, src) => Self::Mov(dest.with_segment(segment)?, src.with_segment(segment)?),
+            Self::MovAccMem(MovMemOperand::Register(reg), MovMemOperand::MemoryPtr(ptr)) => Self::MovAccMem(
+                MovMemOperand::Register(reg),
+                MovMemOperand::SegmentedMemoryPtr(segment, ptr),
+            ),
+            Self::MovAccMem(MovMemOperand::MemoryPtr(ptr), MovMemOperand::Register(reg)) => Self::MovAccMem(
+                MovMemOperand::SegmentedMemoryPtr(segment, ptr),
+                MovMemOperand::Register(reg),
+            ),
+            Self::Add(dest, src, is_8bit) => Self::Add(dest.with_segment(segment)?, src.with_segment(segment)?, is_8bit),
+            Self::Sub(dest, src, is_8bit) => Self::Sub(dest.with_segment(segment)?, src.with_segment(segment)?, is_8bit),
+            Self::And(dest, src, is_8bit) => Self::And(dest.with_segment(segment)?, src.with_segment(segment)?, is_8bit),
+            Self::Or(dest, src, is_8bit) => Self::Or(dest.with_segment(segment)?, src.with_segment(segment)?, is_8bit),
+            Self::Xor(dest, src, is_8bit) => Self::Xor(dest.with_segment(segment)?, src.with_segment(segment)?, is_8bit),
+            Self::Adc(dest, src, is_8bit) => Self::Adc(dest.with_segment(segment)?, src.with_segment(segment)?, is_8bit),
+            Self::Sbb(dest, src, is_8bit) => Self::Sbb(dest.with_segment(segment)?, src.with_segment(segment)?, is_8bit),
+            Self::Cmp(dest, src, is_8bit) => Self::Cmp(dest.with_segment(segment)?, src.with_segment(segment)?, is_8bit),
+            Self::Test(dest, src, is_8bit) => Self::Test(dest.with_segment(segment)?, src.with_segment(segment)?, is_8bit),
+            Self::Xchg(dest, src, is_8bit) => Self::Xchg(dest.with_segment(segment)?, src.with_segment(segment)?, is_8bit),
+            Self::Xchg32(dest, src) => Self::Xchg32(dest.with_segment(segment)?, src.with_segment(segment)?),
+            Self::Mov32(dest, src) => Self::Mov32(dest.with_segment(segment)?, src.with_segment(segment)?),
+            Self::Add32(dest, src) => Self::Add32(dest.with_segment(segment)?, src.with_segment(segment)?),
+            Self::Sub32(dest, src) => Self::Sub32(dest.with_segment(segment)?, src.with_segment(segment)?),
+            Self::And32(dest, src) => Self::And32(dest.with_segment(segment)?, src.with_segment(segment)?),
+            Self::Or32(dest, src) => Self::Or32(dest.with_segment(segment)?, src.with_segment(segment)?),
+            Self::Xor32(dest, src) => Self::Xor32(dest.with_segment(segment)?, src.with_segment(segment)?),
+            Self::Adc32(dest, src) => Self::Adc32(dest.with_segment(segment)?, src.with_segment(segment)?),
+            Self::Sbb32(dest, src) => Self::Sbb32(dest.with_segment(segment)?, src.with_segment(segment)?),
+            Self::Cmp32(dest, src) => Self::Cmp32(dest.with_segment(segment)?, src.with_segment(segment)?),
+            Self::Test32(dest, src) => Self::Test32(dest.with_segment(segment)?, src.with_segment(segment)?),
+            Self::Movzx(reg, src, is_8bit) => Self::Movzx(reg, src.with_segment(segment)?, is_8bit),
+            Self::Movsx(reg, src, is_8bit) => Self::Movsx(reg, src.with_segment(segment)?, is_8bit),
+            Self::Lea(reg, operand) => Self::Lea(reg, operand.with_segment(segment)?),
+            Self::Lds(reg, operand) => Self::Lds(reg, operand.with_segment(segment)?),
+            Self::Les(reg, operand) => Self::Les(reg, operand.with_segment(segment)?),
+            Self::Bound(reg, operand) => Self::Bound(reg, operand.with_segment(segment)?),
+            Self::ImulImm(reg, src, imm) => Self::ImulImm(reg, src.with_segment(segment)?, imm),
+            Self::Xlat(None) => Self::Xlat(Some(segment)),
+            Self::Mul8(operand) => Self::Mul8(operand.with_segment(segment)?),
+            Self::Mul16(operand) => Self::Mul16(operand.with_segment(segment)?),
+            Self::Div8(operand) => Self::Div8(operand.with_segment(segment)?),
+            Self::Div16(operand) => Self::Div16(operand.with_segment(segment)?),
+            Self::Imul8(operand) => Self::Imul8(operand.with_segment(segment)?),
+            Self::Imul16(operand) => Self::Imul16(operand.with_segment(segment)?),
+            Self::Idiv8(operand) => Self::Idiv8(operand.with_segment(segment)?),
+            Self::Idiv16(operand) => Self::Idiv16(operand.with_segment(segment)?),
+            Self::Not8(operand) => Self::Not8(operand.with_segment(segment)?),
+            Self::Not16(operand) => Self::Not16(operand.with_segment(segment)?),
+            Self::Neg8(operand) => Self::Neg8(operand.with_segment(segment)?),
+            Self::Neg16(operand) => Self::Neg16(operand.with_segment(segment)?),
+            Self::Inc8(operand) => Self::Inc8(operand.with_segment(segment)?),
+            Self::Inc16(operand) => Self::Inc16(operand.with_segment(segment)?),
+            Self::Dec8(operand) => Self::Dec8(operand.with_segment(segment)?),
+            Self::Dec16(operand) => Self::Dec16(operand.with_segment(segment)?),
+            Self::Inc32(operand) => Self::Inc32(operand.with_segment(segment)?),
+            Self::Dec32(operand) => Self::Dec32(operand.with_segment(segment)?),
+            Self::Not32(operand) => Self::Not32(operand.with_segment(segment)?),
+            Self::Neg32(operand) => Self::Neg32(operand.with_segment(segment)?),
+            Self::Mul32(operand) => Self::Mul32(operand.with_segment(segment)?),
+            Self::Div32(operand) => Self::Div32(operand.with_segment(segment)?),
+            Self::Imul32(operand) => Self::Imul32(operand.with_segment(segment)?),
+            Self::Idiv32(operand) => Self::Idiv32(operand.with_segment(segment)?),
+            Self::CallNearIndirect(operand) => Self::CallNearIndirect(operand.with_segment(segment)?),
+            Self::CallFarIndirect(operand) => Self::CallFarIndirect(operand.with_segment(segment)?),
+            Self::JmpNearIndirect(operand) => Self::JmpNearIndirect(operand.with_segment(segment)?),
+            Self::JmpFarIndirect(operand) => Self::JmpFarIndirect(operand.with_segment(segment)?),
+            Self::PushRm(operand) => Self::PushRm(operand.with_segment(segment)?),
+            Self::Rol(operand, count, is_8bit) => Self::Rol(operand.with_segment(segment)?, count, is_8bit),
+            Self::Ror(operand, count, is_8bit) => Self::Ror(operand.with_segment(segment)?, count, is_8bit),
+            Self::Rcl(operand, count, is_8bit) => Self::Rcl(operand.with_segment(segment)?, count, is_8bit),
+            Self::Rcr(operand, count, is_8bit) => Self::Rcr(operand.with_segment(segment)?, count, is_8bit),
+            Self::Shl(operand, count, is_8bit) => Self::Shl(operand.with_segment(segment)?, count, is_8bit),
+            Self::Shr(operand, count, is_8bit) => Self::Shr(operand.with_segment(segment)?, count, is_8bit),
+            Self::Sar(operand, count, is_8bit) => Self::Sar(operand.with_segment(segment)?, count, is_8bit),
+            Self::Rol32(operand, count) => Self::Rol32(operand.with_segment(segment)?, count),
+            Self::Ror32(operand, count) => Self::Ror32(operand.with_segment(segment)?, count),
+            Self::Rcl32(operand, count) => Self::Rcl32(operand.with_segment(segment)?, count),
+            Self::Rcr32(operand, count) => Self::Rcr32(operand.with_segment(segment)?, count),
+            Self::Shl32(operand, count) => Self::Shl32(operand.with_segment(segment)?, count),
+            Self::Shr32(operand, count) => Self::Shr32(operand.with_segment(segment)?, count),
+            Self::Sar32(operand, count) => Self::Sar32(operand.with_segment(segment)?, count),
+            Self::Movs(rep, None, is_8bit) => Self::Movs(rep, Some(segment), is_8bit),
+            Self::Cmps(rep, None, is_8bit) => Self::Cmps(rep, Some(segment), is_8bit),
+            Self::Stos(rep, None, is_8bit) => Self::Stos(rep, Some(segment), is_8bit),
+            Self::Lods(rep, None, is_8bit) => Self::Lods(rep, Some(segment), is_8bit),
+            Self::Scas(rep, None, is_8bit) => Self::Scas(rep, Some(segment), is_8bit),
+            Self::Ins(rep, None, is_8bit) => Self::Ins(rep, Some(segment), is_8bit),
+            Self::Outs(rep, None, is_8bit) => Self::Outs(rep, Some(segment), is_8bit),
+            Self::Xlat(_) | Self::Movs(..) | Self::Cmps(..) | Self::Stos(..) | Self::Lods(..)
+            | Self::Scas(..) | Self::Ins(..) | Self::Outs(..) => return Err(DecodeError::DuplicateSegmentOverride),
+            _ => return Ok(self.with_ignored_prefixes(1)),
+        };
+
+        // register only operands leave nothing to override
+        if instr.get_instr_size() == self.get_instr_size() {
+            return Ok(self.with_ignored_prefixes(1));
+        }
+
+        Ok(instr)
+    }
+
+    fn with_ignored_prefixes(self, count: u16) -> Self {
+        match self {
+            Self::IgnoredPrefixes(ignored, instr) => Self::IgnoredPrefixes(ignored + count, instr),
+            instr => Self::IgnoredPrefixes(count, Box::new(instr)),
+        }
+    }
+
+    // memory_slice starts at the opcode following the operand size prefix
+    fn with_operand_size_32(self, memory_slice: &[u8]) -> Result<Self, DecodeError> {
+        let widen = |operand: Operand| match operand {
+            Operand::Register(reg) if reg.to_32bit().is_32bit() => Ok(Operand::Register(reg.to_32bit())),
+            // 8bit and segment registers have no 32bit form
+            Operand::Register(_) => Err(DecodeError::InvalidOperandSizePrefix),
+            // immediates are encoded last, the imm32 starts where the imm16 did
+            Operand::Immediate16(_) => {
+                Ok(Operand::Immediate32(dword_at(memory_slice, self.get_instr_size() as usize - 2)?))
+            }
+            // imm8 is sign extended to 32bit
+            operand => Ok(operand),
+        };
+
+        let instr = match self {
+            Self::IgnoredPrefixes(count, instr) => {
+                instr.with_operand_size_32(&memory_slice[count as usize..])?.with_ignored_prefixes(count)
+            }
+            Self::MovImm16(reg, _) => Self::MovImm32(reg.to_32bit(), dword_at(memory_slice, 1)?),
+            // MOV r/m8, imm8
+            Self::Mov(_, Operand::Immediate8(_)) => return Err(DecodeError::InvalidOperandSizePrefix),
+            Self::Mov(dest, src) => Self::Mov32(widen(dest)?, widen(src)?),
+            Self::Movzx(reg, src, is_8bit) if !reg.is_32bit() => Self::Movzx(reg.to_32bit(), src, is_8bit),
+            Self::Movsx(reg, src, is_8bit) if !reg.is_32bit() => Self::Movsx(reg.to_32bit(), src, is_8bit),
+            Self::Add(dest, src, false) => Self::Add32(widen(dest)?, widen(src)?),
+            Self::Sub(dest, src, false) => Self::Sub32(widen(dest)?, widen(src)?),
+            Self::And(dest, src, false) => Self::And32(widen(dest)?, widen(src)?),
+            Self::Or(dest, src, false) => Self::Or32(widen(dest)?, widen(src)?),
+            Self::Xor(dest, src, false) => Self::Xor32(widen(dest)?, widen(src)?),
+            Self::Adc(dest, src, false) => Self::Adc32(widen(dest)?, widen(src)?),
+            Self::Sbb(dest, src, false) => Self::Sbb32(widen(dest)?, widen(src)?),
+            Self::Cmp(dest, src, false) => Self::Cmp32(widen(dest)?, widen(src)?),
+            Self::Test(dest, src, false) => Self::Test32(widen(dest)?, widen(src)?),
+            Self::AddAcc16(_) => Self::AddAcc32(dword_at(memory_slice, 1)?),
+            Self::SubAcc16(_) => Self::SubAcc32(dword_at(memory_slice, 1)?),
+            Self::AndAcc16(_) => Self::AndAcc32(dword_at(memory_slice, 1)?),
+            Self::OrAcc16(_) => Self::OrAcc32(dword_at(memory_slice, 1)?),
+            Self::XorAcc16(_) => Self::XorAcc32(dword_at(memory_slice, 1)?),
+            Self::AdcAcc16(_) => Self::AdcAcc32(dword_at(memory_slice, 1)?),
+            Self::SbbAcc16(_) => Self::SbbAcc32(dword_at(memory_slice, 1)?),
+            Self::CmpAcc16(_) => Self::CmpAcc32(dword_at(memory_slice, 1)?),
+            Self::TestAcc16(_) => Self::TestAcc32(dword_at(memory_slice, 1)?),
+            Self::Inc(reg) => Self::Inc(reg.to_32bit()),
+            Self::Dec(reg) => Self::Dec(reg.to_32bit()),
+            Self::Inc16(operand) => Self::Inc32(widen(operand)?),
+            Self::Dec16(operand) => Self::Dec32(widen(operand)?),
+            Self::Not16(operand) => Self::Not32(widen(operand)?),
+            Self::Neg16(operand) => Self::Neg32(widen(operand)?),
+            Self::MovAccMem(MovMemOperand::Register(Register::AX), src) => {
+                Self::MovAccMem(MovMemOperand::Register(Register::EAX), src)
+            }
+            Self::MovAccMem(dest, MovMemOperand::Register(Register::AX)) => {
+                Self::MovAccMem(dest, MovMemOperand::Register(Register::EAX))
+            }
+            Self::Mul16(operand) => Self::Mul32(widen(operand)?),
+            Self::Div16(operand) => Self::Div32(widen(operand)?),
+            Self::Imul16(operand) => Self::Imul32(widen(operand)?),
+            Self::Idiv16(operand) => Self::Idiv32(widen(operand)?),
+            Self::Rol(operand, count, false) => Self::Rol32(widen(operand)?, count),
+            Self::Ror(operand, count, false) => Self::Ror32(widen(operand)?, count),
+            Self::Rcl(operand, count, false) => Self::Rcl32(widen(operand)?, count),
+            Self::Rcr(operand, count, false) => Self::Rcr32(widen(operand)?, count),
+            Self::Shl(operand, count, false) => Self::Shl32(widen(operand)?, count),
+            Self::Shr(operand, count, false) => Self::Shr32(widen(operand)?, count),
+            Self::Sar(operand, count, false) => Self::Sar32(widen(operand)?, count),
+            Self::Xchg(dest, src, false) => Self::Xchg32(widen(dest)?, widen(src)?),
+            Self::XchgAcc(reg) => Self::XchgAcc(reg.to_32bit()),
+            Self::Cbw => Self::Cwde,
+            Self::Cwd => Self::Cdq,
+            // segment registers keep their 16bit PUSH/POP
+            Self::Push(reg) if reg.to_32bit().is_32bit() => Self::Push(reg.to_32bit()),
+            Self::Pop(reg) if reg.to_32bit().is_32bit() => Self::Pop(reg.to_32bit()),
+            // IMUL imm, PUSH r/m and imm, string instructions and everything else have no 32bit form in NVM.
+            // The 80386 ignores the prefix on 8bit operations, NVM rejects it like any other instruction
+            _ => return Err(DecodeError::InvalidOperandSizePrefix),
+        };
+
+        Ok(instr)
+    }
+}
+
+impl Opcode {
+    // Opcodes as decoded by the given model
+    pub fn from_byte(value: u8, model: CpuModel) -> Result<Self, DecodeError> {
+        let opcode = match model {
+            CpuModel::I8086 => Self::try_from(Self::alias_8086(value))?,
+            _ => Self::try_from(value)?,
+        };
+
+        match opcode {
+            Self::OPERAND_SIZE | Self::TWO_BYTE if model != CpuModel::I80386 => Err(DecodeError::InvalidOpcode(value)),
+            opcode => Ok(opcode),
+        }
+    }
+
+    // The 8086 does not decode bit 1 of 60 - 6F and C0 - C9, so the opcodes
+    // added by the 80186 execute as Jcc, RET and RETF
+    pub fn alias_8086(value: u8) -> u8 {
+        match value {
+            0x60..=0x6F => value | 0b00010000,
+            0xC0 | 0xC1 | 0xC8 | 0xC9 => value | 0b00000010,
+            _ => value,
         }
     }
 }
 
+// Decodes opcodes of the most capable model, see Opcode::from_byte for the others
 impl TryFrom<u8> for Opcode {
-    type Error = String;
+    type Error = DecodeError;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
             x if x == Self::NOOP as u8 => Ok(Self::NOOP),
-            x if x >= 0x50 && x <= 0x57 => Ok(Self::PUSH),
-            x if x >= 0x58 && x <= 0x5F => Ok(Self::POP),
-            x if x >= 0xB0 && x <= 0xBF => Ok(Self::MOV_IMM),
-            x if x >= 0x88 && x <= 0x8B => Ok(Self::MOV_REG_MEM),
-            x if x >= 0xA0 && x <= 0xA3 => Ok(Self::MOV_ACC_MEM),
+            x if (0x50..=0x57).contains(&x) => Ok(Self::PUSH),
+            x if (0x58..=0x5F).contains(&x) => Ok(Self::POP),
+            x if (0xB0..=0xBF).contains(&x) => Ok(Self::MOV_IMM),
+            x if (0x88..=0x8B).contains(&x) => Ok(Self::MOV_REG_MEM),
+            x if (0xA0..=0xA3).contains(&x) => Ok(Self::MOV_ACC_MEM),
+            x if x == Self::MOV_SREG as u8 || x == 0x8E => Ok(Self::MOV_SREG),
+            x if (0xC6..=0xC7).contains(&x) => Ok(Self::MOV_RM_IMM),
+            x if x & 0b11100111 == Self::PUSH_SREG as u8 => Ok(Self::PUSH_SREG),
+            // 0F (POP CS) is not supported, the 80386 uses it as two byte escape
+            x if x & 0b11100111 == Self::POP_SREG as u8 && x != 0x0F => Ok(Self::POP_SREG),
+            x if x == Self::TWO_BYTE as u8 => Ok(Self::TWO_BYTE),
+            x if x == Self::LEA as u8 => Ok(Self::LEA),
+            x if x == Self::LES as u8 => Ok(Self::LES),
+            x if x == Self::LDS as u8 => Ok(Self::LDS),
+            x if (0x86..=0x87).contains(&x) => Ok(Self::XCHG),
+            x if (0x91..=0x97).contains(&x) => Ok(Self::XCHG_ACC),
+            x if x == Self::XLAT as u8 => Ok(Self::XLAT),
+            x if x == Self::CBW as u8 => Ok(Self::CBW),
+            x if x == Self::CWD as u8 => Ok(Self::CWD),
+            x if x == Self::SAHF as u8 => Ok(Self::SAHF),
+            x if x == Self::LAHF as u8 => Ok(Self::LAHF),
+            x if x & 0b11100111 == Self::SEGMENT_OVERRIDE as u8 => Ok(Self::SEGMENT_OVERRIDE),
+            x if x == Self::OPERAND_SIZE as u8 => Ok(Self::OPERAND_SIZE),
             x if /* x >= 0x00 && */ x <= 0x03 => Ok(Self::ADD),
             x if x == Self::ADD_ACC_8 as u8 => Ok(Self::ADD_ACC_8),
             x if x == Self::ADD_ACC_16 as u8 => Ok(Self::ADD_ACC_16),
-            x if x >= 0x28 && x <= 0x2B => Ok(Self::SUB),
+            x if (0x28..=0x2B).contains(&x) => Ok(Self::SUB),
             x if x == Self::SUB_ACC_8 as u8 => Ok(Self::SUB_ACC_8),
             x if x == Self::SUB_ACC_16 as u8 => Ok(Self::SUB_ACC_16),
-            x if x >= 0x40 && x <= 0x47 => Ok(Self::INC),
-            x if x >= 0x48 && x <= 0x4F => Ok(Self::DEC),
+            x if (0x40..=0x47).contains(&x) => Ok(Self::INC),
+            x if (0x48..=0x4F).contains(&x) => Ok(Self::DEC),
+            x if x == Self::ALU_IMM_8 as u8 || x == 0x82 => Ok(Self::ALU_IMM_8),
+            x if x == Self::ALU_IMM_16 as u8 => Ok(Self::ALU_IMM_16),
+            x if x == Self::ALU_IMM_16_SIGN_EXT as u8 => Ok(Self::ALU_IMM_16_SIGN_EXT),
+            x if x == Self::SHIFT_8 as u8 || x == 0xD2 => Ok(Self::SHIFT_8),
+            x if x == Self::SHIFT_16 as u8 || x == 0xD3 => Ok(Self::SHIFT_16),
+            x if x == Self::SHIFT_IMM_8 as u8 => Ok(Self::SHIFT_IMM_8),
+            x if x == Self::SHIFT_IMM_16 as u8 => Ok(Self::SHIFT_IMM_16),
+            x if x == Self::IMUL_IMM_16 as u8 => Ok(Self::IMUL_IMM_16),
+            x if x == Self::IMUL_IMM_8 as u8 => Ok(Self::IMUL_IMM_8),
+            x if x == Self::PUSH_IMM_16 as u8 => Ok(Self::PUSH_IMM_16),
+            x if x == Self::PUSH_IMM_8 as u8 => Ok(Self::PUSH_IMM_8),
+            x if x == Self::PUSHA as u8 => Ok(Self::PUSHA),
+            x if x == Self::POPA as u8 => Ok(Self::POPA),
+            x if x == Self::ENTER as u8 => Ok(Self::ENTER),
+            x if x == Self::LEAVE as u8 => Ok(Self::LEAVE),
+            x if x == Self::BOUND as u8 => Ok(Self::BOUND),
+            x if (0x6C..=0x6D).contains(&x) => Ok(Self::INS),
+            x if (0x6E..=0x6F).contains(&x) => Ok(Self::OUTS),
+            x if x == Self::DAA as u8 => Ok(Self::DAA),
+            x if x == Self::DAS as u8 => Ok(Self::DAS),
+            x if x == Self::AAA as u8 => Ok(Self::AAA),
+            x if x == Self::AAS as u8 => Ok(Self::AAS),
+            x if x == Self::AAM as u8 => Ok(Self::AAM),
+            x if x == Self::AAD as u8 => Ok(Self::AAD),
             x if x == Self::MUL_DIV_8 as u8 => Ok(Self::MUL_DIV_8),
             x if x == Self::MUL_DIV_16 as u8 => Ok(Self::MUL_DIV_16),
-            x if x >= 0x20 && x <= 0x23 => Ok(Self::AND),
+            x if x == Self::INC_DEC_8 as u8 => Ok(Self::INC_DEC_8),
+            x if x == Self::INC_DEC_16 as u8 => Ok(Self::INC_DEC_16),
+            x if (0x20..=0x23).contains(&x) => Ok(Self::AND),
             x if x == Self::AND_ACC_8 as u8 => Ok(Self::AND_ACC_8),
             x if x == Self::AND_ACC_16 as u8 => Ok(Self::AND_ACC_16),
-            x if x >= 0x08 && x <= 0x0B => Ok(Self::OR),
+            x if (0x08..=0x0B).contains(&x) => Ok(Self::OR),
             x if x == Self::OR_ACC_8 as u8 => Ok(Self::OR_ACC_8),
             x if x == Self::OR_ACC_16 as u8 => Ok(Self::OR_ACC_16),
+            x if (0x30..=0x33).contains(&x) => Ok(Self::XOR),
+            x if x == Self::XOR_ACC_8 as u8 => Ok(Self::XOR_ACC_8),
+            x if x == Self::XOR_ACC_16 as u8 => Ok(Self::XOR_ACC_16),
+            x if (0x10..=0x13).contains(&x) => Ok(Self::ADC),
+            x if x == Self::ADC_ACC_8 as u8 => Ok(Self::ADC_ACC_8),
+            x if x == Self::ADC_ACC_16 as u8 => Ok(Self::ADC_ACC_16),
+            x if (0x18..=0x1B).contains(&x) => Ok(Self::SBB),
+            x if x == Self::SBB_ACC_8 as u8 => Ok(Self::SBB_ACC_8),
+            x if x == Self::SBB_ACC_16 as u8 => Ok(Self::SBB_ACC_16),
+            x if (0x38..=0x3B).contains(&x) => Ok(Self::CMP),
+            x if x == Self::CMP_ACC_8 as u8 => Ok(Self::CMP_ACC_8),
+            x if x == Self::CMP_ACC_16 as u8 => Ok(Self::CMP_ACC_16),
+            x if (0x84..=0x85).contains(&x) => Ok(Self::TEST),
+            x if x == Self::TEST_ACC_8 as u8 => Ok(Self::TEST_ACC_8),
+            x if x == Self::TEST_ACC_16 as u8 => Ok(Self::TEST_ACC_16),
+            x if (0xA4..=0xA5).contains(&x) => Ok(Self::MOVS),
+            x if (0xA6..=0xA7).contains(&x) => Ok(Self::CMPS),
+            x if (0xAA..=0xAB).contains(&x) => Ok(Self::STOS),
+            x if (0xAC..=0xAD).contains(&x) => Ok(Self::LODS),
+            x if (0xAE..=0xAF).contains(&x) => Ok(Self::SCAS),
+            x if x == Self::REPNE as u8 => Ok(Self::REPNE),
+            x if x == Self::REP as u8 => Ok(Self::REP),
+            x if x == Self::CALL as u8 => Ok(Self::CALL),
+            x if x == Self::CALL_FAR as u8 => Ok(Self::CALL_FAR),
+            x if x == Self::RET as u8 => Ok(Self::RET),
+            x if x == Self::RET_IMM as u8 => Ok(Self::RET_IMM),
+            x if x == Self::RETF as u8 => Ok(Self::RETF),
+            x if x == Self::RETF_IMM as u8 => Ok(Self::RETF_IMM),
+            x if (0xE4..=0xE5).contains(&x) => Ok(Self::IN_IMM),
+            x if (0xE6..=0xE7).contains(&x) => Ok(Self::OUT_IMM),
+            x if (0xEC..=0xED).contains(&x) => Ok(Self::IN_DX),
+            x if (0xEE..=0xEF).contains(&x) => Ok(Self::OUT_DX),
+            x if x == Self::INT3 as u8 => Ok(Self::INT3),
+            x if x == Self::INT as u8 => Ok(Self::INT),
+            x if x == Self::INTO as u8 => Ok(Self::INTO),
+            x if x == Self::IRET as u8 => Ok(Self::IRET),
+            x if x == Self::HLT as u8 => Ok(Self::HLT),
+            x if x == Self::CMC as u8 => Ok(Self::CMC),
+            x if x == Self::CLC as u8 => Ok(Self::CLC),
+            x if x == Self::STC as u8 => Ok(Self::STC),
+            x if x == Self::CLI as u8 => Ok(Self::CLI),
+            x if x == Self::STI as u8 => Ok(Self::STI),
+            x if x == Self::CLD as u8 => Ok(Self::CLD),
+            x if x == Self::STD as u8 => Ok(Self::STD),
+            x if x == Self::PUSHF as u8 => Ok(Self::PUSHF),
+            x if x == Self::POPF as u8 => Ok(Self::POPF),
             x if x == Self::JMP as u8 => Ok(Self::JMP),
             x if x == Self::JMP_FAR as u8 => Ok(Self::JMP_FAR),
             x if x == Self::JMP_SHORT as u8 => Ok(Self::JMP_SHORT),
+            x if x == Self::JO as u8 => Ok(Self::JO),
+            x if x == Self::JNO as u8 => Ok(Self::JNO),
+            x if x == Self::JC as u8 => Ok(Self::JC),
+            x if x == Self::JNC as u8 => Ok(Self::JNC),
             x if x == Self::JZ as u8 => Ok(Self::JZ),
             x if x == Self::JNZ as u8 => Ok(Self::JNZ),
-            _ => Err(format!("Invalid opcode: {:#x}", value)),
+            x if x == Self::JBE as u8 => Ok(Self::JBE),
+            x if x == Self::JA as u8 => Ok(Self::JA),
+            x if x == Self::JS as u8 => Ok(Self::JS),
+            x if x == Self::JNS as u8 => Ok(Self::JNS),
+            x if x == Self::JP as u8 => Ok(Self::JP),
+            x if x == Self::JNP as u8 => Ok(Self::JNP),
+            x if x == Self::JL as u8 => Ok(Self::JL),
+            x if x == Self::JGE as u8 => Ok(Self::JGE),
+            x if x == Self::JLE as u8 => Ok(Self::JLE),
+            x if x == Self::JG as u8 => Ok(Self::JG),
+            x if x == Self::LOOPNZ as u8 => Ok(Self::LOOPNZ),
+            x if x == Self::LOOPZ as u8 => Ok(Self::LOOPZ),
+            x if x == Self::LOOP as u8 => Ok(Self::LOOP),
+            x if x == Self::JCXZ as u8 => Ok(Self::JCXZ),
+            _ => Err(DecodeError::InvalidOpcode(value)),
         }
     }
 }
\ No newline at end of file
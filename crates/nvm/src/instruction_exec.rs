@@ -1,155 +1,601 @@
-use crate::instruction::{Instruction, MovMemOperand};
+use crate::cpu::CpuModel;
+use crate::instruction::{Instruction, IoPort, MovMemOperand, RepPrefix, ShiftCount};
 use crate::Machine;
-use crate::modrm::Operand;
+use crate::error::MachineError;
+use crate::modrm::{MemAddress, Operand};
 use crate::register::{Flag, Register};
 
 impl Machine {
 
-    pub fn run_instruction(&mut self, instruction: Instruction) {
+    pub fn run_instruction(&mut self, instruction: Instruction) -> Result<(), MachineError> {
         match instruction {
+            Instruction::IgnoredPrefixes(_, instruction) => self.run_instruction(*instruction)?,
             Instruction::Noop => {},
             Instruction::MovImm8(register, val) => self.set_register(register, val as u16),
             Instruction::MovImm16(register, val) => self.set_register(register, val),
+            Instruction::MovImm32(register, val) => self.set_register32(register, val),
             Instruction::Mov(dest, src) => {
-                self.apply_binary_op(dest, src, |_, b| b);
+                let is_8bit = Self::is_8bit_operation(dest, src);
+                self.apply_binary_op(dest, src, is_8bit, |_, _, b| b)?;
             },
+            Instruction::Mov32(dest, src) => {
+                self.apply_binary_op32(dest, src, |_, _, b| b)?;
+            }
+            Instruction::Movzx(reg, src, is_8bit) => {
+                let value = self.read_operand(src, is_8bit)?;
+                self.set_register32(reg, value as u32);
+            }
+            Instruction::Movsx(reg, src, is_8bit) => {
+                let value = self.read_operand(src, is_8bit)?;
+                let value = if is_8bit { value as u8 as i8 as i32 } else { value as i16 as i32 };
+                self.set_register32(reg, value as u32);
+            }
             Instruction::MovAccMem(dest, src) => {
                 match (dest, src) {
+                    (MovMemOperand::Register(reg), MovMemOperand::MemoryPtr(ptr)) if reg.is_32bit() => {
+                        let value = self.read_dword_at(Register::DS, ptr)?;
+                        self.set_register32(reg, value);
+                    }
+                    (MovMemOperand::Register(reg), MovMemOperand::SegmentedMemoryPtr(segment, ptr)) if reg.is_32bit() => {
+                        let value = self.read_dword_at(segment, ptr)?;
+                        self.set_register32(reg, value);
+                    }
+                    (MovMemOperand::MemoryPtr(ptr), MovMemOperand::Register(reg)) if reg.is_32bit() => {
+                        self.write_dword_at(Register::DS, ptr, self.get_register32(reg))?;
+                    }
+                    (MovMemOperand::SegmentedMemoryPtr(segment, ptr), MovMemOperand::Register(reg)) if reg.is_32bit() => {
+                        self.write_dword_at(segment, ptr, self.get_register32(reg))?;
+                    }
                     (MovMemOperand::Register(reg), MovMemOperand::MemoryPtr(ptr)) => {
-                        if reg.is_8bit() {
-                            self.set_register(reg, self.memory.read_byte(ptr as usize) as u16);
-                        } else {
-                            self.set_register(reg, self.memory.read_word(ptr as usize));
-                        }
+                        let value = self.read_memory(Register::DS, ptr, reg.is_8bit())?;
+                        self.set_register(reg, value);
+                    }
+                    (MovMemOperand::Register(reg), MovMemOperand::SegmentedMemoryPtr(segment, ptr)) => {
+                        let value = self.read_memory(segment, ptr, reg.is_8bit())?;
+                        self.set_register(reg, value);
                     }
                     (MovMemOperand::MemoryPtr(ptr), MovMemOperand::Register(reg)) => {
-                        if reg.is_8bit() {
-                            self.memory.write_byte(ptr as usize, self.get_register(reg) as u8);
-                        } else {
-                            self.memory.write_word(ptr as usize, self.get_register(reg));
-                        }
+                        self.write_memory(Register::DS, ptr, self.get_register(reg), reg.is_8bit())?;
+                    }
+                    (MovMemOperand::SegmentedMemoryPtr(segment, ptr), MovMemOperand::Register(reg)) => {
+                        self.write_memory(segment, ptr, self.get_register(reg), reg.is_8bit())?;
                     }
                     (_, _) => unreachable!()
                 }
             },
-            Instruction::Push(reg) => {
-                self.set_register(Register::SP, self.get_register(Register::SP) - 2);
-                self.memory.write_word(self.get_register(Register::SP) as usize, self.get_register(reg));
-            },
+            Instruction::Push(reg) if reg.is_32bit() => {
+                // the high word goes first so the dword ends up little endian at SS:SP
+                let value = self.get_register32(reg);
+                self.push_word((value >> 16) as u16)?;
+                self.push_word(value as u16)?;
+            }
+            Instruction::Push(reg) => self.push_word(self.get_register(reg))?,
+            Instruction::PushRm(operand) => {
+                let value = self.read_operand(operand, false)?;
+                self.push_word(value)?;
+            }
+            Instruction::PushImm8(val) => self.push_word(val as i8 as u16)?,
+            Instruction::PushImm16(val) => self.push_word(val)?,
+            Instruction::Pusha => {
+                // SP is pushed with its value from before the first push
+                let sp = self.get_register(Register::SP);
+                for reg in [Register::AX, Register::CX, Register::DX, Register::BX] {
+                    self.push_word(self.get_register(reg))?;
+                }
+                self.push_word(sp)?;
+                for reg in [Register::BP, Register::SI, Register::DI] {
+                    self.push_word(self.get_register(reg))?;
+                }
+            }
+            Instruction::Popa => {
+                // the saved SP is skipped
+                for reg in [Register::DI, Register::SI, Register::BP, Register::SP, Register::BX, Register::DX, Register::CX, Register::AX] {
+                    let value = self.pop_word()?;
+                    if reg != Register::SP {
+                        self.set_register(reg, value);
+                    }
+                }
+            }
+            Instruction::Pop(reg) if reg.is_32bit() => {
+                let low = self.pop_word()? as u32;
+                let high = self.pop_word()? as u32;
+                self.set_register32(reg, (high << 16) | low);
+            }
             Instruction::Pop(reg) => {
-                self.set_register(reg, self.memory.read_word(self.get_register(Register::SP) as usize));
-                self.set_register(Register::SP, self.get_register(Register::SP) + 2);
+                let value = self.pop_word()?;
+                self.set_register(reg, value);
             },
-            Instruction::Add(dest, src, ..) => {
-                let (.., result) = self.apply_binary_op(dest, src, |a, b| a.wrapping_add(b));
-                self.update_zero_flag(result);
+            Instruction::Lea(reg, Operand::Memory(mem_addr)) => {
+                self.set_register(reg, self.get_offset_from_mem_address(mem_addr));
+            }
+            Instruction::Lds(reg, Operand::Memory(mem_addr)) => {
+                self.load_far_pointer(reg, Register::DS, mem_addr)?;
+            }
+            Instruction::Les(reg, Operand::Memory(mem_addr)) => {
+                self.load_far_pointer(reg, Register::ES, mem_addr)?;
+            }
+            Instruction::Lea(..) | Instruction::Lds(..) | Instruction::Les(..) => unreachable!(),
+            Instruction::Xchg(dest, src, is_8bit) => {
+                let lhs = self.read_operand(dest, is_8bit)?;
+                let rhs = self.read_operand(src, is_8bit)?;
+                self.write_operand(dest, rhs, is_8bit)?;
+                self.write_operand(src, lhs, is_8bit)?;
+            }
+            Instruction::Xchg32(dest, src) => {
+                let lhs = self.read_operand32(dest)?;
+                let rhs = self.read_operand32(src)?;
+                self.write_operand32(dest, rhs)?;
+                self.write_operand32(src, lhs)?;
+            }
+            Instruction::XchgAcc(reg) if reg.is_32bit() => {
+                let eax = self.get_register32(Register::EAX);
+                self.set_register32(Register::EAX, self.get_register32(reg));
+                self.set_register32(reg, eax);
+            }
+            Instruction::XchgAcc(reg) => {
+                let ax = self.get_register(Register::AX);
+                self.set_register(Register::AX, self.get_register(reg));
+                self.set_register(reg, ax);
+            }
+            Instruction::Xlat(segment) => {
+                let offset = self.get_register(Register::BX).wrapping_add(self.get_register(Register::AL));
+                let value = self.read_byte_at(segment.unwrap_or(Register::DS), offset)?;
+                self.set_register(Register::AL, value as u16);
+            }
+            Instruction::Cbw => {
+                self.set_register(Register::AX, self.get_register(Register::AL) as u8 as i8 as u16);
+            }
+            Instruction::Cwd => {
+                let dx = if self.get_register(Register::AX) & 0x8000 != 0 { 0xFFFF } else { 0 };
+                self.set_register(Register::DX, dx);
+            }
+            Instruction::Cwde => {
+                self.set_register32(Register::EAX, self.get_register(Register::AX) as i16 as i32 as u32);
+            }
+            Instruction::Cdq => {
+                let edx = if self.get_register32(Register::EAX) & 0x80000000 != 0 { 0xFFFFFFFF } else { 0 };
+                self.set_register32(Register::EDX, edx);
+            }
+            Instruction::Lahf => {
+                self.set_register(Register::AH, self.get_register(Register::F) & 0x00FF);
+            }
+            Instruction::Sahf => {
+                // only SF, ZF, AF, PF and CF are loaded from AH
+                let mask = Flag::SIGN as u16 | Flag::ZERO as u16 | Flag::AUXILIARY as u16
+                    | Flag::PARITY as u16 | Flag::CARRY as u16;
+                let flags = (self.get_register(Register::F) & !mask) | (self.get_register(Register::AH) & mask);
+                self.set_register(Register::F, flags);
+            }
+            Instruction::Add(dest, src, is_8bit) => {
+                self.apply_binary_op(dest, src, is_8bit, |machine, a, b| {
+                    machine.add_with_flags(a, b, false, is_8bit)
+                })?;
             }
             Instruction::AddAcc8(val) => {
-                let result = self.get_register(Register::AL).wrapping_add(val as u16);
+                let result = self.add_with_flags(self.get_register(Register::AL), val as u16, false, true);
                 self.set_register(Register::AL, result);
-                self.update_zero_flag(result);
             }
             Instruction::AddAcc16(val) => {
-                let result = self.get_register(Register::AX).wrapping_add(val);
+                let result = self.add_with_flags(self.get_register(Register::AX), val, false, false);
                 self.set_register(Register::AX, result);
-                self.update_zero_flag(result);
             }
-            Instruction::Sub(dest, src, ..) => {
-                let (.., result) = self.apply_binary_op(dest, src, |a, b| a.wrapping_sub(b));
-                self.update_zero_flag(result);
+            Instruction::Add32(dest, src) => {
+                self.apply_binary_op32(dest, src, |machine, a, b| machine.add_with_flags32(a, b, false))?;
+            }
+            Instruction::AddAcc32(val) => {
+                let result = self.add_with_flags32(self.get_register32(Register::EAX), val, false);
+                self.set_register32(Register::EAX, result);
+            }
+            Instruction::Sub(dest, src, is_8bit) => {
+                self.apply_binary_op(dest, src, is_8bit, |machine, a, b| {
+                    machine.sub_with_flags(a, b, false, is_8bit)
+                })?;
             }
             Instruction::SubAcc8(val) => {
-                let result = self.get_register(Register::AL).wrapping_sub(val as u16);
+                let result = self.sub_with_flags(self.get_register(Register::AL), val as u16, false, true);
                 self.set_register(Register::AL, result);
-                self.update_zero_flag(result);
             }
             Instruction::SubAcc16(val) => {
-                let result = self.get_register(Register::AX).wrapping_sub(val);
+                let result = self.sub_with_flags(self.get_register(Register::AX), val, false, false);
                 self.set_register(Register::AX, result);
-                self.update_zero_flag(result);
+            }
+            Instruction::Sub32(dest, src) => {
+                self.apply_binary_op32(dest, src, |machine, a, b| machine.sub_with_flags32(a, b, false))?;
+            }
+            Instruction::SubAcc32(val) => {
+                let result = self.sub_with_flags32(self.get_register32(Register::EAX), val, false);
+                self.set_register32(Register::EAX, result);
+            }
+            Instruction::Inc(reg) if reg.is_32bit() => {
+                let carry = self.get_flag(Flag::CARRY);
+                let result = self.add_with_flags32(self.get_register32(reg), 1, false);
+                self.set_register32(reg, result);
+                self.set_flag(Flag::CARRY, carry);
             }
             Instruction::Inc(reg) => {
-                let result = self.get_register(reg).wrapping_add(1);
+                // INC and DEC leave CF untouched
+                let carry = self.get_flag(Flag::CARRY);
+                let result = self.add_with_flags(self.get_register(reg), 1, false, reg.is_8bit());
                 self.set_register(reg, result);
-                self.update_zero_flag(result);
+                self.set_flag(Flag::CARRY, carry);
+            }
+            Instruction::Dec(reg) if reg.is_32bit() => {
+                let carry = self.get_flag(Flag::CARRY);
+                let result = self.sub_with_flags32(self.get_register32(reg), 1, false);
+                self.set_register32(reg, result);
+                self.set_flag(Flag::CARRY, carry);
             }
             Instruction::Dec(reg) => {
-                let result = self.get_register(reg).wrapping_sub(1);
+                let carry = self.get_flag(Flag::CARRY);
+                let result = self.sub_with_flags(self.get_register(reg), 1, false, reg.is_8bit());
                 self.set_register(reg, result);
-                self.update_zero_flag(result);
+                self.set_flag(Flag::CARRY, carry);
+            }
+            Instruction::Inc8(operand) => {
+                let carry = self.get_flag(Flag::CARRY);
+                self.apply_unary_op(operand, true, |machine, a| machine.add_with_flags(a, 1, false, true))?;
+                self.set_flag(Flag::CARRY, carry);
             }
-            Instruction::And(dest, src) => {
-                let (.., result) = self.apply_binary_op(dest, src, |a, b| a & b);
-                self.update_zero_flag(result);
+            Instruction::Inc16(operand) => {
+                let carry = self.get_flag(Flag::CARRY);
+                self.apply_unary_op(operand, false, |machine, a| machine.add_with_flags(a, 1, false, false))?;
+                self.set_flag(Flag::CARRY, carry);
+            }
+            Instruction::Dec8(operand) => {
+                let carry = self.get_flag(Flag::CARRY);
+                self.apply_unary_op(operand, true, |machine, a| machine.sub_with_flags(a, 1, false, true))?;
+                self.set_flag(Flag::CARRY, carry);
+            }
+            Instruction::Dec16(operand) => {
+                let carry = self.get_flag(Flag::CARRY);
+                self.apply_unary_op(operand, false, |machine, a| machine.sub_with_flags(a, 1, false, false))?;
+                self.set_flag(Flag::CARRY, carry);
+            }
+            Instruction::Inc32(operand) => {
+                let carry = self.get_flag(Flag::CARRY);
+                self.apply_unary_op32(operand, |machine, a| machine.add_with_flags32(a, 1, false))?;
+                self.set_flag(Flag::CARRY, carry);
+            }
+            Instruction::Dec32(operand) => {
+                let carry = self.get_flag(Flag::CARRY);
+                self.apply_unary_op32(operand, |machine, a| machine.sub_with_flags32(a, 1, false))?;
+                self.set_flag(Flag::CARRY, carry);
+            }
+            // OF is undefined after the decimal adjust instructions and left untouched
+            Instruction::Daa => {
+                let al = self.get_register(Register::AL);
+                let carry = self.get_flag(Flag::CARRY);
+                let mut result = al;
+
+                let adjust_low = al & 0x0F > 9 || self.get_flag(Flag::AUXILIARY);
+                if adjust_low {
+                    result += 0x06;
+                }
+                let adjust_high = al > 0x99 || carry;
+                if adjust_high {
+                    result += 0x60;
+                }
+
+                let result = result & 0x00FF;
+                self.set_register(Register::AL, result);
+                self.set_flag(Flag::AUXILIARY, adjust_low);
+                self.set_flag(Flag::CARRY, adjust_high);
+                self.update_result_flags(result, true);
+            }
+            Instruction::Das => {
+                let al = self.get_register(Register::AL);
+                let carry = self.get_flag(Flag::CARRY);
+                let mut result = al;
+                let mut borrow = false;
+
+                let adjust_low = al & 0x0F > 9 || self.get_flag(Flag::AUXILIARY);
+                if adjust_low {
+                    borrow = al < 0x06;
+                    result = result.wrapping_sub(0x06);
+                }
+                let adjust_high = al > 0x99 || carry;
+                if adjust_high {
+                    result = result.wrapping_sub(0x60);
+                }
+
+                let result = result & 0x00FF;
+                self.set_register(Register::AL, result);
+                self.set_flag(Flag::AUXILIARY, adjust_low);
+                self.set_flag(Flag::CARRY, adjust_high || borrow);
+                self.update_result_flags(result, true);
+            }
+            // only AF and CF are defined after AAA and AAS
+            Instruction::Aaa => {
+                let adjust = self.get_register(Register::AL) & 0x0F > 9 || self.get_flag(Flag::AUXILIARY);
+                if adjust {
+                    // the 8086 adjusts AL and AH separately, the carry out of AL is not propagated
+                    self.set_register(Register::AL, (self.get_register(Register::AL) + 0x06) & 0x00FF);
+                    self.set_register(Register::AH, (self.get_register(Register::AH) + 0x01) & 0x00FF);
+                }
+
+                self.set_register(Register::AL, self.get_register(Register::AL) & 0x0F);
+                self.set_flag(Flag::AUXILIARY, adjust);
+                self.set_flag(Flag::CARRY, adjust);
+            }
+            Instruction::Aas => {
+                let adjust = self.get_register(Register::AL) & 0x0F > 9 || self.get_flag(Flag::AUXILIARY);
+                if adjust {
+                    self.set_register(Register::AL, self.get_register(Register::AL).wrapping_sub(0x06) & 0x00FF);
+                    self.set_register(Register::AH, self.get_register(Register::AH).wrapping_sub(0x01) & 0x00FF);
+                }
+
+                self.set_register(Register::AL, self.get_register(Register::AL) & 0x0F);
+                self.set_flag(Flag::AUXILIARY, adjust);
+                self.set_flag(Flag::CARRY, adjust);
+            }
+            Instruction::Aam(base) => {
+                if base == 0 {
+                    return self.interrupt(Self::DIVIDE_ERROR_VECTOR);
+                }
+
+                let al = self.get_register(Register::AL);
+                self.set_register(Register::AH, al / base as u16);
+                self.set_register(Register::AL, al % base as u16);
+                self.update_result_flags(self.get_register(Register::AL), true);
+            }
+            Instruction::Aad(base) => {
+                let ah = self.get_register(Register::AH);
+                let result = (self.get_register(Register::AL) + ah * base as u16) & 0x00FF;
+                self.set_register(Register::AX, result);
+                self.update_result_flags(result, true);
+            }
+            Instruction::And(dest, src, is_8bit) => {
+                self.apply_binary_op(dest, src, is_8bit, |machine, a, b| {
+                    machine.logic_with_flags(a & b, is_8bit)
+                })?;
             }
             Instruction::AndAcc8(val) => {
-                let result = self.get_register(Register::AL) & val as u16;
+                let result = self.logic_with_flags(self.get_register(Register::AL) & val as u16, true);
                 self.set_register(Register::AL, result);
-                self.update_zero_flag(result);
             }
             Instruction::AndAcc16(val) => {
-                let result = self.get_register(Register::AX) & val;
+                let result = self.logic_with_flags(self.get_register(Register::AX) & val, false);
                 self.set_register(Register::AX, result);
-                self.update_zero_flag(result);
             }
-            Instruction::Or(dest, src) => {
-                let (.., result) = self.apply_binary_op(dest, src, |a, b| a | b);
-                self.update_zero_flag(result);
+            Instruction::And32(dest, src) => {
+                self.apply_binary_op32(dest, src, |machine, a, b| machine.logic_with_flags32(a & b))?;
+            }
+            Instruction::AndAcc32(val) => {
+                let result = self.logic_with_flags32(self.get_register32(Register::EAX) & val);
+                self.set_register32(Register::EAX, result);
+            }
+            Instruction::Or(dest, src, is_8bit) => {
+                self.apply_binary_op(dest, src, is_8bit, |machine, a, b| {
+                    machine.logic_with_flags(a | b, is_8bit)
+                })?;
             }
             Instruction::OrAcc8(val) => {
-                let result = self.get_register(Register::AL) | val as u16;
+                let result = self.logic_with_flags(self.get_register(Register::AL) | val as u16, true);
                 self.set_register(Register::AL, result);
-                self.update_zero_flag(result);
             }
             Instruction::OrAcc16(val) => {
-                let result = self.get_register(Register::AX) | val;
+                let result = self.logic_with_flags(self.get_register(Register::AX) | val, false);
                 self.set_register(Register::AX, result);
-                self.update_zero_flag(result);
+            }
+            Instruction::Or32(dest, src) => {
+                self.apply_binary_op32(dest, src, |machine, a, b| machine.logic_with_flags32(a | b))?;
+            }
+            Instruction::OrAcc32(val) => {
+                let result = self.logic_with_flags32(self.get_register32(Register::EAX) | val);
+                self.set_register32(Register::EAX, result);
+            }
+            Instruction::Xor(dest, src, is_8bit) => {
+                self.apply_binary_op(dest, src, is_8bit, |machine, a, b| {
+                    machine.logic_with_flags(a ^ b, is_8bit)
+                })?;
+            }
+            Instruction::XorAcc8(val) => {
+                let result = self.logic_with_flags(self.get_register(Register::AL) ^ val as u16, true);
+                self.set_register(Register::AL, result);
+            }
+            Instruction::XorAcc16(val) => {
+                let result = self.logic_with_flags(self.get_register(Register::AX) ^ val, false);
+                self.set_register(Register::AX, result);
+            }
+            Instruction::Xor32(dest, src) => {
+                self.apply_binary_op32(dest, src, |machine, a, b| machine.logic_with_flags32(a ^ b))?;
+            }
+            Instruction::XorAcc32(val) => {
+                let result = self.logic_with_flags32(self.get_register32(Register::EAX) ^ val);
+                self.set_register32(Register::EAX, result);
+            }
+            Instruction::Adc(dest, src, is_8bit) => {
+                let carry = self.get_flag(Flag::CARRY);
+                self.apply_binary_op(dest, src, is_8bit, |machine, a, b| {
+                    machine.add_with_flags(a, b, carry, is_8bit)
+                })?;
+            }
+            Instruction::AdcAcc8(val) => {
+                let carry = self.get_flag(Flag::CARRY);
+                let result = self.add_with_flags(self.get_register(Register::AL), val as u16, carry, true);
+                self.set_register(Register::AL, result);
+            }
+            Instruction::AdcAcc16(val) => {
+                let carry = self.get_flag(Flag::CARRY);
+                let result = self.add_with_flags(self.get_register(Register::AX), val, carry, false);
+                self.set_register(Register::AX, result);
+            }
+            Instruction::Adc32(dest, src) => {
+                let carry = self.get_flag(Flag::CARRY);
+                self.apply_binary_op32(dest, src, |machine, a, b| machine.add_with_flags32(a, b, carry))?;
+            }
+            Instruction::AdcAcc32(val) => {
+                let carry = self.get_flag(Flag::CARRY);
+                let result = self.add_with_flags32(self.get_register32(Register::EAX), val, carry);
+                self.set_register32(Register::EAX, result);
+            }
+            Instruction::Sbb(dest, src, is_8bit) => {
+                let borrow = self.get_flag(Flag::CARRY);
+                self.apply_binary_op(dest, src, is_8bit, |machine, a, b| {
+                    machine.sub_with_flags(a, b, borrow, is_8bit)
+                })?;
+            }
+            Instruction::SbbAcc8(val) => {
+                let borrow = self.get_flag(Flag::CARRY);
+                let result = self.sub_with_flags(self.get_register(Register::AL), val as u16, borrow, true);
+                self.set_register(Register::AL, result);
+            }
+            Instruction::SbbAcc16(val) => {
+                let borrow = self.get_flag(Flag::CARRY);
+                let result = self.sub_with_flags(self.get_register(Register::AX), val, borrow, false);
+                self.set_register(Register::AX, result);
+            }
+            Instruction::Sbb32(dest, src) => {
+                let borrow = self.get_flag(Flag::CARRY);
+                self.apply_binary_op32(dest, src, |machine, a, b| machine.sub_with_flags32(a, b, borrow))?;
+            }
+            Instruction::SbbAcc32(val) => {
+                let borrow = self.get_flag(Flag::CARRY);
+                let result = self.sub_with_flags32(self.get_register32(Register::EAX), val, borrow);
+                self.set_register32(Register::EAX, result);
+            }
+            // CMP and TEST only update flags, the destination is left untouched
+            Instruction::Cmp(dest, src, is_8bit) => {
+                self.evaluate_binary_op(dest, src, is_8bit, |machine, a, b| {
+                    machine.sub_with_flags(a, b, false, is_8bit)
+                })?;
+            }
+            Instruction::CmpAcc8(val) => {
+                self.sub_with_flags(self.get_register(Register::AL), val as u16, false, true);
+            }
+            Instruction::CmpAcc16(val) => {
+                self.sub_with_flags(self.get_register(Register::AX), val, false, false);
+            }
+            Instruction::Cmp32(dest, src) => {
+                self.evaluate_binary_op32(dest, src, |machine, a, b| machine.sub_with_flags32(a, b, false))?;
+            }
+            Instruction::CmpAcc32(val) => {
+                self.sub_with_flags32(self.get_register32(Register::EAX), val, false);
+            }
+            Instruction::Test(dest, src, is_8bit) => {
+                self.evaluate_binary_op(dest, src, is_8bit, |machine, a, b| {
+                    machine.logic_with_flags(a & b, is_8bit)
+                })?;
+            }
+            Instruction::TestAcc8(val) => {
+                self.logic_with_flags(self.get_register(Register::AL) & val as u16, true);
+            }
+            Instruction::TestAcc16(val) => {
+                self.logic_with_flags(self.get_register(Register::AX) & val, false);
+            }
+            Instruction::Test32(dest, src) => {
+                self.evaluate_binary_op32(dest, src, |machine, a, b| machine.logic_with_flags32(a & b))?;
+            }
+            Instruction::TestAcc32(val) => {
+                self.logic_with_flags32(self.get_register32(Register::EAX) & val);
+            }
+            Instruction::Not8(operand) => {
+                // NOT does not affect any flags
+                self.apply_unary_op(operand, true, |_, a| !a)?;
+            }
+            Instruction::Not16(operand) => {
+                self.apply_unary_op(operand, false, |_, a| !a)?;
+            }
+            Instruction::Neg8(operand) => {
+                self.apply_unary_op(operand, true, |machine, a| machine.sub_with_flags(0, a, false, true))?;
+            }
+            Instruction::Neg16(operand) => {
+                self.apply_unary_op(operand, false, |machine, a| machine.sub_with_flags(0, a, false, false))?;
+            }
+            Instruction::Not32(operand) => {
+                self.apply_unary_op32(operand, |_, a| !a)?;
+            }
+            Instruction::Neg32(operand) => {
+                self.apply_unary_op32(operand, |machine, a| machine.sub_with_flags32(0, a, false))?;
+            }
+            Instruction::Rol(operand, count, is_8bit) => {
+                let count = self.resolve_shift_count(count);
+                self.apply_unary_op(operand, is_8bit, |machine, a| machine.rotate_left(a, count, false, is_8bit))?;
+            }
+            Instruction::Ror(operand, count, is_8bit) => {
+                let count = self.resolve_shift_count(count);
+                self.apply_unary_op(operand, is_8bit, |machine, a| machine.rotate_right(a, count, false, is_8bit))?;
+            }
+            Instruction::Rcl(operand, count, is_8bit) => {
+                let count = self.resolve_shift_count(count);
+                self.apply_unary_op(operand, is_8bit, |machine, a| machine.rotate_left(a, count, true, is_8bit))?;
+            }
+            Instruction::Rcr(operand, count, is_8bit) => {
+                let count = self.resolve_shift_count(count);
+                self.apply_unary_op(operand, is_8bit, |machine, a| machine.rotate_right(a, count, true, is_8bit))?;
+            }
+            Instruction::Shl(operand, count, is_8bit) => {
+                let count = self.resolve_shift_count(count);
+                self.apply_unary_op(operand, is_8bit, |machine, a| machine.shift_left(a, count, is_8bit))?;
+            }
+            Instruction::Shr(operand, count, is_8bit) => {
+                let count = self.resolve_shift_count(count);
+                self.apply_unary_op(operand, is_8bit, |machine, a| machine.shift_right(a, count, false, is_8bit))?;
+            }
+            Instruction::Sar(operand, count, is_8bit) => {
+                let count = self.resolve_shift_count(count);
+                self.apply_unary_op(operand, is_8bit, |machine, a| machine.shift_right(a, count, true, is_8bit))?;
+            }
+            Instruction::Rol32(operand, count) => {
+                let count = self.resolve_shift_count(count);
+                self.apply_unary_op32(operand, |machine, a| machine.rotate_left32(a, count, false))?;
+            }
+            Instruction::Ror32(operand, count) => {
+                let count = self.resolve_shift_count(count);
+                self.apply_unary_op32(operand, |machine, a| machine.rotate_right32(a, count, false))?;
+            }
+            Instruction::Rcl32(operand, count) => {
+                let count = self.resolve_shift_count(count);
+                self.apply_unary_op32(operand, |machine, a| machine.rotate_left32(a, count, true))?;
+            }
+            Instruction::Rcr32(operand, count) => {
+                let count = self.resolve_shift_count(count);
+                self.apply_unary_op32(operand, |machine, a| machine.rotate_right32(a, count, true))?;
+            }
+            Instruction::Shl32(operand, count) => {
+                let count = self.resolve_shift_count(count);
+                self.apply_unary_op32(operand, |machine, a| machine.shift_left32(a, count))?;
+            }
+            Instruction::Shr32(operand, count) => {
+                let count = self.resolve_shift_count(count);
+                self.apply_unary_op32(operand, |machine, a| machine.shift_right32(a, count, false))?;
+            }
+            Instruction::Sar32(operand, count) => {
+                let count = self.resolve_shift_count(count);
+                self.apply_unary_op32(operand, |machine, a| machine.shift_right32(a, count, true))?;
             }
             Instruction::Mul8(mlt_src) => {
-                let multiplier = match mlt_src {
-                    Operand::Register(reg) => {
-                        self.get_register(reg)
-                    }
-                    Operand::Memory(addr) => {
-                        self.memory.read_byte(self.get_ptr_from_mem_address(addr)) as u16
-                    }
-                };
+                let multiplier = self.read_operand(mlt_src, true)?;
 
                 let al = self.get_register(Register::AL) as u8;
                 let product = (al as u16) * multiplier;
 
                 self.set_register(Register::AL, product & 0x00FF);
                 self.set_register(Register::AH, product >> 8);
+
+                // CF and OF signal that the upper half of the product is significant
+                self.set_flag(Flag::CARRY, product >> 8 != 0);
+                self.set_flag(Flag::OVERFLOW, product >> 8 != 0);
             }
             Instruction::Mul16(mlt_src) => {
-                let multiplier = match mlt_src {
-                    Operand::Register(reg) => {
-                        self.get_register(reg)
-                    }
-                    Operand::Memory(addr) => {
-                        self.memory.read_word(self.get_ptr_from_mem_address(addr))
-                    }
-                };
+                let multiplier = self.read_operand(mlt_src, false)?;
 
                 let ax = self.get_register(Register::AX);
                 let product = (ax as u32) * (multiplier as u32);
 
                 self.set_register(Register::AX, (product & 0xFFFF) as u16);
                 self.set_register(Register::DX, (product >> 16) as u16);
+
+                self.set_flag(Flag::CARRY, product >> 16 != 0);
+                self.set_flag(Flag::OVERFLOW, product >> 16 != 0);
             }
             Instruction::Div8(div_src) => {
                 let dividend = self.get_register(Register::AX);
-                let divisor = match div_src {
-                    Operand::Register(reg) => {
-                        self.get_register(reg)
-                    }
-                    Operand::Memory(addr) => {
-                        self.memory.read_word(self.get_ptr_from_mem_address(addr))
-                    }
-                };
+                let divisor = self.read_operand(div_src, true)?;
 
-                let quotient = dividend / divisor;
+                let Some(quotient) = dividend.checked_div(divisor).filter(|q| *q <= 0xFF) else {
+                    return self.interrupt(Self::DIVIDE_ERROR_VECTOR);
+                };
                 let remainder = dividend % divisor;
 
                 self.set_register(Register::AL, quotient);
@@ -157,21 +603,329 @@ impl Machine {
             }
             Instruction::Div16(div_src) => {
                 let dividend = (self.get_register(Register::DX) as u32) << 16 | self.get_register(Register::AX) as u32;
-                let divisor = match div_src {
-                    Operand::Register(reg) => {
-                        self.get_register(reg)
-                    }
-                    Operand::Memory(addr) => {
-                        self.memory.read_word(self.get_ptr_from_mem_address(addr))
-                    }
+                let divisor = self.read_operand(div_src, false)? as u32;
+
+                let Some(quotient) = dividend.checked_div(divisor).filter(|q| *q <= 0xFFFF) else {
+                    return self.interrupt(Self::DIVIDE_ERROR_VECTOR);
                 };
+                let remainder = dividend % divisor;
+
+                self.set_register(Register::AX, quotient as u16);
+                self.set_register(Register::DX, remainder as u16);
+            }
+            Instruction::Imul8(mlt_src) => {
+                let multiplier = self.read_operand(mlt_src, true)? as u8 as i8;
+
+                let al = self.get_register(Register::AL) as u8 as i8;
+                let product = (al as i16) * (multiplier as i16);
+
+                self.set_register(Register::AX, product as u16);
+
+                // CF and OF signal that AH is not just the sign extension of AL
+                let overflow = product != product as i8 as i16;
+                self.set_flag(Flag::CARRY, overflow);
+                self.set_flag(Flag::OVERFLOW, overflow);
+            }
+            Instruction::Imul16(mlt_src) => {
+                let multiplier = self.read_operand(mlt_src, false)? as i16;
 
-                let quotient = dividend / divisor as u32;
-                let remainder = dividend % divisor as u32;
+                let ax = self.get_register(Register::AX) as i16;
+                let product = (ax as i32) * (multiplier as i32);
+
+                self.set_register(Register::AX, product as u16);
+                self.set_register(Register::DX, (product >> 16) as u16);
+
+                let overflow = product != product as i16 as i32;
+                self.set_flag(Flag::CARRY, overflow);
+                self.set_flag(Flag::OVERFLOW, overflow);
+            }
+            Instruction::ImulImm(reg, src, imm) => {
+                let lhs = self.read_operand(src, false)? as i16;
+                let rhs = self.read_operand(imm, false)? as i16;
+                let product = (lhs as i32) * (rhs as i32);
+
+                self.set_register(reg, product as u16);
+
+                let overflow = product != product as i16 as i32;
+                self.set_flag(Flag::CARRY, overflow);
+                self.set_flag(Flag::OVERFLOW, overflow);
+            }
+            Instruction::Idiv8(div_src) => {
+                let dividend = self.get_register(Register::AX) as i16 as i32;
+                let divisor = self.read_operand(div_src, true)? as u8 as i8 as i32;
+
+                // quotient rounds towards zero, remainder has the sign of the dividend
+                let Some(quotient) = dividend.checked_div(divisor).filter(|q| i8::try_from(*q).is_ok()) else {
+                    return self.interrupt(Self::DIVIDE_ERROR_VECTOR);
+                };
+                let remainder = dividend % divisor;
+
+                self.set_register(Register::AL, quotient as u16);
+                self.set_register(Register::AH, remainder as u16);
+            }
+            Instruction::Idiv16(div_src) => {
+                let dividend = ((self.get_register(Register::DX) as u32) << 16 | self.get_register(Register::AX) as u32) as i32 as i64;
+                let divisor = self.read_operand(div_src, false)? as i16 as i64;
+
+                let Some(quotient) = dividend.checked_div(divisor).filter(|q| i16::try_from(*q).is_ok()) else {
+                    return self.interrupt(Self::DIVIDE_ERROR_VECTOR);
+                };
+                let remainder = dividend % divisor;
 
                 self.set_register(Register::AX, quotient as u16);
                 self.set_register(Register::DX, remainder as u16);
             }
+            Instruction::Mul32(mlt_src) => {
+                let multiplier = self.read_operand32(mlt_src)?;
+
+                let eax = self.get_register32(Register::EAX);
+                let product = (eax as u64) * (multiplier as u64);
+
+                self.set_register32(Register::EAX, product as u32);
+                self.set_register32(Register::EDX, (product >> 32) as u32);
+
+                self.set_flag(Flag::CARRY, product >> 32 != 0);
+                self.set_flag(Flag::OVERFLOW, product >> 32 != 0);
+            }
+            Instruction::Div32(div_src) => {
+                let dividend = (self.get_register32(Register::EDX) as u64) << 32 | self.get_register32(Register::EAX) as u64;
+                let divisor = self.read_operand32(div_src)? as u64;
+
+                let Some(quotient) = dividend.checked_div(divisor).filter(|q| *q <= 0xFFFFFFFF) else {
+                    return self.interrupt(Self::DIVIDE_ERROR_VECTOR);
+                };
+                let remainder = dividend % divisor;
+
+                self.set_register32(Register::EAX, quotient as u32);
+                self.set_register32(Register::EDX, remainder as u32);
+            }
+            Instruction::Imul32(mlt_src) => {
+                let multiplier = self.read_operand32(mlt_src)? as i32;
+
+                let eax = self.get_register32(Register::EAX) as i32;
+                let product = (eax as i64) * (multiplier as i64);
+
+                self.set_register32(Register::EAX, product as u32);
+                self.set_register32(Register::EDX, (product >> 32) as u32);
+
+                let overflow = product != product as i32 as i64;
+                self.set_flag(Flag::CARRY, overflow);
+                self.set_flag(Flag::OVERFLOW, overflow);
+            }
+            Instruction::Idiv32(div_src) => {
+                let dividend = ((self.get_register32(Register::EDX) as u64) << 32 | self.get_register32(Register::EAX) as u64) as i64 as i128;
+                let divisor = self.read_operand32(div_src)? as i32 as i128;
+
+                let Some(quotient) = dividend.checked_div(divisor).filter(|q| i32::try_from(*q).is_ok()) else {
+                    return self.interrupt(Self::DIVIDE_ERROR_VECTOR);
+                };
+                let remainder = dividend % divisor;
+
+                self.set_register32(Register::EAX, quotient as u32);
+                self.set_register32(Register::EDX, remainder as u32);
+            }
+            Instruction::Movs(rep, segment, is_8bit) => {
+                self.repeat_string_op(rep, false, instruction.get_instr_size(), |machine| {
+                    let value = machine.read_memory(segment.unwrap_or(Register::DS), machine.get_register(Register::SI), is_8bit)?;
+                    machine.write_memory(Register::ES, machine.get_register(Register::DI), value, is_8bit)?;
+                    machine.advance_string_index(Register::SI, is_8bit);
+                    machine.advance_string_index(Register::DI, is_8bit);
+                    Ok(())
+                })?;
+            }
+            Instruction::Cmps(rep, segment, is_8bit) => {
+                self.repeat_string_op(rep, true, instruction.get_instr_size(), |machine| {
+                    let lhs = machine.read_memory(segment.unwrap_or(Register::DS), machine.get_register(Register::SI), is_8bit)?;
+                    let rhs = machine.read_memory(Register::ES, machine.get_register(Register::DI), is_8bit)?;
+                    machine.sub_with_flags(lhs, rhs, false, is_8bit);
+                    machine.advance_string_index(Register::SI, is_8bit);
+                    machine.advance_string_index(Register::DI, is_8bit);
+                    Ok(())
+                })?;
+            }
+            Instruction::Stos(rep, _, is_8bit) => {
+                self.repeat_string_op(rep, false, instruction.get_instr_size(), |machine| {
+                    let value = machine.get_register(Self::accumulator(is_8bit));
+                    machine.write_memory(Register::ES, machine.get_register(Register::DI), value, is_8bit)?;
+                    machine.advance_string_index(Register::DI, is_8bit);
+                    Ok(())
+                })?;
+            }
+            Instruction::Lods(rep, segment, is_8bit) => {
+                self.repeat_string_op(rep, false, instruction.get_instr_size(), |machine| {
+                    let value = machine.read_memory(segment.unwrap_or(Register::DS), machine.get_register(Register::SI), is_8bit)?;
+                    machine.set_register(Self::accumulator(is_8bit), value);
+                    machine.advance_string_index(Register::SI, is_8bit);
+                    Ok(())
+                })?;
+            }
+            Instruction::Scas(rep, _, is_8bit) => {
+                self.repeat_string_op(rep, true, instruction.get_instr_size(), |machine| {
+                    let lhs = machine.get_register(Self::accumulator(is_8bit));
+                    let rhs = machine.read_memory(Register::ES, machine.get_register(Register::DI), is_8bit)?;
+                    machine.sub_with_flags(lhs, rhs, false, is_8bit);
+                    machine.advance_string_index(Register::DI, is_8bit);
+                    Ok(())
+                })?;
+            }
+            Instruction::Ins(rep, _, is_8bit) => {
+                self.repeat_string_op(rep, false, instruction.get_instr_size(), |machine| {
+                    let port = machine.get_register(Register::DX);
+                    let value = if is_8bit {
+                        machine.read_io_byte(port) as u16
+                    } else {
+                        machine.read_io_word(port)
+                    };
+                    machine.write_memory(Register::ES, machine.get_register(Register::DI), value, is_8bit)?;
+                    machine.advance_string_index(Register::DI, is_8bit);
+                    Ok(())
+                })?;
+            }
+            Instruction::Outs(rep, segment, is_8bit) => {
+                self.repeat_string_op(rep, false, instruction.get_instr_size(), |machine| {
+                    let port = machine.get_register(Register::DX);
+                    let value = machine.read_memory(segment.unwrap_or(Register::DS), machine.get_register(Register::SI), is_8bit)?;
+                    if is_8bit {
+                        machine.write_io_byte(port, value as u8);
+                    } else {
+                        machine.write_io_word(port, value);
+                    }
+                    machine.advance_string_index(Register::SI, is_8bit);
+                    Ok(())
+                })?;
+            }
+            Instruction::CallNear(offset) => {
+                let ip = self.get_register(Register::IP);
+                self.push_word(ip)?;
+                self.set_register(Register::IP, (ip as i16).wrapping_add(offset) as u16);
+            }
+            Instruction::CallFar(segment, offset) => {
+                self.push_word(self.get_register(Register::CS))?;
+                self.push_word(self.get_register(Register::IP))?;
+                self.set_register(Register::CS, segment);
+                self.set_register(Register::IP, offset);
+            }
+            Instruction::CallNearIndirect(operand) => {
+                let target = self.read_operand(operand, false)?;
+                self.push_word(self.get_register(Register::IP))?;
+                self.set_register(Register::IP, target);
+            }
+            Instruction::CallFarIndirect(Operand::Memory(mem_addr)) => {
+                let (segment, offset) = self.read_far_pointer(mem_addr)?;
+                self.push_word(self.get_register(Register::CS))?;
+                self.push_word(self.get_register(Register::IP))?;
+                self.set_register(Register::CS, segment);
+                self.set_register(Register::IP, offset);
+            }
+            Instruction::Ret => {
+                let ip = self.pop_word()?;
+                self.set_register(Register::IP, ip);
+            }
+            Instruction::RetImm(bytes) => {
+                let ip = self.pop_word()?;
+                self.set_register(Register::IP, ip);
+                self.set_register(Register::SP, self.get_register(Register::SP).wrapping_add(bytes));
+            }
+            Instruction::RetFar => {
+                let ip = self.pop_word()?;
+                let cs = self.pop_word()?;
+                self.set_register(Register::IP, ip);
+                self.set_register(Register::CS, cs);
+            }
+            Instruction::RetFarImm(bytes) => {
+                let ip = self.pop_word()?;
+                let cs = self.pop_word()?;
+                self.set_register(Register::IP, ip);
+                self.set_register(Register::CS, cs);
+                self.set_register(Register::SP, self.get_register(Register::SP).wrapping_add(bytes));
+            }
+            Instruction::Enter(size, level) => {
+                let level = level % 32;
+                self.push_word(self.get_register(Register::BP))?;
+                let frame = self.get_register(Register::SP);
+
+                if level > 0 {
+                    // copy the frame pointers of the enclosing procedures, then link the new frame
+                    for _ in 1..level {
+                        let bp = self.get_register(Register::BP).wrapping_sub(2);
+                        self.set_register(Register::BP, bp);
+                        let value = self.read_word_at(Register::SS, bp).map_err(Self::stack_fault)?;
+                        self.push_word(value)?;
+                    }
+                    self.push_word(frame)?;
+                }
+
+                self.set_register(Register::BP, frame);
+                self.set_register(Register::SP, self.get_register(Register::SP).wrapping_sub(size));
+            }
+            Instruction::Leave => {
+                self.set_register(Register::SP, self.get_register(Register::BP));
+                let bp = self.pop_word()?;
+                self.set_register(Register::BP, bp);
+            }
+            Instruction::Bound(reg, Operand::Memory(mem_addr)) => {
+                let segment = Self::get_segment_from_mem_address(mem_addr);
+                let offset = self.get_offset_from_mem_address(mem_addr);
+                let lower = self.read_word_at(segment, offset)? as i16;
+                let upper = self.read_word_at(segment, offset.wrapping_add(2))? as i16;
+
+                let index = self.get_register(reg) as i16;
+                if index < lower || index > upper {
+                    // BOUND is a fault, the handler returns to the BOUND instruction itself
+                    self.set_register(
+                        Register::IP,
+                        self.get_register(Register::IP).wrapping_sub(instruction.get_instr_size()),
+                    );
+                    return self.interrupt(Self::BOUND_VECTOR);
+                }
+            }
+            Instruction::Bound(..) => unreachable!(),
+            Instruction::In(port, is_8bit) => {
+                let port = self.resolve_io_port(port);
+                if is_8bit {
+                    let value = self.read_io_byte(port);
+                    self.set_register(Register::AL, value as u16);
+                } else {
+                    let value = self.read_io_word(port);
+                    self.set_register(Register::AX, value);
+                }
+            }
+            Instruction::Out(port, is_8bit) => {
+                let port = self.resolve_io_port(port);
+                if is_8bit {
+                    self.write_io_byte(port, self.get_register(Register::AL) as u8);
+                } else {
+                    self.write_io_word(port, self.get_register(Register::AX));
+                }
+            }
+            Instruction::Int(vector) => return self.interrupt(vector),
+            Instruction::Int3 => return self.interrupt(Self::BREAKPOINT_VECTOR),
+            Instruction::Into => {
+                if self.get_flag(Flag::OVERFLOW) {
+                    return self.interrupt(Self::OVERFLOW_VECTOR);
+                }
+            }
+            Instruction::Iret => {
+                let ip = self.pop_word()?;
+                let cs = self.pop_word()?;
+                let flags = self.pop_word()?;
+                self.set_register(Register::IP, ip);
+                self.set_register(Register::CS, cs);
+                self.load_flags(flags);
+            }
+            Instruction::Hlt => self.halted = true,
+            Instruction::Cmc => self.set_flag(Flag::CARRY, !self.get_flag(Flag::CARRY)),
+            Instruction::Clc => self.set_flag(Flag::CARRY, false),
+            Instruction::Stc => self.set_flag(Flag::CARRY, true),
+            Instruction::Cli => self.set_flag(Flag::INTERRUPT, false),
+            Instruction::Sti => self.set_flag(Flag::INTERRUPT, true),
+            Instruction::Cld => self.set_flag(Flag::DIRECTION, false),
+            Instruction::Std => self.set_flag(Flag::DIRECTION, true),
+            Instruction::Pushf => self.push_word(self.get_register(Register::F))?,
+            Instruction::Popf => {
+                let flags = self.pop_word()?;
+                self.load_flags(flags);
+            }
             Instruction::JmpNear(offset) => {
                 let ip = self.get_register(Register::IP) as i16;
                 self.set_register(Register::IP, ip.wrapping_add(offset) as u16);
@@ -180,70 +934,613 @@ impl Machine {
                 self.set_register(Register::CS, segment);
                 self.set_register(Register::IP, offset);
             }
+            Instruction::JmpNearIndirect(operand) => {
+                let target = self.read_operand(operand, false)?;
+                self.set_register(Register::IP, target);
+            }
+            Instruction::JmpFarIndirect(Operand::Memory(mem_addr)) => {
+                let (segment, offset) = self.read_far_pointer(mem_addr)?;
+                self.set_register(Register::CS, segment);
+                self.set_register(Register::IP, offset);
+            }
+            Instruction::CallFarIndirect(_) | Instruction::JmpFarIndirect(_) => unreachable!(),
+            Instruction::Jo(offset) => {
+                self.jump_short_if(self.get_flag(Flag::OVERFLOW), offset);
+            }
+            Instruction::Jno(offset) => {
+                self.jump_short_if(!self.get_flag(Flag::OVERFLOW), offset);
+            }
+            Instruction::Jc(offset) => {
+                self.jump_short_if(self.get_flag(Flag::CARRY), offset);
+            }
+            Instruction::Jnc(offset) => {
+                self.jump_short_if(!self.get_flag(Flag::CARRY), offset);
+            }
             Instruction::Jz(offset) => {
-                if self.get_flag(Flag::ZERO) {
-                    let ip = self.get_register(Register::IP) as i16;
-                    self.set_register(Register::IP, ip.wrapping_add(offset as i16) as u16);
-                }
+                self.jump_short_if(self.get_flag(Flag::ZERO), offset);
             }
             Instruction::Jnz(offset) => {
-                if !self.get_flag(Flag::ZERO) {
-                    let ip = self.get_register(Register::IP) as i16;
-                    self.set_register(Register::IP, ip.wrapping_add(offset as i16) as u16);
-                }
+                self.jump_short_if(!self.get_flag(Flag::ZERO), offset);
+            }
+            Instruction::Jbe(offset) => {
+                self.jump_short_if(self.get_flag(Flag::CARRY) || self.get_flag(Flag::ZERO), offset);
+            }
+            Instruction::Ja(offset) => {
+                self.jump_short_if(!self.get_flag(Flag::CARRY) && !self.get_flag(Flag::ZERO), offset);
+            }
+            Instruction::Js(offset) => {
+                self.jump_short_if(self.get_flag(Flag::SIGN), offset);
+            }
+            Instruction::Jns(offset) => {
+                self.jump_short_if(!self.get_flag(Flag::SIGN), offset);
+            }
+            Instruction::Jp(offset) => {
+                self.jump_short_if(self.get_flag(Flag::PARITY), offset);
+            }
+            Instruction::Jnp(offset) => {
+                self.jump_short_if(!self.get_flag(Flag::PARITY), offset);
+            }
+            Instruction::Jl(offset) => {
+                self.jump_short_if(self.get_flag(Flag::SIGN) != self.get_flag(Flag::OVERFLOW), offset);
+            }
+            Instruction::Jge(offset) => {
+                self.jump_short_if(self.get_flag(Flag::SIGN) == self.get_flag(Flag::OVERFLOW), offset);
+            }
+            Instruction::Jle(offset) => {
+                let less = self.get_flag(Flag::SIGN) != self.get_flag(Flag::OVERFLOW);
+                self.jump_short_if(self.get_flag(Flag::ZERO) || less, offset);
+            }
+            Instruction::Jg(offset) => {
+                let less = self.get_flag(Flag::SIGN) != self.get_flag(Flag::OVERFLOW);
+                self.jump_short_if(!self.get_flag(Flag::ZERO) && !less, offset);
             }
             Instruction::JmpShort(offset) => {
                 let ip = self.get_register(Register::IP) as i16;
                 self.set_register(Register::IP, ip.wrapping_add(offset as i16) as u16);
             }
+            Instruction::Loopnz(offset) => {
+                let cx = self.decrement_cx();
+                self.jump_short_if(cx != 0 && !self.get_flag(Flag::ZERO), offset);
+            }
+            Instruction::Loopz(offset) => {
+                let cx = self.decrement_cx();
+                self.jump_short_if(cx != 0 && self.get_flag(Flag::ZERO), offset);
+            }
+            Instruction::Loop(offset) => {
+                let cx = self.decrement_cx();
+                self.jump_short_if(cx != 0, offset);
+            }
+            Instruction::Jcxz(offset) => {
+                self.jump_short_if(self.get_register(Register::CX) == 0, offset);
+            }
         }
+
+        Ok(())
     }
 
-    fn apply_binary_op<F>(&mut self, dest: Operand, src: Operand, op: F) -> (u16, u16, u16)
+    // Runs a single iteration of a string instruction. When repeated, IP is moved back
+    // to the prefix so the instruction restarts on the next step until the repeat ends.
+    fn repeat_string_op<F>(&mut self, rep: Option<RepPrefix>, compares: bool, instr_size: u16, op: F) -> Result<(), MachineError>
     where
-        F: Fn(u16, u16) -> u16,
+        F: FnOnce(&mut Self) -> Result<(), MachineError>,
     {
-        match (dest, src) {
-            (Operand::Register(dest_reg), Operand::Register(src_reg)) => {
-                let lhs = self.get_register(dest_reg);
-                let rhs = self.get_register(src_reg);
-                let result = op(lhs, rhs);
-                self.set_register(dest_reg, result);
-
-                (lhs, rhs, result)
-            }
-            (Operand::Register(dest_reg), Operand::Memory(mem_addr)) => {
-                let ptr = self.get_ptr_from_mem_address(mem_addr);
-                let lhs = self.get_register(dest_reg);
-                let rhs = if dest_reg.is_8bit() {
-                    self.memory.read_byte(ptr) as u16
-                } else {
-                    self.memory.read_word(ptr)
-                };
-                let result = op(lhs, rhs);
-                self.set_register(dest_reg, op(lhs, rhs));
+        let Some(rep) = rep else {
+            return op(self);
+        };
+
+        if self.get_register(Register::CX) == 0 {
+            return Ok(());
+        }
+
+        op(self)?;
+        let cx = self.decrement_cx();
+
+        let zero = self.get_flag(Flag::ZERO);
+        let repeat = cx != 0 && (!compares || match rep {
+            RepPrefix::Rep => zero,
+            RepPrefix::Repne => !zero,
+        });
+
+        if repeat {
+            self.set_register(Register::IP, self.get_register(Register::IP).wrapping_sub(instr_size));
+        }
+
+        Ok(())
+    }
+
+    fn advance_string_index(&mut self, register: Register, is_8bit: bool) {
+        let step = if is_8bit { 1 } else { 2 };
+        let value = if self.get_flag(Flag::DIRECTION) {
+            self.get_register(register).wrapping_sub(step)
+        } else {
+            self.get_register(register).wrapping_add(step)
+        };
+        self.set_register(register, value);
+    }
+
+    fn accumulator(is_8bit: bool) -> Register {
+        if is_8bit { Register::AL } else { Register::AX }
+    }
+
+    // SP is only updated once the stack access succeeded
+    pub(crate) fn push_word(&mut self, value: u16) -> Result<(), MachineError> {
+        let sp = self.get_register(Register::SP).wrapping_sub(2);
+        self.write_word_at(Register::SS, sp, value).map_err(Self::stack_fault)?;
+        self.set_register(Register::SP, sp);
+        Ok(())
+    }
+
+    pub(crate) fn pop_word(&mut self) -> Result<u16, MachineError> {
+        let sp = self.get_register(Register::SP);
+        let value = self.read_word_at(Register::SS, sp).map_err(Self::stack_fault)?;
+        self.set_register(Register::SP, sp.wrapping_add(2));
+        Ok(value)
+    }
+
+    fn stack_fault(err: MachineError) -> MachineError {
+        match err {
+            MachineError::MemoryOutOfRange(address) => MachineError::StackFault(address),
+            err => err,
+        }
+    }
+
+    fn load_far_pointer(&mut self, reg: Register, segment_reg: Register, mem_addr: MemAddress) -> Result<(), MachineError> {
+        let (pointer_segment, pointer_offset) = self.read_far_pointer(mem_addr)?;
+        self.set_register(reg, pointer_offset);
+        self.set_register(segment_reg, pointer_segment);
 
-                (lhs, rhs, result)
+        Ok(())
+    }
+
+    // offset is read from the operand, the segment from the word following it
+    fn read_far_pointer(&mut self, mem_addr: MemAddress) -> Result<(u16, u16), MachineError> {
+        let segment = Self::get_segment_from_mem_address(mem_addr);
+        let offset = self.get_offset_from_mem_address(mem_addr);
+
+        let pointer_offset = self.read_word_at(segment, offset)?;
+        let pointer_segment = self.read_word_at(segment, offset.wrapping_add(2))?;
+
+        Ok((pointer_segment, pointer_offset))
+    }
+
+    fn resolve_io_port(&self, port: IoPort) -> u16 {
+        match port {
+            IoPort::Immediate(port) => port as u16,
+            IoPort::DX => self.get_register(Register::DX),
+        }
+    }
+
+    // LOOP family decrements CX without affecting any flags
+    fn decrement_cx(&mut self) -> u16 {
+        let cx = self.get_register(Register::CX).wrapping_sub(1);
+        self.set_register(Register::CX, cx);
+        cx
+    }
+
+    fn jump_short_if(&mut self, condition: bool, offset: i8) {
+        if condition {
+            let ip = self.get_register(Register::IP) as i16;
+            self.set_register(Register::IP, ip.wrapping_add(offset as i16) as u16);
+        }
+    }
+
+    fn apply_binary_op<F>(&mut self, dest: Operand, src: Operand, is_8bit: bool, op: F) -> Result<u16, MachineError>
+    where
+        F: FnOnce(&mut Self, u16, u16) -> u16,
+    {
+        let result = self.evaluate_binary_op(dest, src, is_8bit, op)?;
+        self.write_operand(dest, result, is_8bit)?;
+
+        Ok(result)
+    }
+
+    fn evaluate_binary_op<F>(&mut self, dest: Operand, src: Operand, is_8bit: bool, op: F) -> Result<u16, MachineError>
+    where
+        F: FnOnce(&mut Self, u16, u16) -> u16,
+    {
+        if let (Operand::Memory(_), Operand::Memory(_)) = (dest, src) {
+            unreachable!()
+        }
+
+        let lhs = self.read_operand(dest, is_8bit)?;
+        let rhs = self.read_operand(src, is_8bit)?;
+
+        Ok(op(self, lhs, rhs) & Self::width_mask(is_8bit))
+    }
+
+    fn apply_unary_op<F>(&mut self, operand: Operand, is_8bit: bool, op: F) -> Result<u16, MachineError>
+    where
+        F: FnOnce(&mut Self, u16) -> u16,
+    {
+        let value = self.read_operand(operand, is_8bit)?;
+        let result = op(self, value) & Self::width_mask(is_8bit);
+        self.write_operand(operand, result, is_8bit)?;
+
+        Ok(result)
+    }
+
+    fn read_operand(&mut self, operand: Operand, is_8bit: bool) -> Result<u16, MachineError> {
+        match operand {
+            Operand::Register(reg) => Ok(self.get_register(reg)),
+            Operand::Memory(mem_addr) => self.read_memory(
+                Self::get_segment_from_mem_address(mem_addr),
+                self.get_offset_from_mem_address(mem_addr),
+                is_8bit,
+            ),
+            Operand::Immediate8(val) if is_8bit => Ok(val as u16),
+            Operand::Immediate8(val) => Ok(val as i8 as u16),
+            Operand::Immediate16(val) => Ok(val),
+            Operand::Immediate32(_) => unreachable!(),
+        }
+    }
+
+    fn write_operand(&mut self, operand: Operand, value: u16, is_8bit: bool) -> Result<(), MachineError> {
+        match operand {
+            Operand::Register(reg) => {
+                self.set_register(reg, value);
+                Ok(())
             }
-            (Operand::Memory(mem_addr), Operand::Register(src_reg)) => {
-                let ptr = self.get_ptr_from_mem_address(mem_addr);
-                let lhs = if src_reg.is_8bit() {
-                    self.memory.read_byte(ptr) as u16
-                } else {
-                    self.memory.read_word(ptr)
-                };
-                let rhs = self.get_register(src_reg);
-                let result = op(lhs, rhs);
-                if src_reg.is_8bit() {
-                    self.memory.write_byte(ptr, result as u8);
-                } else {
-                    self.memory.write_word(ptr, result);
-                }
+            Operand::Memory(mem_addr) => self.write_memory(
+                Self::get_segment_from_mem_address(mem_addr),
+                self.get_offset_from_mem_address(mem_addr),
+                value,
+                is_8bit,
+            ),
+            Operand::Immediate8(_) | Operand::Immediate16(_) | Operand::Immediate32(_) => unreachable!(),
+        }
+    }
 
-                (lhs, rhs, result)
+    fn apply_unary_op32<F>(&mut self, operand: Operand, op: F) -> Result<u32, MachineError>
+    where
+        F: FnOnce(&mut Self, u32) -> u32,
+    {
+        let value = self.read_operand32(operand)?;
+        let result = op(self, value);
+        self.write_operand32(operand, result)?;
+
+        Ok(result)
+    }
+
+    fn apply_binary_op32<F>(&mut self, dest: Operand, src: Operand, op: F) -> Result<u32, MachineError>
+    where
+        F: FnOnce(&mut Self, u32, u32) -> u32,
+    {
+        let result = self.evaluate_binary_op32(dest, src, op)?;
+        self.write_operand32(dest, result)?;
+
+        Ok(result)
+    }
+
+    fn evaluate_binary_op32<F>(&mut self, dest: Operand, src: Operand, op: F) -> Result<u32, MachineError>
+    where
+        F: FnOnce(&mut Self, u32, u32) -> u32,
+    {
+        if let (Operand::Memory(_), Operand::Memory(_)) = (dest, src) {
+            unreachable!()
+        }
+
+        let lhs = self.read_operand32(dest)?;
+        let rhs = self.read_operand32(src)?;
+
+        Ok(op(self, lhs, rhs))
+    }
+
+    fn read_operand32(&mut self, operand: Operand) -> Result<u32, MachineError> {
+        match operand {
+            Operand::Register(reg) => Ok(self.get_register32(reg)),
+            Operand::Memory(mem_addr) => self.read_dword_at(
+                Self::get_segment_from_mem_address(mem_addr),
+                self.get_offset_from_mem_address(mem_addr),
+            ),
+            Operand::Immediate8(val) => Ok(val as i8 as i32 as u32),
+            Operand::Immediate16(val) => Ok(val as u32),
+            Operand::Immediate32(val) => Ok(val),
+        }
+    }
+
+    fn write_operand32(&mut self, operand: Operand, value: u32) -> Result<(), MachineError> {
+        match operand {
+            Operand::Register(reg) => {
+                self.set_register32(reg, value);
+                Ok(())
             }
-            (Operand::Memory(_), Operand::Memory(_)) => unreachable!(),
+            Operand::Memory(mem_addr) => self.write_dword_at(
+                Self::get_segment_from_mem_address(mem_addr),
+                self.get_offset_from_mem_address(mem_addr),
+                value,
+            ),
+            Operand::Immediate8(_) | Operand::Immediate16(_) | Operand::Immediate32(_) => unreachable!(),
+        }
+    }
+
+    fn read_memory(&mut self, segment: Register, offset: u16, is_8bit: bool) -> Result<u16, MachineError> {
+        if is_8bit {
+            Ok(self.read_byte_at(segment, offset)? as u16)
+        } else {
+            self.read_word_at(segment, offset)
+        }
+    }
+
+    fn write_memory(&mut self, segment: Register, offset: u16, value: u16, is_8bit: bool) -> Result<(), MachineError> {
+        if is_8bit {
+            self.write_byte_at(segment, offset, value as u8)
+        } else {
+            self.write_word_at(segment, offset, value)
+        }
+    }
+
+    fn is_8bit_operation(dest: Operand, src: Operand) -> bool {
+        match (dest, src) {
+            (Operand::Register(reg), _) | (_, Operand::Register(reg)) => reg.is_8bit(),
+            // MOV r/m8, imm8
+            (_, Operand::Immediate8(_)) => true,
+            _ => false,
+        }
+    }
+
+    fn width_mask(is_8bit: bool) -> u16 {
+        if is_8bit { 0x00FF } else { 0xFFFF }
+    }
+
+    fn sign_bit(is_8bit: bool) -> u16 {
+        if is_8bit { 0x0080 } else { 0x8000 }
+    }
+
+    fn add_with_flags(&mut self, lhs: u16, rhs: u16, carry_in: bool, is_8bit: bool) -> u16 {
+        let mask = Self::width_mask(is_8bit);
+        let full = lhs as u32 + rhs as u32 + carry_in as u32;
+        let result = full as u16 & mask;
+
+        self.set_flag(Flag::CARRY, full > mask as u32);
+        // Overflow when both operands share a sign the result does not have
+        self.set_flag(Flag::OVERFLOW, !(lhs ^ rhs) & (lhs ^ result) & Self::sign_bit(is_8bit) != 0);
+        self.set_flag(Flag::AUXILIARY, (lhs ^ rhs ^ result) & 0x10 != 0);
+        self.update_result_flags(result, is_8bit);
+
+        result
+    }
+
+    fn sub_with_flags(&mut self, lhs: u16, rhs: u16, borrow_in: bool, is_8bit: bool) -> u16 {
+        let mask = Self::width_mask(is_8bit);
+        let result = (lhs as u32).wrapping_sub(rhs as u32).wrapping_sub(borrow_in as u32) as u16 & mask;
+
+        self.set_flag(Flag::CARRY, (rhs as u32 + borrow_in as u32) > lhs as u32);
+        // Overflow when the operands differ in sign and the result took the sign of the subtrahend
+        self.set_flag(Flag::OVERFLOW, (lhs ^ rhs) & (lhs ^ result) & Self::sign_bit(is_8bit) != 0);
+        self.set_flag(Flag::AUXILIARY, (lhs ^ rhs ^ result) & 0x10 != 0);
+        self.update_result_flags(result, is_8bit);
+
+        result
+    }
+
+    fn add_with_flags32(&mut self, lhs: u32, rhs: u32, carry_in: bool) -> u32 {
+        let full = lhs as u64 + rhs as u64 + carry_in as u64;
+        let result = full as u32;
+
+        self.set_flag(Flag::CARRY, full > u32::MAX as u64);
+        self.set_flag(Flag::OVERFLOW, !(lhs ^ rhs) & (lhs ^ result) & 0x80000000 != 0);
+        self.set_flag(Flag::AUXILIARY, (lhs ^ rhs ^ result) & 0x10 != 0);
+        self.update_result_flags32(result);
+
+        result
+    }
+
+    fn sub_with_flags32(&mut self, lhs: u32, rhs: u32, borrow_in: bool) -> u32 {
+        let result = lhs.wrapping_sub(rhs).wrapping_sub(borrow_in as u32);
+
+        self.set_flag(Flag::CARRY, (rhs as u64 + borrow_in as u64) > lhs as u64);
+        self.set_flag(Flag::OVERFLOW, (lhs ^ rhs) & (lhs ^ result) & 0x80000000 != 0);
+        self.set_flag(Flag::AUXILIARY, (lhs ^ rhs ^ result) & 0x10 != 0);
+        self.update_result_flags32(result);
+
+        result
+    }
+
+    // POPF and IRET leave the reserved bits of F at their fixed values
+    fn load_flags(&mut self, flags: u16) {
+        let model = self.cpu_model();
+        self.set_register(Register::F, (flags & Flag::writable_bits(model)) | Flag::reserved_bits(model));
+    }
+
+    // the 80186 and later only use the low 5 bits of the count
+    fn resolve_shift_count(&self, count: ShiftCount) -> u8 {
+        let count = match count {
+            ShiftCount::One => 1,
+            ShiftCount::CL => self.get_register(Register::CL) as u8,
+            ShiftCount::Immediate(val) => val,
+        };
+
+        if self.cpu_model() == CpuModel::I8086 {
+            count
+        } else {
+            count & 0x1F
+        }
+    }
+
+    // A zero count leaves both the operand and the flags untouched
+    fn shift_left(&mut self, value: u16, count: u8, is_8bit: bool) -> u16 {
+        if count == 0 {
+            return value;
+        }
+
+        let sign_bit = Self::sign_bit(is_8bit);
+        let mut result = value;
+        let mut carry = false;
+        for _ in 0..count {
+            carry = result & sign_bit != 0;
+            result = (result << 1) & Self::width_mask(is_8bit);
+        }
+
+        self.set_flag(Flag::CARRY, carry);
+        self.set_flag(Flag::OVERFLOW, (result & sign_bit != 0) != carry);
+        self.update_result_flags(result, is_8bit);
+
+        result
+    }
+
+    fn shift_right(&mut self, value: u16, count: u8, arithmetic: bool, is_8bit: bool) -> u16 {
+        if count == 0 {
+            return value;
         }
+
+        let sign_bit = Self::sign_bit(is_8bit);
+        let fill = if arithmetic { value & sign_bit } else { 0 };
+        let mut result = value;
+        let mut carry = false;
+        for _ in 0..count {
+            carry = result & 1 != 0;
+            result = (result >> 1) | fill;
+        }
+
+        self.set_flag(Flag::CARRY, carry);
+        // SAR can never overflow, SHR overflows when the original sign bit is shifted away
+        self.set_flag(Flag::OVERFLOW, !arithmetic && value & sign_bit != 0);
+        self.update_result_flags(result, is_8bit);
+
+        result
+    }
+
+    // ROL/ROR/RCL/RCR only affect CF and OF
+    fn rotate_left(&mut self, value: u16, count: u8, through_carry: bool, is_8bit: bool) -> u16 {
+        if count == 0 {
+            return value;
+        }
+
+        let sign_bit = Self::sign_bit(is_8bit);
+        let mut result = value;
+        let mut carry = self.get_flag(Flag::CARRY);
+        for _ in 0..count {
+            let shifted_out = result & sign_bit != 0;
+            let shifted_in = if through_carry { carry } else { shifted_out };
+            result = ((result << 1) | shifted_in as u16) & Self::width_mask(is_8bit);
+            carry = shifted_out;
+        }
+
+        self.set_flag(Flag::CARRY, carry);
+        self.set_flag(Flag::OVERFLOW, (result & sign_bit != 0) != carry);
+
+        result
+    }
+
+    fn rotate_right(&mut self, value: u16, count: u8, through_carry: bool, is_8bit: bool) -> u16 {
+        if count == 0 {
+            return value;
+        }
+
+        let sign_bit = Self::sign_bit(is_8bit);
+        let mut result = value;
+        let mut carry = self.get_flag(Flag::CARRY);
+        for _ in 0..count {
+            let shifted_out = result & 1 != 0;
+            let shifted_in = if through_carry { carry } else { shifted_out };
+            result = (result >> 1) | if shifted_in { sign_bit } else { 0 };
+            carry = shifted_out;
+        }
+
+        self.set_flag(Flag::CARRY, carry);
+        // OF is the XOR of the two most significant bits of the result
+        self.set_flag(Flag::OVERFLOW, (result & sign_bit != 0) != (result & (sign_bit >> 1) != 0));
+
+        result
+    }
+
+    fn shift_left32(&mut self, value: u32, count: u8) -> u32 {
+        if count == 0 {
+            return value;
+        }
+
+        let mut result = value;
+        let mut carry = false;
+        for _ in 0..count {
+            carry = result & 0x80000000 != 0;
+            result <<= 1;
+        }
+
+        self.set_flag(Flag::CARRY, carry);
+        self.set_flag(Flag::OVERFLOW, (result & 0x80000000 != 0) != carry);
+        self.update_result_flags32(result);
+
+        result
+    }
+
+    fn shift_right32(&mut self, value: u32, count: u8, arithmetic: bool) -> u32 {
+        if count == 0 {
+            return value;
+        }
+
+        let fill = if arithmetic { value & 0x80000000 } else { 0 };
+        let mut result = value;
+        let mut carry = false;
+        for _ in 0..count {
+            carry = result & 1 != 0;
+            result = (result >> 1) | fill;
+        }
+
+        self.set_flag(Flag::CARRY, carry);
+        self.set_flag(Flag::OVERFLOW, !arithmetic && value & 0x80000000 != 0);
+        self.update_result_flags32(result);
+
+        result
+    }
+
+    fn rotate_left32(&mut self, value: u32, count: u8, through_carry: bool) -> u32 {
+        if count == 0 {
+            return value;
+        }
+
+        let mut result = value;
+        let mut carry = self.get_flag(Flag::CARRY);
+        for _ in 0..count {
+            let shifted_out = result & 0x80000000 != 0;
+            let shifted_in = if through_carry { carry } else { shifted_out };
+            result = (result << 1) | shifted_in as u32;
+            carry = shifted_out;
+        }
+
+        self.set_flag(Flag::CARRY, carry);
+        self.set_flag(Flag::OVERFLOW, (result & 0x80000000 != 0) != carry);
+
+        result
+    }
+
+    fn rotate_right32(&mut self, value: u32, count: u8, through_carry: bool) -> u32 {
+        if count == 0 {
+            return value;
+        }
+
+        let mut result = value;
+        let mut carry = self.get_flag(Flag::CARRY);
+        for _ in 0..count {
+            let shifted_out = result & 1 != 0;
+            let shifted_in = if through_carry { carry } else { shifted_out };
+            result = (result >> 1) | if shifted_in { 0x80000000 } else { 0 };
+            carry = shifted_out;
+        }
+
+        self.set_flag(Flag::CARRY, carry);
+        self.set_flag(Flag::OVERFLOW, (result & 0x80000000 != 0) != (result & 0x40000000 != 0));
+
+        result
+    }
+
+    fn logic_with_flags(&mut self, result: u16, is_8bit: bool) -> u16 {
+        let result = result & Self::width_mask(is_8bit);
+
+        self.set_flag(Flag::CARRY, false);
+        self.set_flag(Flag::OVERFLOW, false);
+        self.set_flag(Flag::AUXILIARY, false);
+        self.update_result_flags(result, is_8bit);
+
+        result
+    }
+
+    fn logic_with_flags32(&mut self, result: u32) -> u32 {
+        self.set_flag(Flag::CARRY, false);
+        self.set_flag(Flag::OVERFLOW, false);
+        self.set_flag(Flag::AUXILIARY, false);
+        self.update_result_flags32(result);
+
+        result
     }
 
-}
\ No newline at end of file
+}
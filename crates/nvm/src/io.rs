@@ -0,0 +1,19 @@
+// Device attached to the I/O port address space, see Machine::attach_io_device
+pub trait IoBus {
+    fn read_byte(&mut self, port: u16) -> u8;
+
+    fn write_byte(&mut self, port: u16, value: u8);
+
+    // only used when the device claims both ports of the word, split into two byte
+    // accesses unless the device handles them itself
+    fn read_word(&mut self, port: u16) -> u16 {
+        let low = self.read_byte(port) as u16;
+        let high = self.read_byte(port.wrapping_add(1)) as u16;
+        (high << 8) | low
+    }
+
+    fn write_word(&mut self, port: u16, value: u16) {
+        self.write_byte(port, value as u8);
+        self.write_byte(port.wrapping_add(1), (value >> 8) as u8);
+    }
+}
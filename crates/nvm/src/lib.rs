@@ -1,8 +1,15 @@
+pub mod builder;
+pub mod bus;
+pub mod cpu;
+pub mod error;
 pub mod register;
 pub mod instruction;
+pub mod io;
 pub mod machine;
 pub mod memory;
 pub mod modrm;
+pub mod observer;
 mod instruction_exec;
 
+pub use builder::MachineBuilder;
 pub use machine::Machine;
\ No newline at end of file
@@ -1,73 +1,346 @@
+use crate::bus::{MemoryBus, MemoryDevice};
+use crate::cpu::CpuModel;
+use crate::error::{BuildError, DecodeError, MachineError};
 use crate::instruction::Instruction;
-use crate::memory::LinearMemory;
+use crate::io::IoBus;
+use crate::memory::{LinearMemory, ADDRESS_SPACE_SIZE};
 use crate::modrm::MemAddress;
+use crate::observer::Observer;
 use crate::register::{Flag, Register};
+use std::fmt;
 use std::fs::File;
 use std::io::{BufReader, Read};
+use std::ops::{Range, RangeInclusive};
+
+// longest instruction encoding the decoder may look at
+const MAX_INSTRUCTION_SIZE: u16 = 15;
 
 pub struct Machine {
-    pub(super) memory: LinearMemory,
-    registers: [u16; 14],
+    pub(super) bus: MemoryBus,
+    // the 16bit general purpose registers live in the low word of their 32bit extension
+    registers: [u32; 14],
+    io_devices: Vec<(RangeInclusive<u16>, Box<dyn IoBus>)>,
+    observers: Vec<Box<dyn Observer>>,
+    cpu_model: CpuModel,
+    pub(super) halted: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum StopReason {
+    // HLT was executed, IP points past it
+    Halted,
+    // run_for executed all of its steps
+    BudgetExhausted,
+    // INT 3 without a handler installed, IP points past it so running again resumes
+    Breakpoint,
+    // bytes at CS:IP could not be decoded
+    InvalidOpcode { segment: u16, offset: u16, error: DecodeError },
+    Fault(MachineError),
+}
+
+impl fmt::Display for StopReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StopReason::Halted => write!(f, "Halted"),
+            StopReason::BudgetExhausted => write!(f, "Step budget exhausted"),
+            StopReason::Breakpoint => write!(f, "Breakpoint"),
+            StopReason::InvalidOpcode { segment, offset, error } => {
+                write!(f, "{} at {:04x}:{:04x}", error, segment, offset)
+            }
+            StopReason::Fault(err) => write!(f, "{}", err),
+        }
+    }
 }
 
 impl Machine {
-    pub fn load_program(&mut self, program: BufReader<File>) {
-        assert!(
-            program.get_ref().metadata().unwrap().len() <= self.memory.data.len() as u64,
-            "Program cannot be larger than memory"
-        );
+    pub const DIVIDE_ERROR_VECTOR: u8 = 0;
+    pub const BREAKPOINT_VECTOR: u8 = 3;
+    pub const OVERFLOW_VECTOR: u8 = 4;
+    pub const BOUND_VECTOR: u8 = 5;
+    pub const INVALID_OPCODE_VECTOR: u8 = 6;
+
+    pub const DEFAULT_STACK_POINTER: u16 = 1024;
+    // programs are placed past the first interrupt vectors so the fault vectors
+    // stay empty unless a handler is installed
+    pub const DEFAULT_LOAD_OFFSET: u16 = 0x0100;
+
+    pub(crate) fn with_memory(memory: LinearMemory) -> Self {
+        let mut machine = Self {
+            bus: MemoryBus::new(memory),
+            registers: [0; 14],
+            io_devices: Vec::new(),
+            observers: Vec::new(),
+            cpu_model: CpuModel::default(),
+            halted: false,
+        };
+        machine.set_register(Register::F, Flag::reserved_bits(machine.cpu_model));
+
+        machine
+    }
 
-        for (i, byte) in program.bytes().enumerate() {
-            self.memory.write_byte(i, byte.unwrap());
+    pub fn load_program(&mut self, mut program: BufReader<File>) -> Result<(), BuildError> {
+        let mut bytes = Vec::new();
+        program.read_to_end(&mut bytes)?;
+
+        self.load_program_bytes(&bytes)
+    }
+
+    // copies the program to CS:IP, where the next step fetches from
+    pub fn load_program_bytes(&mut self, program: &[u8]) -> Result<(), BuildError> {
+        let start = self.physical_address(Register::CS, self.get_register(Register::IP));
+        let memory_size = self.memory().data.len();
+        if start + program.len() > memory_size {
+            return Err(BuildError::ProgramTooLarge { address: start, size: program.len(), memory_size });
         }
+
+        self.memory_mut().data[start..start + program.len()].copy_from_slice(program);
+        Ok(())
     }
 
-    pub fn load_program_bytes(&mut self, program: &[u8]) {
-        assert!(
-            program.len() <= program.len(),
-            "Program cannot be larger than memory"
-        );
+    // Runs until the machine halts or stops on an error
+    pub fn run(&mut self) -> StopReason {
+        loop {
+            if let Some(reason) = self.run_step() {
+                return reason;
+            }
+        }
+    }
+
+    pub fn run_for(&mut self, steps: usize) -> StopReason {
+        for _ in 0..steps {
+            if let Some(reason) = self.run_step() {
+                return reason;
+            }
+        }
 
-        self.memory.data[..program.len()].copy_from_slice(program);
+        StopReason::BudgetExhausted
     }
 
-    pub fn step(&mut self) {
-        let ip = self.get_register(Register::IP) as usize;
+    fn run_step(&mut self) -> Option<StopReason> {
+        match self.step() {
+            Ok(()) if self.halted => Some(StopReason::Halted),
+            Ok(()) => None,
+            Err(MachineError::UnhandledInterrupt(Self::BREAKPOINT_VECTOR)) => Some(StopReason::Breakpoint),
+            Err(MachineError::InvalidInstruction { segment, offset, error }) => {
+                Some(StopReason::InvalidOpcode { segment, offset, error })
+            }
+            Err(err) => Some(StopReason::Fault(err)),
+        }
+    }
 
-        print!("Running instruction at 0x{:x}", ip);
-        let opcode_byte = self.memory.read_byte(ip);
-        let instruction =
-            Instruction::from_bytes(opcode_byte, &self.memory.data[ip + 1..]).unwrap();
-        println!(": {:?}", instruction);
+    pub fn is_halted(&self) -> bool {
+        self.halted
+    }
 
-        self.run_instruction(instruction);
+    pub fn cpu_model(&self) -> CpuModel {
+        self.cpu_model
+    }
 
+    pub fn set_cpu_model(&mut self, model: CpuModel) {
+        let flags = self.get_register(Register::F) & !Flag::reserved_bits(self.cpu_model);
+        self.cpu_model = model;
+        self.set_register(Register::F, flags | Flag::reserved_bits(model));
+    }
+
+    // A halted CPU does not fetch anything until an interrupt wakes it up
+    pub fn step(&mut self) -> Result<(), MachineError> {
+        if self.halted {
+            return Ok(());
+        }
+
+        let ip = self.get_register(Register::IP);
+
+        let cs = self.get_register(Register::CS);
+
+        // fetch one byte at a time until the decoder has the whole instruction, so
+        // memory mapped devices never see reads of bytes past the instruction
+        let mut bytes = Vec::with_capacity(MAX_INSTRUCTION_SIZE as usize);
+        let decoded = loop {
+            match self.bus.read_byte(self.physical_address(Register::CS, ip.wrapping_add(bytes.len() as u16))) {
+                Ok(byte) => bytes.push(byte),
+                // the instruction runs past the end of memory
+                Err(_) if !bytes.is_empty() => break Err(DecodeError::TruncatedInstruction),
+                Err(err) => return Err(err),
+            }
+
+            match Instruction::decode(bytes[0], &bytes[1..], self.cpu_model) {
+                Err(DecodeError::TruncatedInstruction) if bytes.len() < MAX_INSTRUCTION_SIZE as usize => {}
+                result => break result,
+            }
+        };
+        let instruction = match decoded {
+            Ok(instruction) => instruction,
+            Err(error @ DecodeError::InvalidOpcode(_)) if self.cpu_model != CpuModel::I8086 => {
+                // the 80186 and later fault with IP still pointing at the invalid opcode, without
+                // a handler the machine stops the same way the 8086 does
+                return match self.interrupt(Self::INVALID_OPCODE_VECTOR) {
+                    Err(MachineError::UnhandledInterrupt(_)) => {
+                        Err(MachineError::InvalidInstruction { segment: cs, offset: ip, error })
+                    }
+                    result => result,
+                };
+            }
+            Err(error) => return Err(MachineError::InvalidInstruction { segment: cs, offset: ip, error }),
+        };
+
+        for observer in self.observers.iter_mut() {
+            observer.before_instruction(cs, ip, &instruction);
+        }
+
+        // IP points to the next instruction while executing, so relative jumps
+        // and calls are resolved against it and control transfers can override it
         self.set_register(
             Register::IP,
-            self.get_register(Register::IP) + instruction.get_instr_size(),
+            self.get_register(Register::IP).wrapping_add(instruction.get_instr_size()),
         );
+
+        self.run_instruction(instruction.clone())?;
+
+        for observer in self.observers.iter_mut() {
+            observer.after_instruction(cs, ip, &instruction);
+        }
+
+        Ok(())
+    }
+
+    // Delivers an interrupt through the real mode IVT at 0000:0000, each vector is
+    // a 4 byte OFFSET:SEGMENT pair. An empty (0000:0000) entry means no handler is installed.
+    pub fn interrupt(&mut self, vector: u8) -> Result<(), MachineError> {
+        let entry = vector as u16 * 4;
+        let offset = self.read_physical_word(entry as usize)?;
+        let segment = self.read_physical_word(entry as usize + 2)?;
+
+        if segment == 0 && offset == 0 {
+            return Err(MachineError::UnhandledInterrupt(vector));
+        }
+
+        for observer in self.observers.iter_mut() {
+            observer.interrupt(vector);
+        }
+
+        self.halted = false;
+
+        self.push_word(self.get_register(Register::F))?;
+        self.push_word(self.get_register(Register::CS))?;
+        self.push_word(self.get_register(Register::IP))?;
+
+        self.set_flag(Flag::INTERRUPT, false);
+        self.set_flag(Flag::TRAP, false);
+
+        self.set_register(Register::CS, segment);
+        self.set_register(Register::IP, offset);
+
+        Ok(())
     }
 
     pub fn get_ptr_from_mem_address(&self, mem_addr: MemAddress) -> usize {
-        let ptr = mem_addr.displacement
-            + if let Some(base_reg) = mem_addr.base {
-                self.get_register(base_reg)
-            } else {
-                0
-            }
-            + if let Some(index_reg) = mem_addr.index {
-                self.get_register(index_reg)
-            } else {
-                0
-            };
+        self.physical_address(Self::get_segment_from_mem_address(mem_addr), self.get_offset_from_mem_address(mem_addr))
+    }
+
+    // effective address, wraps around within the 64KiB segment
+    pub fn get_offset_from_mem_address(&self, mem_addr: MemAddress) -> u16 {
+        let base = mem_addr.base.map_or(0, |reg| self.get_register(reg));
+        let index = mem_addr.index.map_or(0, |reg| self.get_register(reg));
+
+        mem_addr.displacement.wrapping_add(base).wrapping_add(index)
+    }
+
+    // BP based addressing defaults to the stack segment, everything else to the data segment
+    pub fn get_segment_from_mem_address(mem_addr: MemAddress) -> Register {
+        if let Some(segment) = mem_addr.segment {
+            segment
+        } else if mem_addr.base == Some(Register::BP) {
+            Register::SS
+        } else {
+            Register::DS
+        }
+    }
+
+    pub fn physical_address(&self, segment: Register, offset: u16) -> usize {
+        ((self.get_register(segment) as usize) << 4).wrapping_add(offset as usize) & (ADDRESS_SPACE_SIZE - 1)
+    }
+
+    pub fn read_byte_at(&mut self, segment: Register, offset: u16) -> Result<u8, MachineError> {
+        self.read_physical_byte(self.physical_address(segment, offset))
+    }
+
+    // the high byte of a word at offset 0xFFFF is read from offset 0 of the same segment
+    pub fn read_word_at(&mut self, segment: Register, offset: u16) -> Result<u16, MachineError> {
+        let low = self.read_byte_at(segment, offset)? as u16;
+        let high = self.read_byte_at(segment, offset.wrapping_add(1))? as u16;
+        Ok((high << 8) | low)
+    }
+
+    pub fn write_byte_at(&mut self, segment: Register, offset: u16, value: u8) -> Result<(), MachineError> {
+        let address = self.physical_address(segment, offset);
+        self.bus.write_byte(address, value)?;
+
+        for observer in self.observers.iter_mut() {
+            observer.memory_write(address, value);
+        }
+
+        Ok(())
+    }
+
+    fn read_physical_byte(&mut self, address: usize) -> Result<u8, MachineError> {
+        let value = self.bus.read_byte(address)?;
 
-        ptr as usize
+        for observer in self.observers.iter_mut() {
+            observer.memory_read(address, value);
+        }
+
+        Ok(value)
+    }
+
+    fn read_physical_word(&mut self, address: usize) -> Result<u16, MachineError> {
+        let low = self.read_physical_byte(address)? as u16;
+        let high = self.read_physical_byte(address + 1)? as u16;
+        Ok((high << 8) | low)
+    }
+
+    pub fn write_word_at(&mut self, segment: Register, offset: u16, value: u16) -> Result<(), MachineError> {
+        self.write_byte_at(segment, offset, value as u8)?;
+        self.write_byte_at(segment, offset.wrapping_add(1), (value >> 8) as u8)
+    }
+
+    pub fn read_dword_at(&mut self, segment: Register, offset: u16) -> Result<u32, MachineError> {
+        let low = self.read_word_at(segment, offset)? as u32;
+        let high = self.read_word_at(segment, offset.wrapping_add(2))? as u32;
+        Ok((high << 16) | low)
+    }
+
+    pub fn write_dword_at(&mut self, segment: Register, offset: u16, value: u32) -> Result<(), MachineError> {
+        self.write_word_at(segment, offset, value as u16)?;
+        self.write_word_at(segment, offset.wrapping_add(2), (value >> 16) as u16)
     }
 
     pub fn update_zero_flag(&mut self, value: u16) {
         self.set_flag(Flag::ZERO, value == 0);
     }
 
+    pub fn update_sign_flag(&mut self, value: u16, is_8bit: bool) {
+        let sign_bit = if is_8bit { 0x80 } else { 0x8000 };
+        self.set_flag(Flag::SIGN, value & sign_bit != 0);
+    }
+
+    pub fn update_parity_flag(&mut self, value: u16) {
+        // PF only reflects the low byte of the result, even for 16bit operations
+        self.set_flag(Flag::PARITY, (value as u8).count_ones().is_multiple_of(2));
+    }
+
+    // value is expected to be already masked to the operation width
+    pub fn update_result_flags(&mut self, value: u16, is_8bit: bool) {
+        self.update_zero_flag(value);
+        self.update_sign_flag(value, is_8bit);
+        self.update_parity_flag(value);
+    }
+
+    pub fn update_result_flags32(&mut self, value: u32) {
+        self.set_flag(Flag::ZERO, value == 0);
+        self.set_flag(Flag::SIGN, value & 0x80000000 != 0);
+        self.update_parity_flag(value as u16);
+    }
+
     pub fn get_flag(&self, flag: Flag) -> bool {
         self.get_register(Register::F) & flag as u16 != 0
     }
@@ -83,14 +356,17 @@ impl Machine {
         self.set_register(Register::F, flag_reg);
     }
 
+    // 32bit registers are accessed through their low word, see get_register32
     pub fn get_register(&self, register: Register) -> u16 {
         use Register::*;
 
         match register {
             AX | BX | CX | DX | SI | DI | SP | BP | CS | DS | SS | ES | IP | F => {
-                self.registers[register as usize]
+                self.registers[register as usize] as u16
             }
 
+            EAX | EBX | ECX | EDX | ESI | EDI | ESP | EBP => self.get_register(register.to_16bit()),
+
             AH | BH | CH | DH => {
                 let base_reg = match register {
                     AH => AX,
@@ -99,7 +375,7 @@ impl Machine {
                     DH => DX,
                     _ => unreachable!(),
                 };
-                (self.registers[base_reg as usize] & 0xFF00) >> 8
+                (self.get_register(base_reg) & 0xFF00) >> 8
             }
 
             AL | BL | CL | DL => {
@@ -110,7 +386,7 @@ impl Machine {
                     DL => DX,
                     _ => unreachable!(),
                 };
-                self.registers[base_reg as usize] & 0x00FF
+                self.get_register(base_reg) & 0x00FF
             }
         }
     }
@@ -120,9 +396,11 @@ impl Machine {
 
         match register {
             AX | BX | CX | DX | SI | DI | SP | BP | CS | DS | SS | ES | IP | F => {
-                self.registers[register as usize] = value
+                self.write_register_word(register, value)
             }
 
+            EAX | EBX | ECX | EDX | ESI | EDI | ESP | EBP => self.write_register_word(register.to_16bit(), value),
+
             AH | BH | CH | DH => {
                 let base_reg = match register {
                     AH => AX,
@@ -131,8 +409,7 @@ impl Machine {
                     DH => DX,
                     _ => unreachable!(),
                 };
-                self.registers[base_reg as usize] =
-                    (self.get_register(base_reg) & 0x00FF) | ((value & 0x00FF) << 8);
+                self.write_register_word(base_reg, (self.get_register(base_reg) & 0x00FF) | ((value & 0x00FF) << 8));
             }
 
             AL | BL | CL | DL => {
@@ -143,22 +420,140 @@ impl Machine {
                     DL => DX,
                     _ => unreachable!(),
                 };
-                self.registers[base_reg as usize] =
-                    (self.get_register(base_reg) & 0xFF00) | (value & 0x00FF);
+                self.write_register_word(base_reg, (self.get_register(base_reg) & 0xFF00) | (value & 0x00FF));
             }
         }
+
+        if !self.observers.is_empty() {
+            let value = self.get_register(register);
+            for observer in self.observers.iter_mut() {
+                observer.register_write(register, value);
+            }
+        }
+    }
+
+    // the upper word of the 32bit extension is kept
+    fn write_register_word(&mut self, register: Register, value: u16) {
+        let slot = &mut self.registers[register as usize];
+        *slot = (*slot & 0xFFFF0000) | value as u32;
     }
 
+    // 16bit and 8bit registers are zero extended
+    pub fn get_register32(&self, register: Register) -> u32 {
+        if register.is_32bit() {
+            self.registers[register.to_16bit() as usize]
+        } else {
+            self.get_register(register) as u32
+        }
+    }
+
+    // 16bit and 8bit registers are truncated to their width
+    pub fn set_register32(&mut self, register: Register, value: u32) {
+        if !register.is_32bit() {
+            return self.set_register(register, value as u16);
+        }
+
+        self.registers[register.to_16bit() as usize] = value;
+
+        for observer in self.observers.iter_mut() {
+            observer.register_write32(register, value);
+        }
+    }
+
+    pub fn attach_observer(&mut self, observer: Box<dyn Observer>) {
+        self.observers.push(observer);
+    }
+
+    // ports claimed by an earlier device take precedence over later ones
+    pub fn attach_io_device(&mut self, ports: RangeInclusive<u16>, device: Box<dyn IoBus>) {
+        self.io_devices.push((ports, device));
+    }
+
+    fn io_device(&mut self, port: u16) -> Option<&mut Box<dyn IoBus>> {
+        self.io_devices
+            .iter_mut()
+            .find(|(ports, _)| ports.contains(&port))
+            .map(|(_, device)| device)
+    }
+
+    // device answering both bytes of a word access at port
+    fn io_word_device(&mut self, port: u16) -> Option<&mut Box<dyn IoBus>> {
+        let claims = |port: u16| self.io_devices.iter().position(|(ports, _)| ports.contains(&port));
+        match claims(port) {
+            Some(index) if claims(port.wrapping_add(1)) == Some(index) => Some(&mut self.io_devices[index].1),
+            _ => None,
+        }
+    }
+
+    // unclaimed ports float high
+    pub fn read_io_byte(&mut self, port: u16) -> u8 {
+        self.io_device(port).map_or(0xFF, |device| device.read_byte(port))
+    }
+
+    // a word spanning two devices, or reaching an unclaimed port, is split into two byte accesses
+    pub fn read_io_word(&mut self, port: u16) -> u16 {
+        if let Some(device) = self.io_word_device(port) {
+            return device.read_word(port);
+        }
+
+        let low = self.read_io_byte(port) as u16;
+        let high = self.read_io_byte(port.wrapping_add(1)) as u16;
+        (high << 8) | low
+    }
+
+    pub fn write_io_byte(&mut self, port: u16, value: u8) {
+        if let Some(device) = self.io_device(port) {
+            device.write_byte(port, value);
+        }
+    }
+
+    pub fn write_io_word(&mut self, port: u16, value: u16) {
+        if let Some(device) = self.io_word_device(port) {
+            return device.write_word(port, value);
+        }
+
+        self.write_io_byte(port, value as u8);
+        self.write_io_byte(port.wrapping_add(1), (value >> 8) as u8);
+    }
+
+    pub fn map_memory_device(&mut self, range: Range<usize>, device: Box<dyn MemoryDevice>) {
+        self.bus.map(range, device);
+    }
+
+    pub fn bus(&self) -> &MemoryBus {
+        &self.bus
+    }
+
+    pub fn bus_mut(&mut self) -> &mut MemoryBus {
+        &mut self.bus
+    }
+
+    // RAM backing the physical address space, devices mapped on the bus are not visible here
     pub fn memory(&self) -> &LinearMemory {
-        &self.memory
+        self.bus.ram()
     }
 
     pub fn memory_mut(&mut self) -> &mut LinearMemory {
-        &mut self.memory
+        self.bus.ram_mut()
     }
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        let mut machine = Self::with_memory(LinearMemory::default());
+
+        machine.set_register(Register::IP, Self::DEFAULT_LOAD_OFFSET);
+        machine.set_register(Register::SP, Self::DEFAULT_STACK_POINTER);
 
-    pub fn dump_self(&self) {
-        println!(
+        machine
+    }
+}
+
+// one line register dump
+impl fmt::Display for Machine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
             "AX: {} | CX: {} | DX: {} | BX: {} | SP: {} | \
         BP: {} | SI: {} | DI: {} | CS: {} | DS: {} | SS: {} | \
         ES: {} | IP: {} | FLAGS: {:b}",
@@ -176,20 +571,7 @@ impl Machine {
             self.get_register(Register::ES),
             self.get_register(Register::IP),
             self.get_register(Register::F)
-        );
-    }
-}
-
-impl Default for Machine {
-    fn default() -> Self {
-        let mut machine = Self {
-            memory: LinearMemory::default(),
-            registers: [0; 14],
-        };
-
-        machine.set_register(Register::SP, 1024);
-
-        machine
+        )
     }
 }
 
@@ -1,12 +1,19 @@
 
-pub const MEMORY_SIZE: usize = 16 * 1024;
+// 20bit physical address space of real mode
+pub const ADDRESS_SPACE_SIZE: usize = 1024 * 1024;
+pub const MEMORY_SIZE: usize = ADDRESS_SPACE_SIZE;
 
 pub struct LinearMemory {
-    pub data: [u8; MEMORY_SIZE]
+    pub data: Box<[u8]>
 }
 
 
 impl LinearMemory {
+    pub fn new(size: usize) -> Self {
+        Self {
+            data: vec![0; size].into_boxed_slice()
+        }
+    }
 
     pub fn read_byte(&self, ptr: usize) -> u8 {
         self.data[ptr]
@@ -28,8 +35,6 @@ impl LinearMemory {
 
 impl Default for LinearMemory {
     fn default() -> Self {
-        Self {
-            data: [0; MEMORY_SIZE]
-        }
+        Self::new(MEMORY_SIZE)
     }
 }
@@ -1,3 +1,4 @@
+use crate::error::DecodeError;
 use crate::register::Register;
 
 #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
@@ -6,19 +7,51 @@ pub struct MemAddress {
     pub index: Option<Register>,
     pub displacement: u16,
     pub displacement_size: u8,
+    // explicit segment override, the default segment is used when None
+    pub segment: Option<Register>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum Operand {
     Register(Register),
     Memory(MemAddress),
+    // imm8 is sign extended when used as a 16bit operand
+    Immediate8(u8),
+    Immediate16(u16),
+    Immediate32(u32),
 }
 
-pub fn decode_operands_from_mod_rm_opcode(opcode_byte: u8, mem_slice: &[u8]) -> Result<(Operand, Operand, bool), String> {
+impl Operand {
+    pub fn with_segment(self, segment: Register) -> Result<Self, DecodeError> {
+        match self {
+            Operand::Memory(mem_addr) if mem_addr.segment.is_none() => Ok(Operand::Memory(MemAddress {
+                segment: Some(segment),
+                ..mem_addr
+            })),
+            Operand::Memory(_) => Err(DecodeError::DuplicateSegmentOverride),
+            operand => Ok(operand),
+        }
+    }
+
+
+    // Number of bytes the operand occupies in the encoding besides the ModR/M byte
+    pub fn encoded_size(&self) -> u16 {
+        match self {
+            Operand::Register(_) => 0,
+            // segment override prefix is counted towards the memory operand
+            Operand::Memory(mem_addr) => mem_addr.displacement_size as u16 + mem_addr.segment.is_some() as u16,
+            Operand::Immediate8(_) => 1,
+            Operand::Immediate16(_) => 2,
+            Operand::Immediate32(_) => 4,
+        }
+    }
+}
+
+pub fn decode_operands_from_mod_rm_opcode(opcode_byte: u8, mem_slice: &[u8]) -> Result<(Operand, Operand, bool), DecodeError> {
     let is_rm_target = opcode_byte & 0b00000010 == 0; // true if destination should be mod r/m
     let is_8_bit = opcode_byte & 0b00000001 == 0; // true if operating with 8bit registers
 
-    let modrm_byte = mem_slice[0];
+    let modrm_byte = byte_at(mem_slice, 0)?;
     let mod_bits = modrm_byte & 0b11000000;
     let reg_bits = (modrm_byte & 0b00111000) >> 3;
     let rm_bits = modrm_byte & 0b00000111;
@@ -34,7 +67,7 @@ pub fn decode_operands_from_mod_rm_opcode(opcode_byte: u8, mem_slice: &[u8]) ->
         }
     }
 
-    let rm_operand = extract_memory_address_operand(rm_bits, mod_bits, mem_slice);
+    let rm_operand = extract_memory_address_operand(rm_bits, mod_bits, mem_slice)?;
 
     if is_rm_target {
         Ok((rm_operand, Operand::Register(reg), is_8_bit))
@@ -43,8 +76,8 @@ pub fn decode_operands_from_mod_rm_opcode(opcode_byte: u8, mem_slice: &[u8]) ->
     }
 }
 
-pub fn decode_operand_from_single_mod_rm_opcode(mem_slice: &[u8], is_8_bit: bool) -> Result<Operand, String> {
-    let modrm_byte = mem_slice[0];
+pub fn decode_operand_from_single_mod_rm_opcode(mem_slice: &[u8], is_8_bit: bool) -> Result<Operand, DecodeError> {
+    let modrm_byte = byte_at(mem_slice, 0)?;
     let mod_bits = modrm_byte & 0b11000000;
     let rm_bits = modrm_byte & 0b00000111;
 
@@ -53,10 +86,10 @@ pub fn decode_operand_from_single_mod_rm_opcode(mem_slice: &[u8], is_8_bit: bool
         return Ok(Operand::Register(rm))
     }
 
-    Ok(extract_memory_address_operand(rm_bits, mod_bits, mem_slice))
+    extract_memory_address_operand(rm_bits, mod_bits, mem_slice)
 }
 
-pub fn extract_memory_address_operand(rm_bits: u8, mod_bits: u8, mem_slice: &[u8]) -> Operand {
+pub fn extract_memory_address_operand(rm_bits: u8, mod_bits: u8, mem_slice: &[u8]) -> Result<Operand, DecodeError> {
     let mut displacement_size = mod_bits >> 6;
     let (base_reg, index_reg) = match rm_bits {
         0b000 => (Some(Register::BX), Some(Register::SI)),
@@ -65,29 +98,45 @@ pub fn extract_memory_address_operand(rm_bits: u8, mod_bits: u8, mem_slice: &[u8
         0b011 => (Some(Register::BP), Some(Register::DI)),
         0b100 => (None, Some(Register::SI)),
         0b101 => (None, Some(Register::DI)),
-        x if x == 0b110 && mod_bits == 0 => {
+        0b110 if mod_bits == 0 => {
             displacement_size = 2;
             (None, None)
         }
+        0b110 => (Some(Register::BP), None),
         0b111 => (Some(Register::BX), None),
         _ => unreachable!(),
     };
 
     let displacement = match displacement_size {
         0 => 0,
-        1 => mem_slice[1] as u16,
-        2 => u16::from_le_bytes([mem_slice[1], mem_slice[2]]),
+        // disp8 is sign extended
+        1 => byte_at(mem_slice, 1)? as i8 as i16 as u16,
+        2 => word_at(mem_slice, 1)?,
         _ => unreachable!(),
     };
 
-    Operand::Memory(MemAddress {
+    Ok(Operand::Memory(MemAddress {
         base: base_reg,
         index: index_reg,
         displacement,
         displacement_size,
-    })
+        segment: None,
+    }))
 }
 
 pub fn is_reg_only(mod_bits: u8) -> bool {
     mod_bits == 0b11000000
 }
+
+// Bounds checked access to the bytes following the opcode
+pub fn byte_at(mem_slice: &[u8], index: usize) -> Result<u8, DecodeError> {
+    mem_slice.get(index).copied().ok_or(DecodeError::TruncatedInstruction)
+}
+
+pub fn word_at(mem_slice: &[u8], index: usize) -> Result<u16, DecodeError> {
+    Ok(u16::from_le_bytes([byte_at(mem_slice, index)?, byte_at(mem_slice, index + 1)?]))
+}
+
+pub fn dword_at(mem_slice: &[u8], index: usize) -> Result<u32, DecodeError> {
+    Ok((word_at(mem_slice, index + 2)? as u32) << 16 | word_at(mem_slice, index)? as u32)
+}
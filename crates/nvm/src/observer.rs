@@ -0,0 +1,38 @@
+use crate::instruction::Instruction;
+use crate::register::Register;
+
+// Hooks into the execution of a machine, see Machine::attach_observer. Every hook
+// does nothing by default so observers only implement what they are interested in.
+pub trait Observer {
+    // CS:IP is the address of the instruction, IP has not been advanced yet
+    fn before_instruction(&mut self, _segment: u16, _offset: u16, _instruction: &Instruction) {}
+
+    // only called when the instruction executed without error
+    fn after_instruction(&mut self, _segment: u16, _offset: u16, _instruction: &Instruction) {}
+
+    // data accesses by physical address, instruction fetches are not reported
+    fn memory_read(&mut self, _address: usize, _value: u8) {}
+
+    fn memory_write(&mut self, _address: usize, _value: u8) {}
+
+    fn register_write(&mut self, _register: Register, _value: u16) {}
+
+    // full width writes to EAX..EDI, see Machine::set_register32
+    fn register_write32(&mut self, _register: Register, _value: u32) {}
+
+    // only interrupts with a handler installed, see Machine::interrupt
+    fn interrupt(&mut self, _vector: u8) {}
+}
+
+// Prints every executed instruction and interrupt to stdout
+pub struct StdoutTracer;
+
+impl Observer for StdoutTracer {
+    fn before_instruction(&mut self, segment: u16, offset: u16, instruction: &Instruction) {
+        println!("Running instruction at {:04x}:{:04x}: {:?}", segment, offset, instruction);
+    }
+
+    fn interrupt(&mut self, vector: u8) {
+        println!("Interrupt {:#04x}", vector);
+    }
+}
@@ -1,3 +1,6 @@
+use crate::cpu::CpuModel;
+use crate::error::DecodeError;
+
 #[repr(C)]
 #[derive(Debug, PartialEq, Clone, Copy, Eq, Hash, PartialOrd, Ord)]
 pub enum Register {
@@ -20,6 +23,16 @@ pub enum Register {
     IP,
     F,
 
+    // 32bit, sorted, extend the 16bit registers above (80386)
+    EAX = 0x40,
+    ECX = 0x41,
+    EDX = 0x42,
+    EBX = 0x43,
+    ESP = 0x44,
+    EBP = 0x45,
+    ESI = 0x46,
+    EDI = 0x47,
+
     // 8bit, sorted:
     // Low
     AL = 0x80,
@@ -48,10 +61,54 @@ impl Register {
         )
     }
 
-    pub fn from_register_code(code: u8, bits_8: bool) -> Result<Self, String> {
-        let code = if bits_8 { code + 0x80 } else { code };
+    pub fn is_32bit(&self) -> bool {
+        matches!(
+            self,
+            Register::EAX
+                | Register::ECX
+                | Register::EDX
+                | Register::EBX
+                | Register::ESP
+                | Register::EBP
+                | Register::ESI
+                | Register::EDI
+        )
+    }
 
-        match code {
+    // 16bit general purpose register a 32bit one extends, other registers are returned as is
+    pub fn to_16bit(self) -> Self {
+        match self {
+            Register::EAX => Register::AX,
+            Register::ECX => Register::CX,
+            Register::EDX => Register::DX,
+            Register::EBX => Register::BX,
+            Register::ESP => Register::SP,
+            Register::EBP => Register::BP,
+            Register::ESI => Register::SI,
+            Register::EDI => Register::DI,
+            register => register,
+        }
+    }
+
+    // 32bit extension of a 16bit general purpose register, other registers are returned as is
+    pub fn to_32bit(self) -> Self {
+        match self {
+            Register::AX => Register::EAX,
+            Register::CX => Register::ECX,
+            Register::DX => Register::EDX,
+            Register::BX => Register::EBX,
+            Register::SP => Register::ESP,
+            Register::BP => Register::EBP,
+            Register::SI => Register::ESI,
+            Register::DI => Register::EDI,
+            register => register,
+        }
+    }
+
+    pub fn from_register_code(code: u8, bits_8: bool) -> Result<Self, DecodeError> {
+        let lookup = if bits_8 { code.wrapping_add(0x80) } else { code };
+
+        match lookup {
             x if x == Self::AX as u8 => Ok(Self::AX),
             x if x == Self::CX as u8 => Ok(Self::CX),
             x if x == Self::DX as u8 => Ok(Self::DX),
@@ -74,7 +131,17 @@ impl Register {
             x if x == Self::CH as u8 => Ok(Self::CH),
             x if x == Self::DH as u8 => Ok(Self::DH),
             x if x == Self::BH as u8 => Ok(Self::BH),
-            _ => Err(format!("Invalid register code: {}", code)),
+            _ => Err(DecodeError::InvalidRegisterCode(code)),
+        }
+    }
+
+    pub fn from_segment_register_code(code: u8) -> Result<Self, DecodeError> {
+        match code {
+            0b00 => Ok(Self::ES),
+            0b01 => Ok(Self::CS),
+            0b10 => Ok(Self::SS),
+            0b11 => Ok(Self::DS),
+            _ => Err(DecodeError::InvalidSegmentRegisterCode(code)),
         }
     }
 }
@@ -91,3 +158,21 @@ pub enum Flag {
     OVERFLOW = 0b00001000_00000000,
 }
 
+impl Flag {
+    // bits of F POPF/IRET can change, the 80386 adds IOPL and NT in bits 12-14
+    pub fn writable_bits(model: CpuModel) -> u16 {
+        match model {
+            CpuModel::I8086 | CpuModel::I80186 => 0b00001111_11010101,
+            CpuModel::I80386 => 0b01111111_11010101,
+        }
+    }
+
+    // reserved bits that always read back as 1, bits 12-15 only before the 80386
+    pub fn reserved_bits(model: CpuModel) -> u16 {
+        match model {
+            CpuModel::I8086 | CpuModel::I80186 => 0b11110000_00000010,
+            CpuModel::I80386 => 0b00000000_00000010,
+        }
+    }
+}
+
@@ -0,0 +1,156 @@
+use nvm::cpu::CpuModel;
+use nvm::error::BuildError;
+use nvm::memory::{ADDRESS_SPACE_SIZE, MEMORY_SIZE};
+use nvm::register::Register;
+use nvm::{Machine, MachineBuilder};
+
+#[test]
+fn test_builder_defaults_match_machine_default() {
+    let machine = MachineBuilder::new().build().unwrap();
+    let default = Machine::default();
+
+    assert_eq!(machine.memory().data.len(), MEMORY_SIZE);
+    assert_eq!(machine.get_register(Register::CS), 0);
+    assert_eq!(machine.get_register(Register::IP), default.get_register(Register::IP));
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET);
+}
+
+#[test]
+fn test_builder_memory_size() {
+    let machine = MachineBuilder::new().memory_size(64 * 1024).build().unwrap();
+
+    assert_eq!(machine.memory().data.len(), 64 * 1024);
+}
+
+#[test]
+fn test_builder_memory_larger_than_address_space() {
+    let result = MachineBuilder::new().memory_size(ADDRESS_SPACE_SIZE + 1).build();
+
+    assert_eq!(result.err(), Some(BuildError::MemoryTooLarge(ADDRESS_SPACE_SIZE + 1)));
+}
+
+#[test]
+fn test_builder_loads_program_at_load_address() {
+    let machine = MachineBuilder::new()
+        .program([0x90, 0xB4, 0x01])
+        .load_address(0x0070, 0x0100)
+        .build()
+        .unwrap();
+
+    assert_eq!(machine.memory().data[0x0800..0x0803], [0x90, 0xB4, 0x01]);
+    assert_eq!(machine.memory().data[0x07FF], 0x00);
+    assert_eq!(machine.get_register(Register::CS), 0x0070);
+    assert_eq!(machine.get_register(Register::IP), 0x0100);
+}
+
+#[test]
+fn test_builder_entry_point() {
+    let machine = MachineBuilder::new()
+        .program([0x90; 8])
+        .load_address(0x0070, 0x0000)
+        .entry_point(0x0070, 0x0004)
+        .build()
+        .unwrap();
+
+    assert_eq!(machine.get_register(Register::CS), 0x0070);
+    assert_eq!(machine.get_register(Register::IP), 0x0004);
+}
+
+#[test]
+fn test_builder_initial_registers() {
+    let machine = MachineBuilder::new()
+        .register(Register::SS, 0x1000)
+        .register(Register::SP, 0xFFFE)
+        .register(Register::AL, 0x42)
+        .build()
+        .unwrap();
+
+    assert_eq!(machine.get_register(Register::SS), 0x1000);
+    assert_eq!(machine.get_register(Register::SP), 0xFFFE);
+    assert_eq!(machine.get_register(Register::AX), 0x0042);
+}
+
+#[test]
+fn test_builder_program_exactly_fits() {
+    let machine = MachineBuilder::new()
+        .memory_size(0x100)
+        .program([0xAA; 0x10])
+        .load_address(0x0000, 0x00F0)
+        .register(Register::SP, 0x0080)
+        .build()
+        .unwrap();
+
+    assert_eq!(machine.memory().data[0xFF], 0xAA);
+}
+
+#[test]
+fn test_builder_program_does_not_fit() {
+    let result = MachineBuilder::new()
+        .memory_size(0x100)
+        .program([0xAA; 0x10])
+        .load_address(0x0000, 0x00F1)
+        .build();
+
+    assert_eq!(result.err(), Some(BuildError::ProgramTooLarge {
+        address: 0xF1,
+        size: 0x10,
+        memory_size: 0x100,
+    }));
+}
+
+#[test]
+fn test_builder_stack_at_top_of_memory() {
+    let machine = MachineBuilder::new().build().unwrap();
+    assert_eq!(machine.get_register(Register::SS), 0xF000);
+    assert_eq!(machine.get_register(Register::SP), 0x0000);
+
+    let machine = MachineBuilder::new().memory_size(0x18000).build().unwrap();
+    assert_eq!(machine.get_register(Register::SS), 0x0800);
+    assert_eq!(machine.get_register(Register::SP), 0x0000);
+
+    let machine = MachineBuilder::new().memory_size(0x1000).build().unwrap();
+    assert_eq!(machine.get_register(Register::SS), 0x0000);
+    assert_eq!(machine.get_register(Register::SP), 0x1000);
+}
+
+#[test]
+fn test_builder_program_overlaps_stack() {
+    let result = MachineBuilder::new()
+        .memory_size(0x1000)
+        .program([0x90; 0x10])
+        .load_address(0x0000, 0x0FF0)
+        .build();
+
+    assert_eq!(result.err(), Some(BuildError::ProgramOverlapsStack {
+        address: 0x0FF0,
+        size: 0x10,
+        stack: 0x0FFE,
+    }));
+
+    // a program that ends right below the stack still fits
+    let result = MachineBuilder::new()
+        .program([0x90; 0x0300])
+        .register(Register::SS, 0x0000)
+        .register(Register::SP, 0x0402)
+        .build();
+    assert!(result.is_ok());
+
+    let result = MachineBuilder::new()
+        .program([0x50; 0x0301])
+        .register(Register::SS, 0x0000)
+        .register(Register::SP, 0x0400)
+        .build();
+    assert!(matches!(result, Err(BuildError::ProgramOverlapsStack { .. })));
+}
+
+#[test]
+fn test_builder_cpu_model() {
+    let machine = MachineBuilder::new().build().unwrap();
+    assert_eq!(machine.cpu_model(), CpuModel::I80186);
+
+    let machine = MachineBuilder::new().cpu_model(CpuModel::I8086).build().unwrap();
+    assert_eq!(machine.cpu_model(), CpuModel::I8086);
+
+    let machine = MachineBuilder::new().cpu_model(CpuModel::I80386).build().unwrap();
+    assert_eq!(machine.cpu_model(), CpuModel::I80386);
+}
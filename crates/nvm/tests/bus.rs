@@ -0,0 +1,87 @@
+use nvm::bus::{CallbackDevice, MemoryBus, MemoryDevice, Rom};
+use nvm::error::MachineError;
+use nvm::memory::LinearMemory;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn test_bus_reads_and_writes_ram() {
+    let mut bus = MemoryBus::default();
+
+    bus.write_byte(0x1234, 0xAB).unwrap();
+
+    assert_eq!(bus.read_byte(0x1234).unwrap(), 0xAB);
+    assert_eq!(bus.ram().data[0x1234], 0xAB);
+}
+
+#[test]
+fn test_bus_unbacked_address_is_out_of_range() {
+    let mut bus = MemoryBus::new(LinearMemory::new(0x1000));
+
+    assert_eq!(bus.write_byte(0x1000, 0x12), Err(MachineError::MemoryOutOfRange(0x1000)));
+    assert_eq!(bus.read_byte(0x1000), Err(MachineError::MemoryOutOfRange(0x1000)));
+}
+
+#[test]
+fn test_bus_rom_ignores_writes() {
+    let mut bus = MemoryBus::default();
+    bus.map(0xF0000..0xF0004, Box::new(Rom::new(vec![0xEA, 0x00, 0x01, 0x02])));
+
+    bus.write_byte(0xF0001, 0x55).unwrap();
+
+    assert_eq!(bus.read_byte(0xF0000).unwrap(), 0xEA);
+    assert_eq!(bus.read_byte(0xF0001).unwrap(), 0x00);
+    assert_eq!(bus.ram().data[0xF0001], 0x00);
+}
+
+#[test]
+fn test_bus_rom_shorter_than_mapping() {
+    let mut rom = Rom::new(vec![0x11]);
+
+    assert_eq!(rom.read_byte(0), 0x11);
+    assert_eq!(rom.read_byte(1), 0xFF);
+}
+
+#[test]
+fn test_bus_device_overlays_ram() {
+    let mut bus = MemoryBus::default();
+    bus.ram_mut().data[0xB8000] = 0x42;
+    bus.map(0xB8000..0xC0000, Box::new(LinearMemory::default()));
+
+    assert_eq!(bus.read_byte(0xB8000).unwrap(), 0x00);
+
+    bus.write_byte(0xB8000, 0x24).unwrap();
+
+    assert_eq!(bus.read_byte(0xB8000).unwrap(), 0x24);
+    assert_eq!(bus.ram().data[0xB8000], 0x42);
+}
+
+#[test]
+fn test_bus_callback_device_gets_relative_address() {
+    let writes = Rc::new(RefCell::new(Vec::new()));
+    let log = writes.clone();
+
+    let mut bus = MemoryBus::default();
+    bus.map(0x1000..0x1010, Box::new(CallbackDevice::new(
+        |address| address as u8 | 0x80,
+        move |address, value| log.borrow_mut().push((address, value)),
+    )));
+
+    assert_eq!(bus.read_byte(0x1003).unwrap(), 0x83);
+
+    bus.write_byte(0x100F, 0x77).unwrap();
+    bus.write_byte(0x1010, 0x66).unwrap();
+
+    assert_eq!(*writes.borrow(), vec![(0xF, 0x77)]);
+    assert_eq!(bus.ram().data[0x1010], 0x66);
+}
+
+#[test]
+fn test_bus_first_mapping_wins() {
+    let mut bus = MemoryBus::default();
+    bus.map(0x2000..0x2010, Box::new(Rom::new(vec![0x01; 16])));
+    bus.map(0x2008..0x2018, Box::new(Rom::new(vec![0x02; 16])));
+
+    assert_eq!(bus.read_byte(0x2008).unwrap(), 0x01);
+    assert_eq!(bus.read_byte(0x2010).unwrap(), 0x02);
+}
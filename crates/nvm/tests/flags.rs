@@ -104,67 +104,67 @@ pub fn test_set_flag_all() {
     let mut machine = Machine::default();
 
     machine.set_flag(Flag::CARRY, true);
-    assert_eq!(machine.get_flag(Flag::CARRY), true);
+    assert!(machine.get_flag(Flag::CARRY));
 
     machine.set_flag(Flag::PARITY, true);
-    assert_eq!(machine.get_flag(Flag::CARRY), true);
-    assert_eq!(machine.get_flag(Flag::PARITY), true);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::PARITY));
 
     machine.set_flag(Flag::AUXILIARY, true);
-    assert_eq!(machine.get_flag(Flag::CARRY), true);
-    assert_eq!(machine.get_flag(Flag::PARITY), true);
-    assert_eq!(machine.get_flag(Flag::AUXILIARY), true);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::PARITY));
+    assert!(machine.get_flag(Flag::AUXILIARY));
 
     machine.set_flag(Flag::ZERO, true);
-    assert_eq!(machine.get_flag(Flag::CARRY), true);
-    assert_eq!(machine.get_flag(Flag::PARITY), true);
-    assert_eq!(machine.get_flag(Flag::AUXILIARY), true);
-    assert_eq!(machine.get_flag(Flag::ZERO), true);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::PARITY));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+    assert!(machine.get_flag(Flag::ZERO));
 
     machine.set_flag(Flag::SIGN, true);
-    assert_eq!(machine.get_flag(Flag::CARRY), true);
-    assert_eq!(machine.get_flag(Flag::PARITY), true);
-    assert_eq!(machine.get_flag(Flag::AUXILIARY), true);
-    assert_eq!(machine.get_flag(Flag::ZERO), true);
-    assert_eq!(machine.get_flag(Flag::SIGN), true);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::PARITY));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::SIGN));
 
     machine.set_flag(Flag::TRAP, true);
-    assert_eq!(machine.get_flag(Flag::CARRY), true);
-    assert_eq!(machine.get_flag(Flag::PARITY), true);
-    assert_eq!(machine.get_flag(Flag::AUXILIARY), true);
-    assert_eq!(machine.get_flag(Flag::ZERO), true);
-    assert_eq!(machine.get_flag(Flag::SIGN), true);
-    assert_eq!(machine.get_flag(Flag::TRAP), true);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::PARITY));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(machine.get_flag(Flag::TRAP));
 
     machine.set_flag(Flag::INTERRUPT, true);
-    assert_eq!(machine.get_flag(Flag::CARRY), true);
-    assert_eq!(machine.get_flag(Flag::PARITY), true);
-    assert_eq!(machine.get_flag(Flag::AUXILIARY), true);
-    assert_eq!(machine.get_flag(Flag::ZERO), true);
-    assert_eq!(machine.get_flag(Flag::SIGN), true);
-    assert_eq!(machine.get_flag(Flag::TRAP), true);
-    assert_eq!(machine.get_flag(Flag::INTERRUPT), true);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::PARITY));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(machine.get_flag(Flag::TRAP));
+    assert!(machine.get_flag(Flag::INTERRUPT));
 
     machine.set_flag(Flag::DIRECTION, true);
-    assert_eq!(machine.get_flag(Flag::CARRY), true);
-    assert_eq!(machine.get_flag(Flag::PARITY), true);
-    assert_eq!(machine.get_flag(Flag::AUXILIARY), true);
-    assert_eq!(machine.get_flag(Flag::ZERO), true);
-    assert_eq!(machine.get_flag(Flag::SIGN), true);
-    assert_eq!(machine.get_flag(Flag::TRAP), true);
-    assert_eq!(machine.get_flag(Flag::INTERRUPT), true);
-    assert_eq!(machine.get_flag(Flag::DIRECTION), true);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::PARITY));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(machine.get_flag(Flag::TRAP));
+    assert!(machine.get_flag(Flag::INTERRUPT));
+    assert!(machine.get_flag(Flag::DIRECTION));
 
     machine.set_flag(Flag::OVERFLOW, true);
-    assert_eq!(machine.get_flag(Flag::CARRY), true);
-    assert_eq!(machine.get_flag(Flag::PARITY), true);
-    assert_eq!(machine.get_flag(Flag::AUXILIARY), true);
-    assert_eq!(machine.get_flag(Flag::ZERO), true);
-    assert_eq!(machine.get_flag(Flag::SIGN), true);
-    assert_eq!(machine.get_flag(Flag::TRAP), true);
-    assert_eq!(machine.get_flag(Flag::INTERRUPT), true);
-    assert_eq!(machine.get_flag(Flag::DIRECTION), true);
-    assert_eq!(machine.get_flag(Flag::OVERFLOW), true);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::PARITY));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(machine.get_flag(Flag::TRAP));
+    assert!(machine.get_flag(Flag::INTERRUPT));
+    assert!(machine.get_flag(Flag::DIRECTION));
+    assert!(machine.get_flag(Flag::OVERFLOW));
 }
 
 #[machine_test]
@@ -175,3 +175,28 @@ pub fn test_update_zero_flag(mut machine: Machine) {
     machine.update_zero_flag(0);
     assert!(machine.get_flag(Flag::ZERO));
 }
+
+#[machine_test]
+pub fn test_update_sign_flag(mut machine: Machine) {
+    machine.update_sign_flag(0x80, true);
+    assert!(machine.get_flag(Flag::SIGN));
+
+    machine.update_sign_flag(0x80, false);
+    assert!(!machine.get_flag(Flag::SIGN));
+
+    machine.update_sign_flag(0x8000, false);
+    assert!(machine.get_flag(Flag::SIGN));
+}
+
+#[machine_test]
+pub fn test_update_parity_flag(mut machine: Machine) {
+    machine.update_parity_flag(0b0000_0011);
+    assert!(machine.get_flag(Flag::PARITY));
+
+    machine.update_parity_flag(0b0000_0111);
+    assert!(!machine.get_flag(Flag::PARITY));
+
+    // Only the low byte is considered
+    machine.update_parity_flag(0x0100);
+    assert!(machine.get_flag(Flag::PARITY));
+}
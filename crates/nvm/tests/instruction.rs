@@ -1,4 +1,6 @@
-use nvm::instruction::{Instruction, MovMemOperand, Opcode};
+use nvm::cpu::CpuModel;
+use nvm::error::DecodeError;
+use nvm::instruction::{Instruction, IoPort, MovMemOperand, Opcode, RepPrefix, ShiftCount};
 use nvm::modrm::{MemAddress, Operand};
 use nvm::register::Register;
 
@@ -86,6 +88,89 @@ fn test_opcode_from_byte() {
     let noop_opcode = Opcode::try_from(0x0D).unwrap();
     assert_eq!(noop_opcode, Opcode::OR_ACC_16);
 
+    for x in 0x30..=0x33 {
+        let mov_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(mov_opcode, Opcode::XOR);
+    }
+
+    let noop_opcode = Opcode::try_from(0x34).unwrap();
+    assert_eq!(noop_opcode, Opcode::XOR_ACC_8);
+
+    let noop_opcode = Opcode::try_from(0x35).unwrap();
+    assert_eq!(noop_opcode, Opcode::XOR_ACC_16);
+
+    for x in 0x10..=0x13 {
+        let mov_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(mov_opcode, Opcode::ADC);
+    }
+
+    let noop_opcode = Opcode::try_from(0x14).unwrap();
+    assert_eq!(noop_opcode, Opcode::ADC_ACC_8);
+
+    let noop_opcode = Opcode::try_from(0x15).unwrap();
+    assert_eq!(noop_opcode, Opcode::ADC_ACC_16);
+
+    for x in 0x18..=0x1B {
+        let mov_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(mov_opcode, Opcode::SBB);
+    }
+
+    let noop_opcode = Opcode::try_from(0x1C).unwrap();
+    assert_eq!(noop_opcode, Opcode::SBB_ACC_8);
+
+    let noop_opcode = Opcode::try_from(0x1D).unwrap();
+    assert_eq!(noop_opcode, Opcode::SBB_ACC_16);
+
+    for x in 0x38..=0x3B {
+        let mov_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(mov_opcode, Opcode::CMP);
+    }
+
+    let noop_opcode = Opcode::try_from(0x3C).unwrap();
+    assert_eq!(noop_opcode, Opcode::CMP_ACC_8);
+
+    let noop_opcode = Opcode::try_from(0x3D).unwrap();
+    assert_eq!(noop_opcode, Opcode::CMP_ACC_16);
+
+    for x in 0x84..=0x85 {
+        let mov_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(mov_opcode, Opcode::TEST);
+    }
+
+    let noop_opcode = Opcode::try_from(0xA8).unwrap();
+    assert_eq!(noop_opcode, Opcode::TEST_ACC_8);
+
+    let noop_opcode = Opcode::try_from(0xA9).unwrap();
+    assert_eq!(noop_opcode, Opcode::TEST_ACC_16);
+
+    let noop_opcode = Opcode::try_from(0x80).unwrap();
+    assert_eq!(noop_opcode, Opcode::ALU_IMM_8);
+
+    let noop_opcode = Opcode::try_from(0x82).unwrap();
+    assert_eq!(noop_opcode, Opcode::ALU_IMM_8);
+
+    let noop_opcode = Opcode::try_from(0x81).unwrap();
+    assert_eq!(noop_opcode, Opcode::ALU_IMM_16);
+
+    let noop_opcode = Opcode::try_from(0x83).unwrap();
+    assert_eq!(noop_opcode, Opcode::ALU_IMM_16_SIGN_EXT);
+
+    for x in [0xD0, 0xD2] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::SHIFT_8);
+    }
+
+    for x in [0xD1, 0xD3] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::SHIFT_16);
+    }
+
+    let noop_opcode = Opcode::try_from(0xC0).unwrap();
+    assert_eq!(noop_opcode, Opcode::SHIFT_IMM_8);
+
+    let noop_opcode = Opcode::try_from(0xC1).unwrap();
+    assert_eq!(noop_opcode, Opcode::SHIFT_IMM_16);
+
     let noop_opcode = Opcode::try_from(0xF6).unwrap();
     assert_eq!(noop_opcode, Opcode::MUL_DIV_8);
 
@@ -106,6 +191,300 @@ fn test_opcode_from_byte() {
 
     let noop_opcode = Opcode::try_from(0x75).unwrap();
     assert_eq!(noop_opcode, Opcode::JNZ);
+
+    let noop_opcode = Opcode::try_from(0x70).unwrap();
+    assert_eq!(noop_opcode, Opcode::JO);
+
+    let noop_opcode = Opcode::try_from(0x71).unwrap();
+    assert_eq!(noop_opcode, Opcode::JNO);
+
+    let noop_opcode = Opcode::try_from(0x72).unwrap();
+    assert_eq!(noop_opcode, Opcode::JC);
+
+    let noop_opcode = Opcode::try_from(0x73).unwrap();
+    assert_eq!(noop_opcode, Opcode::JNC);
+
+    let noop_opcode = Opcode::try_from(0x76).unwrap();
+    assert_eq!(noop_opcode, Opcode::JBE);
+
+    let noop_opcode = Opcode::try_from(0x77).unwrap();
+    assert_eq!(noop_opcode, Opcode::JA);
+
+    let noop_opcode = Opcode::try_from(0x78).unwrap();
+    assert_eq!(noop_opcode, Opcode::JS);
+
+    let noop_opcode = Opcode::try_from(0x79).unwrap();
+    assert_eq!(noop_opcode, Opcode::JNS);
+
+    let noop_opcode = Opcode::try_from(0x7A).unwrap();
+    assert_eq!(noop_opcode, Opcode::JP);
+
+    let noop_opcode = Opcode::try_from(0x7B).unwrap();
+    assert_eq!(noop_opcode, Opcode::JNP);
+
+    let noop_opcode = Opcode::try_from(0x7C).unwrap();
+    assert_eq!(noop_opcode, Opcode::JL);
+
+    let noop_opcode = Opcode::try_from(0x7D).unwrap();
+    assert_eq!(noop_opcode, Opcode::JGE);
+
+    let noop_opcode = Opcode::try_from(0x7E).unwrap();
+    assert_eq!(noop_opcode, Opcode::JLE);
+
+    let noop_opcode = Opcode::try_from(0x7F).unwrap();
+    assert_eq!(noop_opcode, Opcode::JG);
+
+    let noop_opcode = Opcode::try_from(0xE8).unwrap();
+    assert_eq!(noop_opcode, Opcode::CALL);
+
+    let noop_opcode = Opcode::try_from(0x9A).unwrap();
+    assert_eq!(noop_opcode, Opcode::CALL_FAR);
+
+    let noop_opcode = Opcode::try_from(0xC3).unwrap();
+    assert_eq!(noop_opcode, Opcode::RET);
+
+    let noop_opcode = Opcode::try_from(0xC2).unwrap();
+    assert_eq!(noop_opcode, Opcode::RET_IMM);
+
+    let noop_opcode = Opcode::try_from(0xCB).unwrap();
+    assert_eq!(noop_opcode, Opcode::RETF);
+
+    let noop_opcode = Opcode::try_from(0xCA).unwrap();
+    assert_eq!(noop_opcode, Opcode::RETF_IMM);
+
+    let noop_opcode = Opcode::try_from(0xE0).unwrap();
+    assert_eq!(noop_opcode, Opcode::LOOPNZ);
+
+    let noop_opcode = Opcode::try_from(0xE1).unwrap();
+    assert_eq!(noop_opcode, Opcode::LOOPZ);
+
+    let noop_opcode = Opcode::try_from(0xE2).unwrap();
+    assert_eq!(noop_opcode, Opcode::LOOP);
+
+    let noop_opcode = Opcode::try_from(0xE3).unwrap();
+    assert_eq!(noop_opcode, Opcode::JCXZ);
+
+    for x in [0xA4, 0xA5] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::MOVS);
+    }
+
+    for x in [0xA6, 0xA7] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::CMPS);
+    }
+
+    for x in [0xAA, 0xAB] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::STOS);
+    }
+
+    for x in [0xAC, 0xAD] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::LODS);
+    }
+
+    for x in [0xAE, 0xAF] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::SCAS);
+    }
+
+    let noop_opcode = Opcode::try_from(0xF2).unwrap();
+    assert_eq!(noop_opcode, Opcode::REPNE);
+
+    let noop_opcode = Opcode::try_from(0xF3).unwrap();
+    assert_eq!(noop_opcode, Opcode::REP);
+
+    for x in [0xE4, 0xE5] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::IN_IMM);
+    }
+
+    for x in [0xE6, 0xE7] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::OUT_IMM);
+    }
+
+    for x in [0xEC, 0xED] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::IN_DX);
+    }
+
+    for x in [0xEE, 0xEF] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::OUT_DX);
+    }
+
+    let noop_opcode = Opcode::try_from(0xCC).unwrap();
+    assert_eq!(noop_opcode, Opcode::INT3);
+
+    let noop_opcode = Opcode::try_from(0xCD).unwrap();
+    assert_eq!(noop_opcode, Opcode::INT);
+
+    let noop_opcode = Opcode::try_from(0xCE).unwrap();
+    assert_eq!(noop_opcode, Opcode::INTO);
+
+    let noop_opcode = Opcode::try_from(0xCF).unwrap();
+    assert_eq!(noop_opcode, Opcode::IRET);
+
+    let noop_opcode = Opcode::try_from(0xF4).unwrap();
+    assert_eq!(noop_opcode, Opcode::HLT);
+
+    let noop_opcode = Opcode::try_from(0x8D).unwrap();
+    assert_eq!(noop_opcode, Opcode::LEA);
+
+    let noop_opcode = Opcode::try_from(0xC4).unwrap();
+    assert_eq!(noop_opcode, Opcode::LES);
+
+    let noop_opcode = Opcode::try_from(0xC5).unwrap();
+    assert_eq!(noop_opcode, Opcode::LDS);
+
+    for x in [0x86, 0x87] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::XCHG);
+    }
+
+    for x in 0x91..=0x97 {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::XCHG_ACC);
+    }
+
+    let noop_opcode = Opcode::try_from(0xD7).unwrap();
+    assert_eq!(noop_opcode, Opcode::XLAT);
+
+    let noop_opcode = Opcode::try_from(0x98).unwrap();
+    assert_eq!(noop_opcode, Opcode::CBW);
+
+    let noop_opcode = Opcode::try_from(0x99).unwrap();
+    assert_eq!(noop_opcode, Opcode::CWD);
+
+    let noop_opcode = Opcode::try_from(0x9E).unwrap();
+    assert_eq!(noop_opcode, Opcode::SAHF);
+
+    let noop_opcode = Opcode::try_from(0x9F).unwrap();
+    assert_eq!(noop_opcode, Opcode::LAHF);
+
+    let noop_opcode = Opcode::try_from(0xF5).unwrap();
+    assert_eq!(noop_opcode, Opcode::CMC);
+
+    let noop_opcode = Opcode::try_from(0xF8).unwrap();
+    assert_eq!(noop_opcode, Opcode::CLC);
+
+    let noop_opcode = Opcode::try_from(0xF9).unwrap();
+    assert_eq!(noop_opcode, Opcode::STC);
+
+    let noop_opcode = Opcode::try_from(0xFA).unwrap();
+    assert_eq!(noop_opcode, Opcode::CLI);
+
+    let noop_opcode = Opcode::try_from(0xFB).unwrap();
+    assert_eq!(noop_opcode, Opcode::STI);
+
+    let noop_opcode = Opcode::try_from(0xFC).unwrap();
+    assert_eq!(noop_opcode, Opcode::CLD);
+
+    let noop_opcode = Opcode::try_from(0xFD).unwrap();
+    assert_eq!(noop_opcode, Opcode::STD);
+
+    let noop_opcode = Opcode::try_from(0x9C).unwrap();
+    assert_eq!(noop_opcode, Opcode::PUSHF);
+
+    let noop_opcode = Opcode::try_from(0x9D).unwrap();
+    assert_eq!(noop_opcode, Opcode::POPF);
+
+    let noop_opcode = Opcode::try_from(0xC6).unwrap();
+    assert_eq!(noop_opcode, Opcode::MOV_RM_IMM);
+
+    let noop_opcode = Opcode::try_from(0xC7).unwrap();
+    assert_eq!(noop_opcode, Opcode::MOV_RM_IMM);
+
+    let noop_opcode = Opcode::try_from(0xFE).unwrap();
+    assert_eq!(noop_opcode, Opcode::INC_DEC_8);
+
+    let noop_opcode = Opcode::try_from(0xFF).unwrap();
+    assert_eq!(noop_opcode, Opcode::INC_DEC_16);
+
+    let noop_opcode = Opcode::try_from(0x27).unwrap();
+    assert_eq!(noop_opcode, Opcode::DAA);
+
+    let noop_opcode = Opcode::try_from(0x2F).unwrap();
+    assert_eq!(noop_opcode, Opcode::DAS);
+
+    let noop_opcode = Opcode::try_from(0x37).unwrap();
+    assert_eq!(noop_opcode, Opcode::AAA);
+
+    let noop_opcode = Opcode::try_from(0x3F).unwrap();
+    assert_eq!(noop_opcode, Opcode::AAS);
+
+    let noop_opcode = Opcode::try_from(0xD4).unwrap();
+    assert_eq!(noop_opcode, Opcode::AAM);
+
+    let noop_opcode = Opcode::try_from(0xD5).unwrap();
+    assert_eq!(noop_opcode, Opcode::AAD);
+
+    let noop_opcode = Opcode::try_from(0x60).unwrap();
+    assert_eq!(noop_opcode, Opcode::PUSHA);
+
+    let noop_opcode = Opcode::try_from(0x61).unwrap();
+    assert_eq!(noop_opcode, Opcode::POPA);
+
+    let noop_opcode = Opcode::try_from(0x62).unwrap();
+    assert_eq!(noop_opcode, Opcode::BOUND);
+
+    let noop_opcode = Opcode::try_from(0x68).unwrap();
+    assert_eq!(noop_opcode, Opcode::PUSH_IMM_16);
+
+    let noop_opcode = Opcode::try_from(0x69).unwrap();
+    assert_eq!(noop_opcode, Opcode::IMUL_IMM_16);
+
+    let noop_opcode = Opcode::try_from(0x6A).unwrap();
+    assert_eq!(noop_opcode, Opcode::PUSH_IMM_8);
+
+    let noop_opcode = Opcode::try_from(0x6B).unwrap();
+    assert_eq!(noop_opcode, Opcode::IMUL_IMM_8);
+
+    let noop_opcode = Opcode::try_from(0x6C).unwrap();
+    assert_eq!(noop_opcode, Opcode::INS);
+
+    let noop_opcode = Opcode::try_from(0x6D).unwrap();
+    assert_eq!(noop_opcode, Opcode::INS);
+
+    let noop_opcode = Opcode::try_from(0x6E).unwrap();
+    assert_eq!(noop_opcode, Opcode::OUTS);
+
+    let noop_opcode = Opcode::try_from(0x6F).unwrap();
+    assert_eq!(noop_opcode, Opcode::OUTS);
+
+    let noop_opcode = Opcode::try_from(0xC8).unwrap();
+    assert_eq!(noop_opcode, Opcode::ENTER);
+
+    let noop_opcode = Opcode::try_from(0xC9).unwrap();
+    assert_eq!(noop_opcode, Opcode::LEAVE);
+
+    for x in [0x8C, 0x8E] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::MOV_SREG);
+    }
+
+    for x in [0x06, 0x0E, 0x16, 0x1E] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::PUSH_SREG);
+    }
+
+    for x in [0x07, 0x17, 0x1F] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::POP_SREG);
+    }
+
+    for x in [0x26, 0x2E, 0x36, 0x3E] {
+        let noop_opcode = Opcode::try_from(x).unwrap();
+        assert_eq!(noop_opcode, Opcode::SEGMENT_OVERRIDE);
+    }
+
+    let noop_opcode = Opcode::try_from(0x66).unwrap();
+    assert_eq!(noop_opcode, Opcode::OPERAND_SIZE);
+
+    let noop_opcode = Opcode::try_from(0x0F).unwrap();
+    assert_eq!(noop_opcode, Opcode::TWO_BYTE);
 }
 
 #[test]
@@ -114,16 +493,16 @@ fn test_opcode_from_byte_returns_ok_only_for_explicitly_supported_opcodes() {
         if x == Opcode::NOOP as u8 {
             continue;
         }
-        if x >= 0xB0 && x <= 0xBF {
+        if (0xB0..=0xBF).contains(&x) {
             continue;
         }
-        if x >= 0x88 && x <= 0x8B {
+        if (0x88..=0x8B).contains(&x) {
             continue;
         }
-        if x >= 0xA0 && x <= 0xA3 {
+        if (0xA0..=0xA3).contains(&x) {
             continue;
         }
-        if x >= 0x50 && x <= 0x5F {
+        if (0x50..=0x5F).contains(&x) {
             continue;
         }
         if
@@ -137,7 +516,7 @@ fn test_opcode_from_byte_returns_ok_only_for_explicitly_supported_opcodes() {
         if x == Opcode::ADD_ACC_16 as u8 {
             continue;
         }
-        if x >= 0x28 && x <= 0x2B {
+        if (0x28..=0x2B).contains(&x) {
             continue;
         }
         if x == Opcode::SUB_ACC_8 as u8 {
@@ -146,10 +525,10 @@ fn test_opcode_from_byte_returns_ok_only_for_explicitly_supported_opcodes() {
         if x == Opcode::SUB_ACC_16 as u8 {
             continue;
         }
-        if x >= 0x40 && x <= 0x4F {
+        if (0x40..=0x4F).contains(&x) {
             continue;
         }
-        if x >= 0x20 && x <= 0x23 {
+        if (0x20..=0x23).contains(&x) {
             continue;
         }
         if x == Opcode::AND_ACC_8 as u8 {
@@ -158,7 +537,7 @@ fn test_opcode_from_byte_returns_ok_only_for_explicitly_supported_opcodes() {
         if x == Opcode::AND_ACC_16 as u8 {
             continue;
         }
-        if x >= 0x08 && x <= 0x0B {
+        if (0x08..=0x0B).contains(&x) {
             continue;
         }
         if x == Opcode::OR_ACC_8 as u8 {
@@ -167,6 +546,27 @@ fn test_opcode_from_byte_returns_ok_only_for_explicitly_supported_opcodes() {
         if x == Opcode::OR_ACC_16 as u8 {
             continue;
         }
+        if (0x30..=0x35).contains(&x) {
+            continue;
+        }
+        if (0x10..=0x15).contains(&x) {
+            continue;
+        }
+        if (0x18..=0x1D).contains(&x) {
+            continue;
+        }
+        if (0x38..=0x3D).contains(&x) {
+            continue;
+        }
+        if (0x84..=0x85).contains(&x) || x == 0xA8 || x == 0xA9 {
+            continue;
+        }
+        if (0x80..=0x83).contains(&x) {
+            continue;
+        }
+        if (0xD0..=0xD3).contains(&x) || x == 0xC0 || x == 0xC1 {
+            continue;
+        }
         if x == Opcode::MUL_DIV_8 as u8 {
             continue;
         }
@@ -188,6 +588,60 @@ fn test_opcode_from_byte_returns_ok_only_for_explicitly_supported_opcodes() {
         if x == Opcode::JNZ as u8 {
             continue;
         }
+        if (0x70..=0x7F).contains(&x) {
+            continue;
+        }
+        if x == Opcode::CALL as u8 || x == Opcode::CALL_FAR as u8 {
+            continue;
+        }
+        if (0xC2..=0xC3).contains(&x) || (0xCA..=0xCB).contains(&x) {
+            continue;
+        }
+        if (0xE0..=0xE3).contains(&x) {
+            continue;
+        }
+        if (0xA4..=0xA7).contains(&x) || (0xAA..=0xAF).contains(&x) {
+            continue;
+        }
+        if x == Opcode::REPNE as u8 || x == Opcode::REP as u8 {
+            continue;
+        }
+        if (0xE4..=0xE7).contains(&x) || (0xEC..=0xEF).contains(&x) {
+            continue;
+        }
+        if (0xCC..=0xCF).contains(&x) {
+            continue;
+        }
+        if x == Opcode::HLT as u8 {
+            continue;
+        }
+        if [0x8D, 0xC4, 0xC5, 0x86, 0x87, 0xD7, 0x98, 0x99, 0x9E, 0x9F].contains(&x) || (0x91..=0x97).contains(&x) {
+            continue;
+        }
+        if x == 0xF5 || (0xF8..=0xFD).contains(&x) || x == 0x9C || x == 0x9D {
+            continue;
+        }
+        if [0xC6, 0xC7, 0xFE, 0xFF].contains(&x) {
+            continue;
+        }
+        if [0x27, 0x2F, 0x37, 0x3F, 0xD4, 0xD5].contains(&x) {
+            continue;
+        }
+        if (0x60..=0x62).contains(&x) || (0x68..=0x6F).contains(&x) || x == 0xC8 || x == 0xC9 {
+            continue;
+        }
+        if x == 0x8C || x == 0x8E {
+            continue;
+        }
+        if [0x06, 0x07, 0x0E, 0x16, 0x17, 0x1E, 0x1F].contains(&x) {
+            continue;
+        }
+        if [0x26, 0x2E, 0x36, 0x3E].contains(&x) {
+            continue;
+        }
+        if x == 0x66 || x == 0x0F {
+            continue;
+        }
 
         let result = Opcode::try_from(x);
         assert!(result.is_err())
@@ -196,8 +650,8 @@ fn test_opcode_from_byte_returns_ok_only_for_explicitly_supported_opcodes() {
 
 #[test]
 fn test_opcode_from_invalid_byte() {
-    let result = Opcode::try_from(0xFF);
-    assert!(result.is_err())
+    let result = Opcode::try_from(0xF1);
+    assert_eq!(result, Err(DecodeError::InvalidOpcode(0xF1)))
 }
 
 #[test]
@@ -374,6 +828,7 @@ fn test_instruction_get_size() {
     let instr = Instruction::And(
         Operand::Register(Register::AX),
         Operand::Memory(MemAddress::default()),
+        false,
     );
     assert_eq!(instr.get_instr_size(), 2);
     let instr = Instruction::And(
@@ -382,6 +837,7 @@ fn test_instruction_get_size() {
             displacement_size: 2,
             ..Default::default()
         }),
+        false,
     );
     assert_eq!(instr.get_instr_size(), 4);
     let instr = Instruction::And(
@@ -390,6 +846,7 @@ fn test_instruction_get_size() {
             ..Default::default()
         }),
         Operand::Register(Register::AX),
+        false,
     );
     assert_eq!(instr.get_instr_size(), 5);
     let instr = Instruction::And(
@@ -401,6 +858,7 @@ fn test_instruction_get_size() {
             displacement_size: 3,
             ..Default::default()
         }),
+        false,
     );
     assert_eq!(instr.get_instr_size(), 7);
 
@@ -416,6 +874,7 @@ fn test_instruction_get_size() {
     let instr = Instruction::Or(
         Operand::Register(Register::AX),
         Operand::Memory(MemAddress::default()),
+        false,
     );
     assert_eq!(instr.get_instr_size(), 2);
     let instr = Instruction::Or(
@@ -424,6 +883,7 @@ fn test_instruction_get_size() {
             displacement_size: 2,
             ..Default::default()
         }),
+        false,
     );
     assert_eq!(instr.get_instr_size(), 4);
     let instr = Instruction::Or(
@@ -432,6 +892,7 @@ fn test_instruction_get_size() {
             ..Default::default()
         }),
         Operand::Register(Register::AX),
+        false,
     );
     assert_eq!(instr.get_instr_size(), 5);
     let instr = Instruction::Or(
@@ -443,6 +904,7 @@ fn test_instruction_get_size() {
             displacement_size: 3,
             ..Default::default()
         }),
+        false,
     );
     assert_eq!(instr.get_instr_size(), 7);
 
@@ -453,48 +915,285 @@ fn test_instruction_get_size() {
     assert_eq!(instr.get_instr_size(), 3);
 
     // ===================
-    // ==      MUL      ==
+    // ==      XOR      ==
     // ===================
-
-    let instr = Instruction::Mul8(Operand::Register(Register::AX));
-    assert_eq!(instr.get_instr_size(), 2);
-    let instr = Instruction::Mul8(Operand::Memory(MemAddress {
-        ..Default::default()
-    }));
+    let instr = Instruction::Xor(
+        Operand::Register(Register::AX),
+        Operand::Memory(MemAddress::default()),
+        false,
+    );
     assert_eq!(instr.get_instr_size(), 2);
-    let instr = Instruction::Mul8(Operand::Memory(MemAddress {
-        displacement_size: 3,
-        ..Default::default()
-    }));
-    assert_eq!(instr.get_instr_size(), 5);
+    let instr = Instruction::Xor(
+        Operand::Memory(MemAddress {
+            displacement_size: 2,
+            ..Default::default()
+        }),
+        Operand::Register(Register::AX),
+        false,
+    );
+    assert_eq!(instr.get_instr_size(), 4);
 
-    let instr = Instruction::Mul16(Operand::Register(Register::AX));
-    assert_eq!(instr.get_instr_size(), 2);
-    let instr = Instruction::Mul16(Operand::Memory(MemAddress {
-        ..Default::default()
-    }));
+    let instr = Instruction::XorAcc8(0);
     assert_eq!(instr.get_instr_size(), 2);
-    let instr = Instruction::Mul16(Operand::Memory(MemAddress {
-        displacement_size: 3,
-        ..Default::default()
-    }));
-    assert_eq!(instr.get_instr_size(), 5);
+
+    let instr = Instruction::XorAcc16(0);
+    assert_eq!(instr.get_instr_size(), 3);
 
     // ===================
-    // ==      DIV      ==
+    // ==      ADC      ==
     // ===================
-
-    let instr = Instruction::Div8(Operand::Register(Register::AX));
+    let instr = Instruction::Adc(
+        Operand::Register(Register::AX),
+        Operand::Memory(MemAddress::default()),
+        false,
+    );
     assert_eq!(instr.get_instr_size(), 2);
-    let instr = Instruction::Div8(Operand::Memory(MemAddress {
-        ..Default::default()
-    }));
+    let instr = Instruction::Adc(
+        Operand::Memory(MemAddress {
+            displacement_size: 2,
+            ..Default::default()
+        }),
+        Operand::Register(Register::AX),
+        false,
+    );
+    assert_eq!(instr.get_instr_size(), 4);
+
+    let instr = Instruction::AdcAcc8(0);
     assert_eq!(instr.get_instr_size(), 2);
-    let instr = Instruction::Div8(Operand::Memory(MemAddress {
-        displacement_size: 3,
-        ..Default::default()
-    }));
-    assert_eq!(instr.get_instr_size(), 5);
+
+    let instr = Instruction::AdcAcc16(0);
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // ===================
+    // ==      SBB      ==
+    // ===================
+    let instr = Instruction::Sbb(
+        Operand::Register(Register::AX),
+        Operand::Memory(MemAddress::default()),
+        false,
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Sbb(
+        Operand::Memory(MemAddress {
+            displacement_size: 2,
+            ..Default::default()
+        }),
+        Operand::Register(Register::AX),
+        false,
+    );
+    assert_eq!(instr.get_instr_size(), 4);
+
+    let instr = Instruction::SbbAcc8(0);
+    assert_eq!(instr.get_instr_size(), 2);
+
+    let instr = Instruction::SbbAcc16(0);
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // ===================
+    // ==      CMP      ==
+    // ===================
+    let instr = Instruction::Cmp(
+        Operand::Register(Register::AX),
+        Operand::Memory(MemAddress::default()),
+        false,
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Cmp(
+        Operand::Memory(MemAddress {
+            displacement_size: 2,
+            ..Default::default()
+        }),
+        Operand::Register(Register::AX),
+        false,
+    );
+    assert_eq!(instr.get_instr_size(), 4);
+
+    let instr = Instruction::CmpAcc8(0);
+    assert_eq!(instr.get_instr_size(), 2);
+
+    let instr = Instruction::CmpAcc16(0);
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // ===================
+    // ==      TEST     ==
+    // ===================
+    let instr = Instruction::Test(
+        Operand::Register(Register::AX),
+        Operand::Memory(MemAddress::default()),
+        false,
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Test(
+        Operand::Memory(MemAddress {
+            displacement_size: 2,
+            ..Default::default()
+        }),
+        Operand::Register(Register::AX),
+        false,
+    );
+    assert_eq!(instr.get_instr_size(), 4);
+
+    let instr = Instruction::TestAcc8(0);
+    assert_eq!(instr.get_instr_size(), 2);
+
+    let instr = Instruction::TestAcc16(0);
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // ===================
+    // ==  ALU r/m, imm ==
+    // ===================
+
+    let instr = Instruction::Add(
+        Operand::Register(Register::AL),
+        Operand::Immediate8(0),
+        true,
+    );
+    assert_eq!(instr.get_instr_size(), 3);
+    let instr = Instruction::Add(
+        Operand::Register(Register::AX),
+        Operand::Immediate16(0),
+        false,
+    );
+    assert_eq!(instr.get_instr_size(), 4);
+    let instr = Instruction::Cmp(
+        Operand::Memory(MemAddress {
+            displacement_size: 2,
+            ..Default::default()
+        }),
+        Operand::Immediate16(0),
+        false,
+    );
+    assert_eq!(instr.get_instr_size(), 6);
+    let instr = Instruction::Sub(
+        Operand::Memory(MemAddress {
+            displacement_size: 1,
+            ..Default::default()
+        }),
+        Operand::Immediate8(0),
+        false,
+    );
+    assert_eq!(instr.get_instr_size(), 4);
+
+    // ===================
+    // ==   NOT & NEG   ==
+    // ===================
+
+    let instr = Instruction::Not8(Operand::Register(Register::AL));
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Not16(Operand::Memory(MemAddress {
+        displacement_size: 1,
+        ..Default::default()
+    }));
+    assert_eq!(instr.get_instr_size(), 3);
+    let instr = Instruction::Neg8(Operand::Register(Register::AL));
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Neg16(Operand::Memory(MemAddress {
+        displacement_size: 2,
+        ..Default::default()
+    }));
+    assert_eq!(instr.get_instr_size(), 4);
+
+    // ===================
+    // ==   IMUL/IDIV   ==
+    // ===================
+
+    let instr = Instruction::Imul8(Operand::Register(Register::CL));
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Imul16(Operand::Memory(MemAddress {
+        displacement_size: 2,
+        ..Default::default()
+    }));
+    assert_eq!(instr.get_instr_size(), 4);
+    let instr = Instruction::Idiv8(Operand::Register(Register::CL));
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Idiv16(Operand::Memory(MemAddress {
+        displacement_size: 1,
+        ..Default::default()
+    }));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // ===================
+    // ==    STRING     ==
+    // ===================
+
+    let instr = Instruction::Movs(None, None, true);
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Cmps(None, None, false);
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Stos(Some(RepPrefix::Rep), None, true);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Lods(Some(RepPrefix::Rep), None, false);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Scas(Some(RepPrefix::Repne), None, true);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Movs(None, Some(Register::ES), true);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Lods(Some(RepPrefix::Rep), Some(Register::CS), false);
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // ===================
+    // == SHIFT & ROTATE ==
+    // ===================
+
+    let instr = Instruction::Shl(Operand::Register(Register::AX), ShiftCount::One, false);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Rcr(Operand::Register(Register::AX), ShiftCount::CL, false);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Sar(Operand::Register(Register::AL), ShiftCount::Immediate(1), true);
+    assert_eq!(instr.get_instr_size(), 3);
+    let instr = Instruction::Rol(
+        Operand::Memory(MemAddress {
+            displacement_size: 2,
+            ..Default::default()
+        }),
+        ShiftCount::Immediate(1),
+        false,
+    );
+    assert_eq!(instr.get_instr_size(), 5);
+
+    // ===================
+    // ==      MUL      ==
+    // ===================
+
+    let instr = Instruction::Mul8(Operand::Register(Register::AX));
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Mul8(Operand::Memory(MemAddress {
+        ..Default::default()
+    }));
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Mul8(Operand::Memory(MemAddress {
+        displacement_size: 3,
+        ..Default::default()
+    }));
+    assert_eq!(instr.get_instr_size(), 5);
+
+    let instr = Instruction::Mul16(Operand::Register(Register::AX));
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Mul16(Operand::Memory(MemAddress {
+        ..Default::default()
+    }));
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Mul16(Operand::Memory(MemAddress {
+        displacement_size: 3,
+        ..Default::default()
+    }));
+    assert_eq!(instr.get_instr_size(), 5);
+
+    // ===================
+    // ==      DIV      ==
+    // ===================
+
+    let instr = Instruction::Div8(Operand::Register(Register::AX));
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Div8(Operand::Memory(MemAddress {
+        ..Default::default()
+    }));
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Div8(Operand::Memory(MemAddress {
+        displacement_size: 3,
+        ..Default::default()
+    }));
+    assert_eq!(instr.get_instr_size(), 5);
 
     let instr = Instruction::Div16(Operand::Register(Register::AX));
     assert_eq!(instr.get_instr_size(), 2);
@@ -512,22 +1211,200 @@ fn test_instruction_get_size() {
     // ==     JUMP      ==
     // ===================
 
+    let instr = Instruction::Loop(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Loopz(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Loopnz(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Jcxz(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::CallNear(0);
+    assert_eq!(instr.get_instr_size(), 3);
+    let instr = Instruction::Int(0x21);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::In(IoPort::Immediate(0x60), true);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Out(IoPort::DX, false);
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Int3;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Into;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Iret;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Hlt;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::XchgAcc(Register::BX);
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Xlat(None);
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Xlat(Some(Register::ES));
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Cbw;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Cwd;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Lahf;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Sahf;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Cmc;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Std;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Pushf;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Popf;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Mov(Operand::Register(Register::AL), Operand::Immediate8(0x10));
+    assert_eq!(instr.get_instr_size(), 3);
+    let instr = Instruction::Mov(Operand::Memory(MemAddress {
+        base: None,
+        index: None,
+        displacement: 0x1234,
+        displacement_size: 2,
+        segment: None,
+    }), Operand::Immediate16(0x10));
+    assert_eq!(instr.get_instr_size(), 6);
+    let instr = Instruction::Inc8(Operand::Register(Register::AL));
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Dec16(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0x10,
+        displacement_size: 1,
+        segment: None,
+    }));
+    assert_eq!(instr.get_instr_size(), 3);
+    let instr = Instruction::CallNearIndirect(Operand::Register(Register::BX));
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::JmpFarIndirect(Operand::Memory(MemAddress {
+        base: None,
+        index: None,
+        displacement: 0x1234,
+        displacement_size: 2,
+        segment: Some(Register::ES),
+    }));
+    assert_eq!(instr.get_instr_size(), 5);
+    let instr = Instruction::PushRm(Operand::Register(Register::AX));
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Daa;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Aas;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Aam(10);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Aad(10);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::PushImm8(0x10);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::PushImm16(0x1000);
+    assert_eq!(instr.get_instr_size(), 3);
+    let instr = Instruction::Pusha;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Popa;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::ImulImm(Register::AX, Operand::Register(Register::BX), Operand::Immediate8(0x10));
+    assert_eq!(instr.get_instr_size(), 3);
+    let instr = Instruction::ImulImm(Register::AX, Operand::Register(Register::BX), Operand::Immediate16(0x1000));
+    assert_eq!(instr.get_instr_size(), 4);
+    let instr = Instruction::Enter(0x10, 0);
+    assert_eq!(instr.get_instr_size(), 4);
+    let instr = Instruction::Leave;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::Ins(Some(RepPrefix::Rep), None, true);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Outs(None, Some(Register::ES), false);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::MovImm32(Register::EAX, 0x12345678);
+    assert_eq!(instr.get_instr_size(), 6);
+    let instr = Instruction::AddAcc32(0x12345678);
+    assert_eq!(instr.get_instr_size(), 6);
+    let instr = Instruction::TestAcc32(0x12345678);
+    assert_eq!(instr.get_instr_size(), 6);
+    let instr = Instruction::Mov32(Operand::Register(Register::EAX), Operand::Register(Register::EBX));
+    assert_eq!(instr.get_instr_size(), 3);
+    let instr = Instruction::Add32(Operand::Register(Register::EAX), Operand::Immediate32(0x12345678));
+    assert_eq!(instr.get_instr_size(), 7);
+    let instr = Instruction::Cmp32(Operand::Register(Register::ECX), Operand::Immediate8(0x10));
+    assert_eq!(instr.get_instr_size(), 4);
+    let instr = Instruction::Movzx(Register::AX, Operand::Register(Register::BL), true);
+    assert_eq!(instr.get_instr_size(), 3);
+    let instr = Instruction::Movsx(Register::EAX, Operand::Register(Register::BX), false);
+    assert_eq!(instr.get_instr_size(), 4);
+    let instr = Instruction::CallFar(0, 0);
+    assert_eq!(instr.get_instr_size(), 5);
+    let instr = Instruction::Ret;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::RetImm(0);
+    assert_eq!(instr.get_instr_size(), 3);
+    let instr = Instruction::RetFar;
+    assert_eq!(instr.get_instr_size(), 1);
+    let instr = Instruction::RetFarImm(0);
+    assert_eq!(instr.get_instr_size(), 3);
+
     let instr = Instruction::JmpShort(0);
     assert_eq!(instr.get_instr_size(), 2);
     let instr = Instruction::JmpNear(0);
     assert_eq!(instr.get_instr_size(), 3);
     let instr = Instruction::JmpFar(0, 0);
-    assert_eq!(instr.get_instr_size(), 4);
+    assert_eq!(instr.get_instr_size(), 5);
     let instr = Instruction::Jz(0);
     assert_eq!(instr.get_instr_size(), 2);
     let instr = Instruction::Jnz(0);
     assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Jo(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Jno(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Jc(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Jnc(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Jbe(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Ja(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Js(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Jns(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Jp(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Jnp(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Jl(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Jge(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Jle(0);
+    assert_eq!(instr.get_instr_size(), 2);
+    let instr = Instruction::Jg(0);
+    assert_eq!(instr.get_instr_size(), 2);
 }
 
 #[test]
 fn test_instruction_from_invalid_bytes() {
-    let result = Instruction::from_bytes(0xFF, &[]);
-    assert!(result.is_err());
+    let result = Instruction::from_bytes(0x0F, &[]);
+    assert_eq!(result, Err(DecodeError::InvalidOpcode(0x0F)));
+}
+
+#[test]
+fn test_truncated_instruction_from_bytes() {
+    // MOV AX, imm16
+    assert_eq!(Instruction::from_bytes(0xB8, &[0x01]), Err(DecodeError::TruncatedInstruction));
+    // ADD AX, [BX + disp16]
+    assert_eq!(Instruction::from_bytes(0x03, &[0b10000111, 0x01]), Err(DecodeError::TruncatedInstruction));
+    // ADD [BX], imm16
+    assert_eq!(Instruction::from_bytes(0x81, &[0b00000111, 0x01]), Err(DecodeError::TruncatedInstruction));
+    // missing ModR/M byte
+    assert_eq!(Instruction::from_bytes(0x88, &[]), Err(DecodeError::TruncatedInstruction));
+    // JMP FAR
+    assert_eq!(Instruction::from_bytes(0xEA, &[0x00, 0x01, 0x00]), Err(DecodeError::TruncatedInstruction));
+    // prefix without instruction
+    assert_eq!(Instruction::from_bytes(0xF3, &[]), Err(DecodeError::TruncatedInstruction));
+    assert_eq!(Instruction::from_bytes(0x26, &[]), Err(DecodeError::TruncatedInstruction));
 }
 
 #[test]
@@ -653,6 +1530,7 @@ fn test_mov_reg_mem_instruction_from_bytes() {
                     index: Some(Register::DI),
                     displacement: 0,
                     displacement_size: 0,
+                    segment: None,
                 }),
                 Operand::Register(Register::CL)
             )
@@ -672,6 +1550,7 @@ fn test_mov_reg_mem_instruction_from_bytes() {
                     index: Some(Register::DI),
                     displacement: 0,
                     displacement_size: 0,
+                    segment: None,
                 })
             )
         );
@@ -694,6 +1573,7 @@ fn test_mov_reg_mem_instruction_from_bytes() {
                     index: Some(Register::DI),
                     displacement: 0,
                     displacement_size: 0,
+                    segment: None,
                 }),
                 Operand::Register(Register::CX)
             )
@@ -713,6 +1593,7 @@ fn test_mov_reg_mem_instruction_from_bytes() {
                     index: Some(Register::DI),
                     displacement: 0,
                     displacement_size: 0,
+                    segment: None,
                 })
             )
         );
@@ -738,6 +1619,7 @@ fn test_mov_reg_mem_mod_0_instruction_from_bytes() {
                     index: Some(Register::SI),
                     displacement: 0,
                     displacement_size: 0,
+                    segment: None,
                 }),
                 reg_operand
             )
@@ -753,6 +1635,7 @@ fn test_mov_reg_mem_mod_0_instruction_from_bytes() {
                     index: Some(Register::DI),
                     displacement: 0,
                     displacement_size: 0,
+                    segment: None,
                 }),
                 reg_operand
             )
@@ -768,6 +1651,7 @@ fn test_mov_reg_mem_mod_0_instruction_from_bytes() {
                     index: Some(Register::SI),
                     displacement: 0,
                     displacement_size: 0,
+                    segment: None,
                 }),
                 reg_operand
             )
@@ -783,6 +1667,7 @@ fn test_mov_reg_mem_mod_0_instruction_from_bytes() {
                     index: Some(Register::DI),
                     displacement: 0,
                     displacement_size: 0,
+                    segment: None,
                 }),
                 reg_operand
             )
@@ -798,6 +1683,7 @@ fn test_mov_reg_mem_mod_0_instruction_from_bytes() {
                     index: Some(Register::SI),
                     displacement: 0,
                     displacement_size: 0,
+                    segment: None,
                 }),
                 reg_operand
             )
@@ -813,6 +1699,7 @@ fn test_mov_reg_mem_mod_0_instruction_from_bytes() {
                     index: Some(Register::DI),
                     displacement: 0,
                     displacement_size: 0,
+                    segment: None,
                 }),
                 reg_operand
             )
@@ -828,6 +1715,7 @@ fn test_mov_reg_mem_mod_0_instruction_from_bytes() {
                     index: None,
                     displacement: 0xBBAA,
                     displacement_size: 2,
+                    segment: None,
                 }),
                 reg_operand
             )
@@ -844,6 +1732,7 @@ fn test_mov_reg_mem_mod_0_instruction_from_bytes() {
                     index: None,
                     displacement: 0,
                     displacement_size: 0,
+                    segment: None,
                 }),
                 reg_operand
             )
@@ -867,8 +1756,9 @@ fn test_mov_reg_mem_displacement_instruction_from_bytes() {
                 Operand::Memory(MemAddress {
                     base: Some(Register::BX),
                     index: Some(Register::SI),
-                    displacement: 0xAA,
+                    displacement: 0xFFAA,
                     displacement_size: 1,
+                    segment: None,
                 }),
                 reg_operand
             )
@@ -885,6 +1775,7 @@ fn test_mov_reg_mem_displacement_instruction_from_bytes() {
                     index: Some(Register::SI),
                     displacement: 0xBBAA,
                     displacement_size: 2,
+                    segment: None,
                 }),
                 reg_operand
             )
@@ -1037,6 +1928,7 @@ fn test_add_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
+                segment: None,
             }),
             Operand::Register(Register::AL),
             true
@@ -1054,6 +1946,7 @@ fn test_add_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
+                segment: None,
             }),
             Operand::Register(Register::AX),
             false
@@ -1072,6 +1965,7 @@ fn test_add_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
+                segment: None,
             }),
             true
         )
@@ -1089,6 +1983,7 @@ fn test_add_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
+                segment: None,
             }),
             false
         )
@@ -1106,6 +2001,7 @@ fn test_add_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
+                segment: None,
             }),
             Operand::Register(Register::AL),
             true
@@ -1123,6 +2019,7 @@ fn test_add_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
+                segment: None,
             }),
             Operand::Register(Register::AX),
             false
@@ -1141,6 +2038,7 @@ fn test_add_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
+                segment: None,
             }),
             true
         )
@@ -1158,6 +2056,7 @@ fn test_add_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
+                segment: None,
             }),
             false
         )
@@ -1175,6 +2074,7 @@ fn test_add_instruction_from_bytes() {
                 index: None,
                 displacement: 0xBBFF,
                 displacement_size: 2,
+                segment: None,
             }),
             false
         )
@@ -1220,6 +2120,7 @@ fn test_sub_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
+                segment: None,
             }),
             Operand::Register(Register::AL),
             true
@@ -1237,6 +2138,7 @@ fn test_sub_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
+                segment: None,
             }),
             Operand::Register(Register::AX),
             false
@@ -1255,6 +2157,7 @@ fn test_sub_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
+                segment: None,
             }),
             true
         )
@@ -1272,6 +2175,7 @@ fn test_sub_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
+                segment: None,
             }),
             false
         )
@@ -1289,6 +2193,7 @@ fn test_sub_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
+                segment: None,
             }),
             Operand::Register(Register::AL),
             true
@@ -1306,6 +2211,7 @@ fn test_sub_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
+                segment: None,
             }),
             Operand::Register(Register::AX),
             false
@@ -1324,6 +2230,7 @@ fn test_sub_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
+                segment: None,
             }),
             true
         )
@@ -1341,6 +2248,7 @@ fn test_sub_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
+                segment: None,
             }),
             false
         )
@@ -1358,6 +2266,7 @@ fn test_sub_instruction_from_bytes() {
                 index: None,
                 displacement: 0xBBFF,
                 displacement_size: 2,
+                segment: None,
             }),
             false
         )
@@ -1441,7 +2350,8 @@ fn test_and_instruction_from_bytes() {
         instr,
         Instruction::And(
             Operand::Register(Register::AL),
-            Operand::Register(Register::AL)
+            Operand::Register(Register::AL),
+            true
         )
     );
     assert_eq!(instr.get_instr_size(), 2);
@@ -1456,8 +2366,10 @@ fn test_and_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
+                segment: None,
             }),
-            Operand::Register(Register::AL)
+            Operand::Register(Register::AL),
+            true
         )
     );
     assert_eq!(instr.get_instr_size(), 2);
@@ -1472,8 +2384,10 @@ fn test_and_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
+                segment: None,
             }),
-            Operand::Register(Register::AX)
+            Operand::Register(Register::AX),
+            false
         )
     );
     assert_eq!(instr.get_instr_size(), 2);
@@ -1489,7 +2403,9 @@ fn test_and_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
-            })
+                segment: None,
+            }),
+            true
         )
     );
     assert_eq!(instr.get_instr_size(), 2);
@@ -1505,7 +2421,9 @@ fn test_and_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
-            })
+                segment: None,
+            }),
+            false
         )
     );
     assert_eq!(instr.get_instr_size(), 2);
@@ -1521,8 +2439,10 @@ fn test_and_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
+                segment: None,
             }),
-            Operand::Register(Register::AL)
+            Operand::Register(Register::AL),
+            true
         )
     );
     assert_eq!(instr.get_instr_size(), 4);
@@ -1537,8 +2457,10 @@ fn test_and_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
+                segment: None,
             }),
-            Operand::Register(Register::AX)
+            Operand::Register(Register::AX),
+            false
         )
     );
     assert_eq!(instr.get_instr_size(), 4);
@@ -1554,7 +2476,9 @@ fn test_and_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
-            })
+                segment: None,
+            }),
+            true
         )
     );
     assert_eq!(instr.get_instr_size(), 4);
@@ -1570,7 +2494,9 @@ fn test_and_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
-            })
+                segment: None,
+            }),
+            false
         )
     );
     assert_eq!(instr.get_instr_size(), 4);
@@ -1586,7 +2512,9 @@ fn test_and_instruction_from_bytes() {
                 index: None,
                 displacement: 0xBBFF,
                 displacement_size: 2,
-            })
+                segment: None,
+            }),
+            false
         )
     );
     assert_eq!(instr.get_instr_size(), 4);
@@ -1614,7 +2542,8 @@ fn test_or_instruction_from_bytes() {
         instr,
         Instruction::Or(
             Operand::Register(Register::AL),
-            Operand::Register(Register::AL)
+            Operand::Register(Register::AL),
+            true
         )
     );
     assert_eq!(instr.get_instr_size(), 2);
@@ -1629,8 +2558,10 @@ fn test_or_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
+                segment: None,
             }),
-            Operand::Register(Register::AL)
+            Operand::Register(Register::AL),
+            true
         )
     );
     assert_eq!(instr.get_instr_size(), 2);
@@ -1645,8 +2576,10 @@ fn test_or_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
+                segment: None,
             }),
-            Operand::Register(Register::AX)
+            Operand::Register(Register::AX),
+            false
         )
     );
     assert_eq!(instr.get_instr_size(), 2);
@@ -1662,7 +2595,9 @@ fn test_or_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
-            })
+                segment: None,
+            }),
+            true
         )
     );
     assert_eq!(instr.get_instr_size(), 2);
@@ -1678,7 +2613,9 @@ fn test_or_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0,
                 displacement_size: 0,
-            })
+                segment: None,
+            }),
+            false
         )
     );
     assert_eq!(instr.get_instr_size(), 2);
@@ -1694,8 +2631,10 @@ fn test_or_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
+                segment: None,
             }),
-            Operand::Register(Register::AL)
+            Operand::Register(Register::AL),
+            true
         )
     );
     assert_eq!(instr.get_instr_size(), 4);
@@ -1710,8 +2649,10 @@ fn test_or_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
+                segment: None,
             }),
-            Operand::Register(Register::AX)
+            Operand::Register(Register::AX),
+            false
         )
     );
     assert_eq!(instr.get_instr_size(), 4);
@@ -1727,7 +2668,9 @@ fn test_or_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
-            })
+                segment: None,
+            }),
+            true
         )
     );
     assert_eq!(instr.get_instr_size(), 4);
@@ -1743,7 +2686,9 @@ fn test_or_instruction_from_bytes() {
                 index: Some(Register::SI),
                 displacement: 0xBBFF,
                 displacement_size: 2,
-            })
+                segment: None,
+            }),
+            false
         )
     );
     assert_eq!(instr.get_instr_size(), 4);
@@ -1759,7 +2704,9 @@ fn test_or_instruction_from_bytes() {
                 index: None,
                 displacement: 0xBBFF,
                 displacement_size: 2,
-            })
+                segment: None,
+            }),
+            false
         )
     );
     assert_eq!(instr.get_instr_size(), 4);
@@ -1779,10 +2726,10 @@ fn test_or_acc_16_instruction_from_bytes() {
 
 #[test]
 fn test_mul_8_instruction_from_bytes() {
-    let instr = Instruction::from_bytes(0xF6, &[0b11001000]).unwrap();
+    let instr = Instruction::from_bytes(0xF6, &[0b11100000]).unwrap();
     assert_eq!(instr, Instruction::Mul8(Operand::Register(Register::AL)));
 
-    let instr = Instruction::from_bytes(0xF6, &[0b00001000]).unwrap();
+    let instr = Instruction::from_bytes(0xF6, &[0b00100000]).unwrap();
     assert_eq!(
         instr,
         Instruction::Mul8(Operand::Memory(MemAddress {
@@ -1790,21 +2737,23 @@ fn test_mul_8_instruction_from_bytes() {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }))
     );
 
-    let instr = Instruction::from_bytes(0xF6, &[0b01001000, 0xFF]).unwrap();
+    let instr = Instruction::from_bytes(0xF6, &[0b01100000, 0xFF]).unwrap();
     assert_eq!(
         instr,
         Instruction::Mul8(Operand::Memory(MemAddress {
             base: Some(Register::BX),
             index: Some(Register::SI),
-            displacement: 0xFF,
+            displacement: 0xFFFF,
             displacement_size: 1,
+            segment: None,
         }))
     );
 
-    let instr = Instruction::from_bytes(0xF6, &[0b10001000, 0xFF, 0xAA]).unwrap();
+    let instr = Instruction::from_bytes(0xF6, &[0b10100000, 0xFF, 0xAA]).unwrap();
     assert_eq!(
         instr,
         Instruction::Mul8(Operand::Memory(MemAddress {
@@ -1812,10 +2761,11 @@ fn test_mul_8_instruction_from_bytes() {
             index: Some(Register::SI),
             displacement: 0xAAFF,
             displacement_size: 2,
+            segment: None,
         }))
     );
 
-    let instr = Instruction::from_bytes(0xF6, &[0b00001110, 0xFF, 0xAA]).unwrap();
+    let instr = Instruction::from_bytes(0xF6, &[0b00100110, 0xFF, 0xAA]).unwrap();
     assert_eq!(
         instr,
         Instruction::Mul8(Operand::Memory(MemAddress {
@@ -1823,16 +2773,17 @@ fn test_mul_8_instruction_from_bytes() {
             index: None,
             displacement: 0xAAFF,
             displacement_size: 2,
+            segment: None,
         }))
     );
 }
 
 #[test]
 fn test_mul_16_instruction_from_bytes() {
-    let instr = Instruction::from_bytes(0xF7, &[0b11001000]).unwrap();
+    let instr = Instruction::from_bytes(0xF7, &[0b11100000]).unwrap();
     assert_eq!(instr, Instruction::Mul16(Operand::Register(Register::AX)));
 
-    let instr = Instruction::from_bytes(0xF7, &[0b00001000]).unwrap();
+    let instr = Instruction::from_bytes(0xF7, &[0b00100000]).unwrap();
     assert_eq!(
         instr,
         Instruction::Mul16(Operand::Memory(MemAddress {
@@ -1840,21 +2791,23 @@ fn test_mul_16_instruction_from_bytes() {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }))
     );
 
-    let instr = Instruction::from_bytes(0xF7, &[0b01001000, 0xFF]).unwrap();
+    let instr = Instruction::from_bytes(0xF7, &[0b01100000, 0xFF]).unwrap();
     assert_eq!(
         instr,
         Instruction::Mul16(Operand::Memory(MemAddress {
             base: Some(Register::BX),
             index: Some(Register::SI),
-            displacement: 0xFF,
+            displacement: 0xFFFF,
             displacement_size: 1,
+            segment: None,
         }))
     );
 
-    let instr = Instruction::from_bytes(0xF7, &[0b10001000, 0xFF, 0xAA]).unwrap();
+    let instr = Instruction::from_bytes(0xF7, &[0b10100000, 0xFF, 0xAA]).unwrap();
     assert_eq!(
         instr,
         Instruction::Mul16(Operand::Memory(MemAddress {
@@ -1862,10 +2815,11 @@ fn test_mul_16_instruction_from_bytes() {
             index: Some(Register::SI),
             displacement: 0xAAFF,
             displacement_size: 2,
+            segment: None,
         }))
     );
 
-    let instr = Instruction::from_bytes(0xF7, &[0b00001110, 0xFF, 0xAA]).unwrap();
+    let instr = Instruction::from_bytes(0xF7, &[0b00100110, 0xFF, 0xAA]).unwrap();
     assert_eq!(
         instr,
         Instruction::Mul16(Operand::Memory(MemAddress {
@@ -1873,6 +2827,7 @@ fn test_mul_16_instruction_from_bytes() {
             index: None,
             displacement: 0xAAFF,
             displacement_size: 2,
+            segment: None,
         }))
     );
 }
@@ -1890,6 +2845,7 @@ fn test_div_8_instruction_from_bytes() {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }))
     );
 
@@ -1899,8 +2855,9 @@ fn test_div_8_instruction_from_bytes() {
         Instruction::Div8(Operand::Memory(MemAddress {
             base: Some(Register::BX),
             index: Some(Register::SI),
-            displacement: 0xFF,
+            displacement: 0xFFFF,
             displacement_size: 1,
+            segment: None,
         }))
     );
 
@@ -1912,6 +2869,7 @@ fn test_div_8_instruction_from_bytes() {
             index: Some(Register::SI),
             displacement: 0xAAFF,
             displacement_size: 2,
+            segment: None,
         }))
     );
 
@@ -1923,6 +2881,7 @@ fn test_div_8_instruction_from_bytes() {
             index: None,
             displacement: 0xAAFF,
             displacement_size: 2,
+            segment: None,
         }))
     );
 }
@@ -1940,6 +2899,7 @@ fn test_div_16_instruction_from_bytes() {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }))
     );
 
@@ -1949,8 +2909,9 @@ fn test_div_16_instruction_from_bytes() {
         Instruction::Div16(Operand::Memory(MemAddress {
             base: Some(Register::BX),
             index: Some(Register::SI),
-            displacement: 0xFF,
+            displacement: 0xFFFF,
             displacement_size: 1,
+            segment: None,
         }))
     );
 
@@ -1962,6 +2923,7 @@ fn test_div_16_instruction_from_bytes() {
             index: Some(Register::SI),
             displacement: 0xAAFF,
             displacement_size: 2,
+            segment: None,
         }))
     );
 
@@ -1973,6 +2935,7 @@ fn test_div_16_instruction_from_bytes() {
             index: None,
             displacement: 0xAAFF,
             displacement_size: 2,
+            segment: None,
         }))
     );
 }
@@ -2018,3 +2981,1768 @@ fn test_jnz_near_instruction_from_bytes() {
     let instr = Instruction::from_bytes(0x75, &[0xFF]).unwrap();
     assert_eq!(instr, Instruction::Jnz(-1));
 }
+
+#[test]
+fn test_jo_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x70, &[0b01111111]).unwrap();
+    assert_eq!(instr, Instruction::Jo(0b01111111));
+
+    let instr = Instruction::from_bytes(0x70, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::Jo(-1));
+}
+
+#[test]
+fn test_jno_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x71, &[0b01111111]).unwrap();
+    assert_eq!(instr, Instruction::Jno(0b01111111));
+
+    let instr = Instruction::from_bytes(0x71, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::Jno(-1));
+}
+
+#[test]
+fn test_jc_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x72, &[0b01111111]).unwrap();
+    assert_eq!(instr, Instruction::Jc(0b01111111));
+
+    let instr = Instruction::from_bytes(0x72, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::Jc(-1));
+}
+
+#[test]
+fn test_jnc_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x73, &[0b01111111]).unwrap();
+    assert_eq!(instr, Instruction::Jnc(0b01111111));
+
+    let instr = Instruction::from_bytes(0x73, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::Jnc(-1));
+}
+
+#[test]
+fn test_jbe_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x76, &[0b01111111]).unwrap();
+    assert_eq!(instr, Instruction::Jbe(0b01111111));
+
+    let instr = Instruction::from_bytes(0x76, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::Jbe(-1));
+}
+
+#[test]
+fn test_ja_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x77, &[0b01111111]).unwrap();
+    assert_eq!(instr, Instruction::Ja(0b01111111));
+
+    let instr = Instruction::from_bytes(0x77, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::Ja(-1));
+}
+
+#[test]
+fn test_js_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x78, &[0b01111111]).unwrap();
+    assert_eq!(instr, Instruction::Js(0b01111111));
+
+    let instr = Instruction::from_bytes(0x78, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::Js(-1));
+}
+
+#[test]
+fn test_jns_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x79, &[0b01111111]).unwrap();
+    assert_eq!(instr, Instruction::Jns(0b01111111));
+
+    let instr = Instruction::from_bytes(0x79, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::Jns(-1));
+}
+
+#[test]
+fn test_jp_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x7A, &[0b01111111]).unwrap();
+    assert_eq!(instr, Instruction::Jp(0b01111111));
+
+    let instr = Instruction::from_bytes(0x7A, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::Jp(-1));
+}
+
+#[test]
+fn test_jnp_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x7B, &[0b01111111]).unwrap();
+    assert_eq!(instr, Instruction::Jnp(0b01111111));
+
+    let instr = Instruction::from_bytes(0x7B, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::Jnp(-1));
+}
+
+#[test]
+fn test_jl_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x7C, &[0b01111111]).unwrap();
+    assert_eq!(instr, Instruction::Jl(0b01111111));
+
+    let instr = Instruction::from_bytes(0x7C, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::Jl(-1));
+}
+
+#[test]
+fn test_jge_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x7D, &[0b01111111]).unwrap();
+    assert_eq!(instr, Instruction::Jge(0b01111111));
+
+    let instr = Instruction::from_bytes(0x7D, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::Jge(-1));
+}
+
+#[test]
+fn test_jle_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x7E, &[0b01111111]).unwrap();
+    assert_eq!(instr, Instruction::Jle(0b01111111));
+
+    let instr = Instruction::from_bytes(0x7E, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::Jle(-1));
+}
+
+#[test]
+fn test_jg_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x7F, &[0b01111111]).unwrap();
+    assert_eq!(instr, Instruction::Jg(0b01111111));
+
+    let instr = Instruction::from_bytes(0x7F, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::Jg(-1));
+}
+
+#[test]
+fn test_xor_instruction_from_bytes() {
+    // r/m8 <- r8
+    let instr = Instruction::from_bytes(0x30, &[0b11001000]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Xor(
+            Operand::Register(Register::AL),
+            Operand::Register(Register::CL),
+            true
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+
+    // r/m16 <- r16
+    let instr = Instruction::from_bytes(0x31, &[0b01000000, 0x10]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Xor(
+            Operand::Memory(MemAddress {
+                base: Some(Register::BX),
+                index: Some(Register::SI),
+                displacement: 0x10,
+                displacement_size: 1,
+                segment: None,
+            }),
+            Operand::Register(Register::AX),
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // r8 <- r/m8
+    let instr = Instruction::from_bytes(0x32, &[0b00001111]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Xor(
+            Operand::Register(Register::CL),
+            Operand::Memory(MemAddress {
+                base: Some(Register::BX),
+                index: None,
+                displacement: 0,
+                displacement_size: 0,
+                segment: None,
+            }),
+            true
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+
+    // r16 <- r/m16
+    let instr = Instruction::from_bytes(0x33, &[0b11011000]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Xor(
+            Operand::Register(Register::BX),
+            Operand::Register(Register::AX),
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+}
+
+#[test]
+fn test_xor_acc_8_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x34, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::XorAcc8(0xFF));
+}
+
+#[test]
+fn test_xor_acc_16_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x35, &[0xFF, 0xAA]).unwrap();
+    assert_eq!(instr, Instruction::XorAcc16(0xAAFF));
+}
+
+#[test]
+fn test_adc_instruction_from_bytes() {
+    // r/m8 <- r8
+    let instr = Instruction::from_bytes(0x10, &[0b11001000]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Adc(
+            Operand::Register(Register::AL),
+            Operand::Register(Register::CL),
+            true
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+
+    // r/m16 <- r16
+    let instr = Instruction::from_bytes(0x11, &[0b01000000, 0x10]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Adc(
+            Operand::Memory(MemAddress {
+                base: Some(Register::BX),
+                index: Some(Register::SI),
+                displacement: 0x10,
+                displacement_size: 1,
+                segment: None,
+            }),
+            Operand::Register(Register::AX),
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // r8 <- r/m8
+    let instr = Instruction::from_bytes(0x12, &[0b00001111]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Adc(
+            Operand::Register(Register::CL),
+            Operand::Memory(MemAddress {
+                base: Some(Register::BX),
+                index: None,
+                displacement: 0,
+                displacement_size: 0,
+                segment: None,
+            }),
+            true
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+
+    // r16 <- r/m16
+    let instr = Instruction::from_bytes(0x13, &[0b11011000]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Adc(
+            Operand::Register(Register::BX),
+            Operand::Register(Register::AX),
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+}
+
+#[test]
+fn test_adc_acc_8_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x14, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::AdcAcc8(0xFF));
+}
+
+#[test]
+fn test_adc_acc_16_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x15, &[0xFF, 0xAA]).unwrap();
+    assert_eq!(instr, Instruction::AdcAcc16(0xAAFF));
+}
+
+#[test]
+fn test_sbb_instruction_from_bytes() {
+    // r/m8 <- r8
+    let instr = Instruction::from_bytes(0x18, &[0b11001000]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Sbb(
+            Operand::Register(Register::AL),
+            Operand::Register(Register::CL),
+            true
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+
+    // r/m16 <- r16
+    let instr = Instruction::from_bytes(0x19, &[0b01000000, 0x10]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Sbb(
+            Operand::Memory(MemAddress {
+                base: Some(Register::BX),
+                index: Some(Register::SI),
+                displacement: 0x10,
+                displacement_size: 1,
+                segment: None,
+            }),
+            Operand::Register(Register::AX),
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // r8 <- r/m8
+    let instr = Instruction::from_bytes(0x1A, &[0b00001111]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Sbb(
+            Operand::Register(Register::CL),
+            Operand::Memory(MemAddress {
+                base: Some(Register::BX),
+                index: None,
+                displacement: 0,
+                displacement_size: 0,
+                segment: None,
+            }),
+            true
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+
+    // r16 <- r/m16
+    let instr = Instruction::from_bytes(0x1B, &[0b11011000]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Sbb(
+            Operand::Register(Register::BX),
+            Operand::Register(Register::AX),
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+}
+
+#[test]
+fn test_sbb_acc_8_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x1C, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::SbbAcc8(0xFF));
+}
+
+#[test]
+fn test_sbb_acc_16_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x1D, &[0xFF, 0xAA]).unwrap();
+    assert_eq!(instr, Instruction::SbbAcc16(0xAAFF));
+}
+
+#[test]
+fn test_cmp_instruction_from_bytes() {
+    // r/m8 <- r8
+    let instr = Instruction::from_bytes(0x38, &[0b11001000]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Cmp(
+            Operand::Register(Register::AL),
+            Operand::Register(Register::CL),
+            true
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+
+    // r/m16 <- r16
+    let instr = Instruction::from_bytes(0x39, &[0b01000000, 0x10]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Cmp(
+            Operand::Memory(MemAddress {
+                base: Some(Register::BX),
+                index: Some(Register::SI),
+                displacement: 0x10,
+                displacement_size: 1,
+                segment: None,
+            }),
+            Operand::Register(Register::AX),
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // r8 <- r/m8
+    let instr = Instruction::from_bytes(0x3A, &[0b00001111]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Cmp(
+            Operand::Register(Register::CL),
+            Operand::Memory(MemAddress {
+                base: Some(Register::BX),
+                index: None,
+                displacement: 0,
+                displacement_size: 0,
+                segment: None,
+            }),
+            true
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+
+    // r16 <- r/m16
+    let instr = Instruction::from_bytes(0x3B, &[0b11011000]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Cmp(
+            Operand::Register(Register::BX),
+            Operand::Register(Register::AX),
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+}
+
+#[test]
+fn test_cmp_acc_8_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x3C, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::CmpAcc8(0xFF));
+}
+
+#[test]
+fn test_cmp_acc_16_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0x3D, &[0xFF, 0xAA]).unwrap();
+    assert_eq!(instr, Instruction::CmpAcc16(0xAAFF));
+}
+
+#[test]
+fn test_test_instruction_from_bytes() {
+    // r/m8, r8
+    let instr = Instruction::from_bytes(0x84, &[0b11001000]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Test(
+            Operand::Register(Register::AL),
+            Operand::Register(Register::CL),
+            true
+        )
+    );
+
+    // r/m16, r16
+    let instr = Instruction::from_bytes(0x85, &[0b00000111]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Test(
+            Operand::Memory(MemAddress {
+                base: Some(Register::BX),
+                index: None,
+                displacement: 0,
+                displacement_size: 0,
+                segment: None,
+            }),
+            Operand::Register(Register::AX),
+            false
+        )
+    );
+}
+
+#[test]
+fn test_test_acc_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0xA8, &[0xFF]).unwrap();
+    assert_eq!(instr, Instruction::TestAcc8(0xFF));
+
+    let instr = Instruction::from_bytes(0xA9, &[0xFF, 0xAA]).unwrap();
+    assert_eq!(instr, Instruction::TestAcc16(0xAAFF));
+}
+
+#[test]
+fn test_not_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0xF6, &[0b11010000]).unwrap();
+    assert_eq!(instr, Instruction::Not8(Operand::Register(Register::AL)));
+
+    let instr = Instruction::from_bytes(0xF7, &[0b01010000, 0xFF]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Not16(Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: Some(Register::SI),
+            displacement: 0xFFFF,
+            displacement_size: 1,
+            segment: None,
+        }))
+    );
+}
+
+#[test]
+fn test_neg_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0xF6, &[0b11011001]).unwrap();
+    assert_eq!(instr, Instruction::Neg8(Operand::Register(Register::CL)));
+
+    let instr = Instruction::from_bytes(0xF7, &[0b00011110, 0xFF, 0xAA]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Neg16(Operand::Memory(MemAddress {
+            base: None,
+            index: None,
+            displacement: 0xAAFF,
+            displacement_size: 2,
+            segment: None,
+        }))
+    );
+}
+
+#[test]
+fn test_alu_imm_8_instruction_from_bytes() {
+    // ADD BL, 0x05
+    let instr = Instruction::from_bytes(0x80, &[0b11000011, 0x05]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Add(
+            Operand::Register(Register::BL),
+            Operand::Immediate8(0x05),
+            true
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // CMP BYTE [BX + SI + 0x10], 0xFF
+    let instr = Instruction::from_bytes(0x80, &[0b01111000, 0x10, 0xFF]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Cmp(
+            Operand::Memory(MemAddress {
+                base: Some(Register::BX),
+                index: Some(Register::SI),
+                displacement: 0x10,
+                displacement_size: 1,
+                segment: None,
+            }),
+            Operand::Immediate8(0xFF),
+            true
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 4);
+
+    // 0x82 is an alias of 0x80
+    let instr = Instruction::from_bytes(0x82, &[0b11001011, 0x05]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Or(
+            Operand::Register(Register::BL),
+            Operand::Immediate8(0x05),
+            true
+        )
+    );
+}
+
+#[test]
+fn test_alu_imm_16_instruction_from_bytes() {
+    // SUB WORD [0xAAFF], 0x1234
+    let instr = Instruction::from_bytes(0x81, &[0b00101110, 0xFF, 0xAA, 0x34, 0x12]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Sub(
+            Operand::Memory(MemAddress {
+                base: None,
+                index: None,
+                displacement: 0xAAFF,
+                displacement_size: 2,
+                segment: None,
+            }),
+            Operand::Immediate16(0x1234),
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 6);
+
+    // AND CX, 0x00FF
+    let instr = Instruction::from_bytes(0x81, &[0b11100001, 0xFF, 0x00]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::And(
+            Operand::Register(Register::CX),
+            Operand::Immediate16(0x00FF),
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 4);
+}
+
+#[test]
+fn test_alu_imm_16_sign_extended_instruction_from_bytes() {
+    // ADD BX, 5
+    let instr = Instruction::from_bytes(0x83, &[0b11000011, 0x05]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Add(
+            Operand::Register(Register::BX),
+            Operand::Immediate8(0x05),
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // CMP WORD [SI], 0x10
+    let instr = Instruction::from_bytes(0x83, &[0b00111100, 0x10]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Cmp(
+            Operand::Memory(MemAddress {
+                base: None,
+                index: Some(Register::SI),
+                displacement: 0,
+                displacement_size: 0,
+                segment: None,
+            }),
+            Operand::Immediate8(0x10),
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 3);
+}
+
+#[test]
+fn test_alu_imm_group_selects_operation_by_reg_bits() {
+    let operations = [
+        Instruction::Add(Operand::Register(Register::AX), Operand::Immediate8(1), false),
+        Instruction::Or(Operand::Register(Register::AX), Operand::Immediate8(1), false),
+        Instruction::Adc(Operand::Register(Register::AX), Operand::Immediate8(1), false),
+        Instruction::Sbb(Operand::Register(Register::AX), Operand::Immediate8(1), false),
+        Instruction::And(Operand::Register(Register::AX), Operand::Immediate8(1), false),
+        Instruction::Sub(Operand::Register(Register::AX), Operand::Immediate8(1), false),
+        Instruction::Xor(Operand::Register(Register::AX), Operand::Immediate8(1), false),
+        Instruction::Cmp(Operand::Register(Register::AX), Operand::Immediate8(1), false),
+    ];
+
+    for (reg_bits, expected) in operations.iter().enumerate() {
+        let modrm = 0b11000000 | ((reg_bits as u8) << 3);
+        let instr = Instruction::from_bytes(0x83, &[modrm, 0x01]).unwrap();
+        assert_eq!(instr, *expected);
+    }
+}
+
+#[test]
+fn test_shift_by_one_instruction_from_bytes() {
+    // SHL AL, 1
+    let instr = Instruction::from_bytes(0xD0, &[0b11100000]).unwrap();
+    assert_eq!(instr, Instruction::Shl(Operand::Register(Register::AL), ShiftCount::One, true));
+    assert_eq!(instr.get_instr_size(), 2);
+
+    // SAR WORD [BX + 0x10], 1
+    let instr = Instruction::from_bytes(0xD1, &[0b01111111, 0x10]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Sar(
+            Operand::Memory(MemAddress {
+                base: Some(Register::BX),
+                index: None,
+                displacement: 0x10,
+                displacement_size: 1,
+                segment: None,
+            }),
+            ShiftCount::One,
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 3);
+}
+
+#[test]
+fn test_shift_by_cl_instruction_from_bytes() {
+    // ROL BL, CL
+    let instr = Instruction::from_bytes(0xD2, &[0b11000011]).unwrap();
+    assert_eq!(instr, Instruction::Rol(Operand::Register(Register::BL), ShiftCount::CL, true));
+
+    // SHR DX, CL
+    let instr = Instruction::from_bytes(0xD3, &[0b11101010]).unwrap();
+    assert_eq!(instr, Instruction::Shr(Operand::Register(Register::DX), ShiftCount::CL, false));
+    assert_eq!(instr.get_instr_size(), 2);
+}
+
+#[test]
+fn test_shift_by_imm_instruction_from_bytes() {
+    // RCL CL, 3
+    let instr = Instruction::from_bytes(0xC0, &[0b11010001, 0x03]).unwrap();
+    assert_eq!(instr, Instruction::Rcl(Operand::Register(Register::CL), ShiftCount::Immediate(3), true));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // RCR WORD [0xAAFF], 4
+    let instr = Instruction::from_bytes(0xC1, &[0b00011110, 0xFF, 0xAA, 0x04]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Rcr(
+            Operand::Memory(MemAddress {
+                base: None,
+                index: None,
+                displacement: 0xAAFF,
+                displacement_size: 2,
+                segment: None,
+            }),
+            ShiftCount::Immediate(4),
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 5);
+}
+
+#[test]
+fn test_shift_group_selects_operation_by_reg_bits() {
+    let operand = Operand::Register(Register::AX);
+    let operations = [
+        Instruction::Rol(operand, ShiftCount::One, false),
+        Instruction::Ror(operand, ShiftCount::One, false),
+        Instruction::Rcl(operand, ShiftCount::One, false),
+        Instruction::Rcr(operand, ShiftCount::One, false),
+        Instruction::Shl(operand, ShiftCount::One, false),
+        Instruction::Shr(operand, ShiftCount::One, false),
+        Instruction::Shl(operand, ShiftCount::One, false),
+        Instruction::Sar(operand, ShiftCount::One, false),
+    ];
+
+    for (reg_bits, expected) in operations.iter().enumerate() {
+        let modrm = 0b11000000 | ((reg_bits as u8) << 3);
+        let instr = Instruction::from_bytes(0xD1, &[modrm]).unwrap();
+        assert_eq!(instr, *expected);
+    }
+}
+
+#[test]
+fn test_call_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0xE8, &[0xFF, 0x0A]).unwrap();
+    assert_eq!(instr, Instruction::CallNear(0x0AFF));
+
+    let instr = Instruction::from_bytes(0xE8, &[0xFF, 0xAA]).unwrap();
+    assert_eq!(instr, Instruction::CallNear(-0x5501));
+
+    let instr = Instruction::from_bytes(0x9A, &[0xAA, 0xBB, 0xCC, 0xDD]).unwrap();
+    assert_eq!(instr, Instruction::CallFar(0xDDCC, 0xBBAA));
+}
+
+#[test]
+fn test_ret_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0xC3, &[]).unwrap();
+    assert_eq!(instr, Instruction::Ret);
+
+    let instr = Instruction::from_bytes(0xC2, &[0x04, 0x01]).unwrap();
+    assert_eq!(instr, Instruction::RetImm(0x0104));
+
+    let instr = Instruction::from_bytes(0xCB, &[]).unwrap();
+    assert_eq!(instr, Instruction::RetFar);
+
+    let instr = Instruction::from_bytes(0xCA, &[0x06, 0x00]).unwrap();
+    assert_eq!(instr, Instruction::RetFarImm(0x0006));
+}
+
+#[test]
+fn test_loop_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0xE0, &[0xFE]).unwrap();
+    assert_eq!(instr, Instruction::Loopnz(-2));
+
+    let instr = Instruction::from_bytes(0xE1, &[0x10]).unwrap();
+    assert_eq!(instr, Instruction::Loopz(0x10));
+
+    let instr = Instruction::from_bytes(0xE2, &[0xF0]).unwrap();
+    assert_eq!(instr, Instruction::Loop(-0x10));
+
+    let instr = Instruction::from_bytes(0xE3, &[0x7F]).unwrap();
+    assert_eq!(instr, Instruction::Jcxz(0x7F));
+}
+
+#[test]
+fn test_string_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0xA4, &[]).unwrap();
+    assert_eq!(instr, Instruction::Movs(None, None, true));
+    let instr = Instruction::from_bytes(0xA5, &[]).unwrap();
+    assert_eq!(instr, Instruction::Movs(None, None, false));
+
+    let instr = Instruction::from_bytes(0xA6, &[]).unwrap();
+    assert_eq!(instr, Instruction::Cmps(None, None, true));
+    let instr = Instruction::from_bytes(0xA7, &[]).unwrap();
+    assert_eq!(instr, Instruction::Cmps(None, None, false));
+
+    let instr = Instruction::from_bytes(0xAA, &[]).unwrap();
+    assert_eq!(instr, Instruction::Stos(None, None, true));
+    let instr = Instruction::from_bytes(0xAB, &[]).unwrap();
+    assert_eq!(instr, Instruction::Stos(None, None, false));
+
+    let instr = Instruction::from_bytes(0xAC, &[]).unwrap();
+    assert_eq!(instr, Instruction::Lods(None, None, true));
+    let instr = Instruction::from_bytes(0xAD, &[]).unwrap();
+    assert_eq!(instr, Instruction::Lods(None, None, false));
+
+    let instr = Instruction::from_bytes(0xAE, &[]).unwrap();
+    assert_eq!(instr, Instruction::Scas(None, None, true));
+    let instr = Instruction::from_bytes(0xAF, &[]).unwrap();
+    assert_eq!(instr, Instruction::Scas(None, None, false));
+}
+
+#[test]
+fn test_rep_prefix_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0xF3, &[0xA4]).unwrap();
+    assert_eq!(instr, Instruction::Movs(Some(RepPrefix::Rep), None, true));
+    assert_eq!(instr.get_instr_size(), 2);
+
+    let instr = Instruction::from_bytes(0xF3, &[0xA7]).unwrap();
+    assert_eq!(instr, Instruction::Cmps(Some(RepPrefix::Rep), None, false));
+
+    let instr = Instruction::from_bytes(0xF2, &[0xAE]).unwrap();
+    assert_eq!(instr, Instruction::Scas(Some(RepPrefix::Repne), None, true));
+    assert_eq!(instr.get_instr_size(), 2);
+}
+
+#[test]
+fn test_rep_prefix_on_non_string_instruction_from_bytes() {
+    // REP NOP
+    let instr = Instruction::from_bytes(0xF3, &[0x90]).unwrap();
+    assert_eq!(instr, Instruction::IgnoredPrefixes(1, Box::new(Instruction::Noop)));
+    assert_eq!(instr.get_instr_size(), 2);
+    // REPNE ADD AL, 1
+    let instr = Instruction::from_bytes(0xF2, &[0x04, 0x01]).unwrap();
+    assert_eq!(instr, Instruction::IgnoredPrefixes(1, Box::new(Instruction::AddAcc8(0x01))));
+    assert_eq!(instr.get_instr_size(), 3);
+    // REP RET
+    let instr = Instruction::from_bytes(0xF3, &[0xC3]).unwrap();
+    assert_eq!(instr, Instruction::IgnoredPrefixes(1, Box::new(Instruction::Ret)));
+    assert_eq!(instr.get_instr_size(), 2);
+    // ES: REP MOV AX, [BX]
+    let instr = Instruction::from_bytes(0x26, &[0xF3, 0x8B, 0b00000111]).unwrap();
+    assert_eq!(instr, Instruction::IgnoredPrefixes(1, Box::new(Instruction::Mov(
+        Operand::Register(Register::AX),
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0,
+            displacement_size: 0,
+            segment: Some(Register::ES),
+        }),
+    ))));
+    assert_eq!(instr.get_instr_size(), 4);
+    // REP CS: NOP
+    let instr = Instruction::from_bytes(0xF3, &[0x2E, 0x90]).unwrap();
+    assert_eq!(instr, Instruction::IgnoredPrefixes(2, Box::new(Instruction::Noop)));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    assert_eq!(Instruction::from_bytes(0xF3, &[0xF3, 0xA4]), Err(DecodeError::DuplicateRepeatPrefix));
+}
+
+#[test]
+fn test_bp_displacement_instruction_from_bytes() {
+    // MOV AX, [BP + 0x10]
+    let instr = Instruction::from_bytes(0x8B, &[0b01000110, 0x10]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Mov(
+            Operand::Register(Register::AX),
+            Operand::Memory(MemAddress {
+                base: Some(Register::BP),
+                index: None,
+                displacement: 0x10,
+                displacement_size: 1,
+                segment: None,
+            })
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // MOV [BP + 0x1234], CL
+    let instr = Instruction::from_bytes(0x88, &[0b10001110, 0x34, 0x12]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Mov(
+            Operand::Memory(MemAddress {
+                base: Some(Register::BP),
+                index: None,
+                displacement: 0x1234,
+                displacement_size: 2,
+                segment: None,
+            }),
+            Operand::Register(Register::CL)
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 4);
+}
+
+#[test]
+fn test_mov_sreg_instruction_from_bytes() {
+    // MOV DS, AX
+    let instr = Instruction::from_bytes(0x8E, &[0b11011000]).unwrap();
+    assert_eq!(instr, Instruction::Mov(Operand::Register(Register::DS), Operand::Register(Register::AX)));
+    assert_eq!(instr.get_instr_size(), 2);
+
+    // MOV [BX + 0x10], ES
+    let instr = Instruction::from_bytes(0x8C, &[0b01000111, 0x10]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Mov(
+            Operand::Memory(MemAddress {
+                base: Some(Register::BX),
+                index: None,
+                displacement: 0x10,
+                displacement_size: 1,
+                segment: None,
+            }),
+            Operand::Register(Register::ES)
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // MOV CX, SS
+    let instr = Instruction::from_bytes(0x8C, &[0b11010001]).unwrap();
+    assert_eq!(instr, Instruction::Mov(Operand::Register(Register::CX), Operand::Register(Register::SS)));
+}
+
+#[test]
+fn test_push_pop_sreg_instruction_from_bytes() {
+    assert_eq!(Instruction::from_bytes(0x06, &[]).unwrap(), Instruction::Push(Register::ES));
+    assert_eq!(Instruction::from_bytes(0x0E, &[]).unwrap(), Instruction::Push(Register::CS));
+    assert_eq!(Instruction::from_bytes(0x16, &[]).unwrap(), Instruction::Push(Register::SS));
+    assert_eq!(Instruction::from_bytes(0x1E, &[]).unwrap(), Instruction::Push(Register::DS));
+    assert_eq!(Instruction::from_bytes(0x07, &[]).unwrap(), Instruction::Pop(Register::ES));
+    assert_eq!(Instruction::from_bytes(0x17, &[]).unwrap(), Instruction::Pop(Register::SS));
+    assert_eq!(Instruction::from_bytes(0x1F, &[]).unwrap(), Instruction::Pop(Register::DS));
+    assert_eq!(Instruction::from_bytes(0x0F, &[]), Err(DecodeError::InvalidOpcode(0x0F)));
+}
+
+#[test]
+fn test_segment_override_instruction_from_bytes() {
+    // MOV AX, ES:[BX]
+    let instr = Instruction::from_bytes(0x26, &[0x8B, 0b00000111]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Mov(
+            Operand::Register(Register::AX),
+            Operand::Memory(MemAddress {
+                base: Some(Register::BX),
+                index: None,
+                displacement: 0,
+                displacement_size: 0,
+                segment: Some(Register::ES),
+            })
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // ADD WORD SS:[0x1234], 0x05
+    let instr = Instruction::from_bytes(0x36, &[0x83, 0b00000110, 0x34, 0x12, 0x05]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Add(
+            Operand::Memory(MemAddress {
+                base: None,
+                index: None,
+                displacement: 0x1234,
+                displacement_size: 2,
+                segment: Some(Register::SS),
+            }),
+            Operand::Immediate8(0x05),
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 6);
+
+    // MOV AL, CS:[0x0010]
+    let instr = Instruction::from_bytes(0x2E, &[0xA0, 0x10, 0x00]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::MovAccMem(
+            MovMemOperand::Register(Register::AL),
+            MovMemOperand::SegmentedMemoryPtr(Register::CS, 0x0010)
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 4);
+}
+
+#[test]
+fn test_segment_override_with_rep_prefix_instruction_from_bytes() {
+    // REP MOVSB ES:[SI]
+    let instr = Instruction::from_bytes(0xF3, &[0x26, 0xA4]).unwrap();
+    assert_eq!(instr, Instruction::Movs(Some(RepPrefix::Rep), Some(Register::ES), true));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    let instr = Instruction::from_bytes(0x3E, &[0xF3, 0xA5]).unwrap();
+    assert_eq!(instr, Instruction::Movs(Some(RepPrefix::Rep), Some(Register::DS), false));
+    assert_eq!(instr.get_instr_size(), 3);
+}
+
+#[test]
+fn test_segment_override_without_memory_operand_from_bytes() {
+    // NOP
+    let instr = Instruction::from_bytes(0x26, &[0x90]).unwrap();
+    assert_eq!(instr, Instruction::IgnoredPrefixes(1, Box::new(Instruction::Noop)));
+    assert_eq!(instr.get_instr_size(), 2);
+    // MOV AX, BX
+    let instr = Instruction::from_bytes(0x26, &[0x8B, 0b11000011]).unwrap();
+    assert_eq!(instr, Instruction::IgnoredPrefixes(1, Box::new(Instruction::Mov(
+        Operand::Register(Register::AX),
+        Operand::Register(Register::BX),
+    ))));
+    assert_eq!(instr.get_instr_size(), 3);
+    // JZ 0
+    let instr = Instruction::from_bytes(0x2E, &[0x74, 0x00]).unwrap();
+    assert_eq!(instr, Instruction::IgnoredPrefixes(1, Box::new(Instruction::Jz(0))));
+    assert_eq!(instr.get_instr_size(), 3);
+    // DS: CS: PUSH AX
+    let instr = Instruction::from_bytes(0x3E, &[0x2E, 0x50]).unwrap();
+    assert_eq!(instr, Instruction::IgnoredPrefixes(2, Box::new(Instruction::Push(Register::AX))));
+    assert_eq!(instr.get_instr_size(), 3);
+    // ES: ES: MOVSB
+    assert_eq!(Instruction::from_bytes(0x26, &[0x26, 0xA4]), Err(DecodeError::DuplicateSegmentOverride));
+    // ES: CS: MOV AX, [BX]
+    assert_eq!(Instruction::from_bytes(0x26, &[0x2E, 0x8B, 0b00000111]), Err(DecodeError::DuplicateSegmentOverride));
+}
+
+#[test]
+fn test_mul_div_group_selects_operation_by_reg_bits() {
+    let operand = Operand::Register(Register::BX);
+    let operations = [
+        Instruction::Test(operand, Operand::Immediate16(0x1234), false),
+        Instruction::Test(operand, Operand::Immediate16(0x1234), false),
+        Instruction::Not16(operand),
+        Instruction::Neg16(operand),
+        Instruction::Mul16(operand),
+        Instruction::Imul16(operand),
+        Instruction::Div16(operand),
+        Instruction::Idiv16(operand),
+    ];
+
+    for (reg_bits, expected) in operations.iter().enumerate() {
+        let modrm = 0b11000011 | ((reg_bits as u8) << 3);
+        let instr = Instruction::from_bytes(0xF7, &[modrm, 0x34, 0x12]).unwrap();
+        assert_eq!(instr, *expected);
+    }
+}
+
+#[test]
+fn test_imul_idiv_instruction_from_bytes() {
+    // IMUL CL
+    let instr = Instruction::from_bytes(0xF6, &[0b11101001]).unwrap();
+    assert_eq!(instr, Instruction::Imul8(Operand::Register(Register::CL)));
+
+    // IDIV BYTE [BX + 0x10]
+    let instr = Instruction::from_bytes(0xF6, &[0b01111111, 0x10]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Idiv8(Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0x10,
+            displacement_size: 1,
+            segment: None,
+        }))
+    );
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // IMUL WORD [0xAAFF]
+    let instr = Instruction::from_bytes(0xF7, &[0b00101110, 0xFF, 0xAA]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Imul16(Operand::Memory(MemAddress {
+            base: None,
+            index: None,
+            displacement: 0xAAFF,
+            displacement_size: 2,
+            segment: None,
+        }))
+    );
+
+    // IDIV CX
+    let instr = Instruction::from_bytes(0xF7, &[0b11111001]).unwrap();
+    assert_eq!(instr, Instruction::Idiv16(Operand::Register(Register::CX)));
+}
+
+#[test]
+fn test_test_imm_instruction_from_bytes() {
+    // TEST AL, 0x80
+    let instr = Instruction::from_bytes(0xF6, &[0b11000000, 0x80]).unwrap();
+    assert_eq!(instr, Instruction::Test(Operand::Register(Register::AL), Operand::Immediate8(0x80), true));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // TEST WORD [BX + 0x10], 0x1234
+    let instr = Instruction::from_bytes(0xF7, &[0b01000111, 0x10, 0x34, 0x12]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Test(
+            Operand::Memory(MemAddress {
+                base: Some(Register::BX),
+                index: None,
+                displacement: 0x10,
+                displacement_size: 1,
+                segment: None,
+            }),
+            Operand::Immediate16(0x1234),
+            false
+        )
+    );
+    assert_eq!(instr.get_instr_size(), 5);
+}
+
+#[test]
+fn test_interrupt_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0xCD, &[0x21]).unwrap();
+    assert_eq!(instr, Instruction::Int(0x21));
+
+    let instr = Instruction::from_bytes(0xCC, &[]).unwrap();
+    assert_eq!(instr, Instruction::Int3);
+
+    let instr = Instruction::from_bytes(0xCE, &[]).unwrap();
+    assert_eq!(instr, Instruction::Into);
+
+    let instr = Instruction::from_bytes(0xCF, &[]).unwrap();
+    assert_eq!(instr, Instruction::Iret);
+}
+
+#[test]
+fn test_hlt_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0xF4, &[]).unwrap();
+    assert_eq!(instr, Instruction::Hlt);
+}
+
+#[test]
+fn test_in_out_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0xE4, &[0x60]).unwrap();
+    assert_eq!(instr, Instruction::In(IoPort::Immediate(0x60), true));
+    let instr = Instruction::from_bytes(0xE5, &[0x60]).unwrap();
+    assert_eq!(instr, Instruction::In(IoPort::Immediate(0x60), false));
+
+    let instr = Instruction::from_bytes(0xE6, &[0x43]).unwrap();
+    assert_eq!(instr, Instruction::Out(IoPort::Immediate(0x43), true));
+    let instr = Instruction::from_bytes(0xE7, &[0x43]).unwrap();
+    assert_eq!(instr, Instruction::Out(IoPort::Immediate(0x43), false));
+
+    let instr = Instruction::from_bytes(0xEC, &[]).unwrap();
+    assert_eq!(instr, Instruction::In(IoPort::DX, true));
+    let instr = Instruction::from_bytes(0xED, &[]).unwrap();
+    assert_eq!(instr, Instruction::In(IoPort::DX, false));
+
+    let instr = Instruction::from_bytes(0xEE, &[]).unwrap();
+    assert_eq!(instr, Instruction::Out(IoPort::DX, true));
+    let instr = Instruction::from_bytes(0xEF, &[]).unwrap();
+    assert_eq!(instr, Instruction::Out(IoPort::DX, false));
+}
+
+#[test]
+fn test_lea_from_bytes() {
+    // LEA SI, [BP + DI + 0x10]
+    let instr = Instruction::from_bytes(0x8D, &[0b01110011, 0x10]).unwrap();
+    assert_eq!(instr, Instruction::Lea(Register::SI, Operand::Memory(MemAddress {
+        base: Some(Register::BP),
+        index: Some(Register::DI),
+        displacement: 0x10,
+        displacement_size: 1,
+        segment: None,
+    })));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // LEA AX, BX
+    assert_eq!(Instruction::from_bytes(0x8D, &[0b11000011]), Err(DecodeError::InvalidOperand));
+}
+
+#[test]
+fn test_lds_les_from_bytes() {
+    // LDS BX, [0x1234]
+    let instr = Instruction::from_bytes(0xC5, &[0b00011110, 0x34, 0x12]).unwrap();
+    assert_eq!(instr, Instruction::Lds(Register::BX, Operand::Memory(MemAddress {
+        base: None,
+        index: None,
+        displacement: 0x1234,
+        displacement_size: 2,
+        segment: None,
+    })));
+    assert_eq!(instr.get_instr_size(), 4);
+
+    // LES DI, [SI]
+    let instr = Instruction::from_bytes(0xC4, &[0b00111100]).unwrap();
+    assert_eq!(instr, Instruction::Les(Register::DI, Operand::Memory(MemAddress {
+        base: None,
+        index: Some(Register::SI),
+        displacement: 0,
+        displacement_size: 0,
+        segment: None,
+    })));
+    assert_eq!(instr.get_instr_size(), 2);
+
+    // ES: LDS SI, [BX]
+    let instr = Instruction::from_bytes(0x26, &[0xC5, 0b00110111]).unwrap();
+    assert_eq!(instr, Instruction::Lds(Register::SI, Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0,
+        displacement_size: 0,
+        segment: Some(Register::ES),
+    })));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    // LES AX, CX
+    assert_eq!(Instruction::from_bytes(0xC4, &[0b11000001]), Err(DecodeError::InvalidOperand));
+}
+
+#[test]
+fn test_xchg_from_bytes() {
+    // XCHG CL, DL
+    let instr = Instruction::from_bytes(0x86, &[0b11010001]).unwrap();
+    assert_eq!(instr, Instruction::Xchg(Operand::Register(Register::DL), Operand::Register(Register::CL), true));
+    assert_eq!(instr.get_instr_size(), 2);
+
+    // XCHG [BX + 0x0102], AX
+    let instr = Instruction::from_bytes(0x87, &[0b10000111, 0x02, 0x01]).unwrap();
+    assert_eq!(instr, Instruction::Xchg(Operand::Register(Register::AX), Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0x0102,
+        displacement_size: 2,
+        segment: None,
+    }), false));
+    assert_eq!(instr.get_instr_size(), 4);
+
+    let instr = Instruction::from_bytes(0x91, &[]).unwrap();
+    assert_eq!(instr, Instruction::XchgAcc(Register::CX));
+    let instr = Instruction::from_bytes(0x97, &[]).unwrap();
+    assert_eq!(instr, Instruction::XchgAcc(Register::DI));
+
+    // 90 stays NOP
+    assert_eq!(Instruction::from_bytes(0x90, &[]).unwrap(), Instruction::Noop);
+}
+
+#[test]
+fn test_data_conversion_instructions_from_bytes() {
+    assert_eq!(Instruction::from_bytes(0xD7, &[]).unwrap(), Instruction::Xlat(None));
+    assert_eq!(Instruction::from_bytes(0x2E, &[0xD7]).unwrap(), Instruction::Xlat(Some(Register::CS)));
+    assert_eq!(Instruction::from_bytes(0x98, &[]).unwrap(), Instruction::Cbw);
+    assert_eq!(Instruction::from_bytes(0x99, &[]).unwrap(), Instruction::Cwd);
+    assert_eq!(Instruction::from_bytes(0x9E, &[]).unwrap(), Instruction::Sahf);
+    assert_eq!(Instruction::from_bytes(0x9F, &[]).unwrap(), Instruction::Lahf);
+}
+
+#[test]
+fn test_flag_instructions_from_bytes() {
+    assert_eq!(Instruction::from_bytes(0xF5, &[]).unwrap(), Instruction::Cmc);
+    assert_eq!(Instruction::from_bytes(0xF8, &[]).unwrap(), Instruction::Clc);
+    assert_eq!(Instruction::from_bytes(0xF9, &[]).unwrap(), Instruction::Stc);
+    assert_eq!(Instruction::from_bytes(0xFA, &[]).unwrap(), Instruction::Cli);
+    assert_eq!(Instruction::from_bytes(0xFB, &[]).unwrap(), Instruction::Sti);
+    assert_eq!(Instruction::from_bytes(0xFC, &[]).unwrap(), Instruction::Cld);
+    assert_eq!(Instruction::from_bytes(0xFD, &[]).unwrap(), Instruction::Std);
+    assert_eq!(Instruction::from_bytes(0x9C, &[]).unwrap(), Instruction::Pushf);
+    assert_eq!(Instruction::from_bytes(0x9D, &[]).unwrap(), Instruction::Popf);
+}
+
+#[test]
+fn test_mov_rm_imm_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0xC6, &[0b11000001, 0xAA]).unwrap();
+    assert_eq!(instr, Instruction::Mov(Operand::Register(Register::CL), Operand::Immediate8(0xAA)));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    let instr = Instruction::from_bytes(0xC7, &[0b11000001, 0xBB, 0xAA]).unwrap();
+    assert_eq!(instr, Instruction::Mov(Operand::Register(Register::CX), Operand::Immediate16(0xAABB)));
+    assert_eq!(instr.get_instr_size(), 4);
+
+    let instr = Instruction::from_bytes(0xC6, &[0b01000111, 0x10, 0xAA]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Mov(Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0x10,
+            displacement_size: 1,
+            segment: None,
+        }), Operand::Immediate8(0xAA))
+    );
+    assert_eq!(instr.get_instr_size(), 4);
+
+    let instr = Instruction::from_bytes(0xC7, &[0b00000110, 0x34, 0x12, 0xBB, 0xAA]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Mov(Operand::Memory(MemAddress {
+            base: None,
+            index: None,
+            displacement: 0x1234,
+            displacement_size: 2,
+            segment: None,
+        }), Operand::Immediate16(0xAABB))
+    );
+    assert_eq!(instr.get_instr_size(), 6);
+
+    let result = Instruction::from_bytes(0xC7, &[0b00000110, 0x34, 0x12, 0xBB]);
+    assert_eq!(result, Err(DecodeError::TruncatedInstruction));
+}
+
+#[test]
+fn test_inc_dec_group_instruction_from_bytes() {
+    let instr = Instruction::from_bytes(0xFE, &[0b11000100]).unwrap();
+    assert_eq!(instr, Instruction::Inc8(Operand::Register(Register::AH)));
+
+    let instr = Instruction::from_bytes(0xFE, &[0b00001111]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Dec8(Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }))
+    );
+
+    let instr = Instruction::from_bytes(0xFF, &[0b11000000]).unwrap();
+    assert_eq!(instr, Instruction::Inc16(Operand::Register(Register::AX)));
+    assert_eq!(instr.get_instr_size(), 2);
+
+    let instr = Instruction::from_bytes(0xFF, &[0b01001110, 0x02]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Dec16(Operand::Memory(MemAddress {
+            base: Some(Register::BP),
+            index: None,
+            displacement: 0x02,
+            displacement_size: 1,
+            segment: None,
+        }))
+    );
+
+    for reg_bits in 2..=7 {
+        let result = Instruction::from_bytes(0xFE, &[0b11000000 | reg_bits << 3]);
+        assert_eq!(result, Err(DecodeError::InvalidOpcode(0xFE)));
+    }
+}
+
+#[test]
+fn test_indirect_call_jmp_push_instruction_from_bytes() {
+    let table_entry = Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0x10,
+        displacement_size: 1,
+        segment: None,
+    });
+
+    let instr = Instruction::from_bytes(0xFF, &[0b11010011]).unwrap();
+    assert_eq!(instr, Instruction::CallNearIndirect(Operand::Register(Register::BX)));
+
+    let instr = Instruction::from_bytes(0xFF, &[0b01010111, 0x10]).unwrap();
+    assert_eq!(instr, Instruction::CallNearIndirect(table_entry));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    let instr = Instruction::from_bytes(0xFF, &[0b01011111, 0x10]).unwrap();
+    assert_eq!(instr, Instruction::CallFarIndirect(table_entry));
+
+    let instr = Instruction::from_bytes(0xFF, &[0b11100011]).unwrap();
+    assert_eq!(instr, Instruction::JmpNearIndirect(Operand::Register(Register::BX)));
+
+    let instr = Instruction::from_bytes(0xFF, &[0b01100111, 0x10]).unwrap();
+    assert_eq!(instr, Instruction::JmpNearIndirect(table_entry));
+
+    let instr = Instruction::from_bytes(0xFF, &[0b01101111, 0x10]).unwrap();
+    assert_eq!(instr, Instruction::JmpFarIndirect(table_entry));
+
+    let instr = Instruction::from_bytes(0xFF, &[0b01110111, 0x10]).unwrap();
+    assert_eq!(instr, Instruction::PushRm(table_entry));
+
+    let instr = Instruction::from_bytes(0xFF, &[0b11110000]).unwrap();
+    assert_eq!(instr, Instruction::PushRm(Operand::Register(Register::AX)));
+
+    // 111 only aliases PUSH on the 8086
+    let instr = Instruction::decode(0xFF, &[0b11111000], CpuModel::I8086).unwrap();
+    assert_eq!(instr, Instruction::PushRm(Operand::Register(Register::AX)));
+    let result = Instruction::from_bytes(0xFF, &[0b11111000]);
+    assert_eq!(result, Err(DecodeError::InvalidOpcode(0xFF)));
+
+    // far pointers cannot be held in a register
+    let result = Instruction::from_bytes(0xFF, &[0b11011011]);
+    assert_eq!(result, Err(DecodeError::InvalidOperand));
+    let result = Instruction::from_bytes(0xFF, &[0b11101011]);
+    assert_eq!(result, Err(DecodeError::InvalidOperand));
+}
+
+#[test]
+fn test_indirect_jmp_with_segment_override_from_bytes() {
+    let instr = Instruction::from_bytes(0x2E, &[0xFF, 0b00100111]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::JmpNearIndirect(Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0,
+            displacement_size: 0,
+            segment: Some(Register::CS),
+        }))
+    );
+    assert_eq!(instr.get_instr_size(), 3);
+
+    let instr = Instruction::from_bytes(0x2E, &[0xFF, 0b11100011]).unwrap();
+    assert_eq!(instr, Instruction::IgnoredPrefixes(1, Box::new(Instruction::JmpNearIndirect(Operand::Register(Register::BX)))));
+    assert_eq!(instr.get_instr_size(), 3);
+}
+
+#[test]
+fn test_bcd_adjust_instructions_from_bytes() {
+    assert_eq!(Instruction::from_bytes(0x27, &[]).unwrap(), Instruction::Daa);
+    assert_eq!(Instruction::from_bytes(0x2F, &[]).unwrap(), Instruction::Das);
+    assert_eq!(Instruction::from_bytes(0x37, &[]).unwrap(), Instruction::Aaa);
+    assert_eq!(Instruction::from_bytes(0x3F, &[]).unwrap(), Instruction::Aas);
+    assert_eq!(Instruction::from_bytes(0xD4, &[0x0A]).unwrap(), Instruction::Aam(0x0A));
+    assert_eq!(Instruction::from_bytes(0xD5, &[0x10]).unwrap(), Instruction::Aad(0x10));
+
+    assert_eq!(Instruction::from_bytes(0xD4, &[]), Err(DecodeError::TruncatedInstruction));
+}
+
+#[test]
+fn test_opcode_from_byte_for_cpu_model() {
+    assert_eq!(Opcode::from_byte(0x60, CpuModel::I80186), Ok(Opcode::PUSHA));
+    assert_eq!(Opcode::from_byte(0xC8, CpuModel::I80186), Ok(Opcode::ENTER));
+    assert_eq!(Opcode::from_byte(0x63, CpuModel::I80186), Err(DecodeError::InvalidOpcode(0x63)));
+
+    // 80186 opcodes alias older instructions on the 8086
+    assert_eq!(Opcode::from_byte(0x60, CpuModel::I8086), Ok(Opcode::JO));
+    assert_eq!(Opcode::from_byte(0x63, CpuModel::I8086), Ok(Opcode::JNC));
+    assert_eq!(Opcode::from_byte(0x6F, CpuModel::I8086), Ok(Opcode::JG));
+    assert_eq!(Opcode::from_byte(0xC0, CpuModel::I8086), Ok(Opcode::RET_IMM));
+    assert_eq!(Opcode::from_byte(0xC1, CpuModel::I8086), Ok(Opcode::RET));
+    assert_eq!(Opcode::from_byte(0xC8, CpuModel::I8086), Ok(Opcode::RETF_IMM));
+    assert_eq!(Opcode::from_byte(0xC9, CpuModel::I8086), Ok(Opcode::RETF));
+    assert_eq!(Opcode::from_byte(0x90, CpuModel::I8086), Ok(Opcode::NOOP));
+    assert_eq!(Opcode::from_byte(0x0F, CpuModel::I8086), Err(DecodeError::InvalidOpcode(0x0F)));
+
+    // the operand size prefix and the two byte escape need the 80386
+    assert_eq!(Opcode::from_byte(0x66, CpuModel::I80186), Err(DecodeError::InvalidOpcode(0x66)));
+    assert_eq!(Opcode::from_byte(0x0F, CpuModel::I80186), Err(DecodeError::InvalidOpcode(0x0F)));
+    assert_eq!(Opcode::from_byte(0x66, CpuModel::I80386), Ok(Opcode::OPERAND_SIZE));
+    assert_eq!(Opcode::from_byte(0x0F, CpuModel::I80386), Ok(Opcode::TWO_BYTE));
+    assert_eq!(Opcode::from_byte(0x60, CpuModel::I80386), Ok(Opcode::PUSHA));
+}
+
+#[test]
+fn test_80186_instructions_from_bytes() {
+    assert_eq!(Instruction::from_bytes(0x6A, &[0xFE]).unwrap(), Instruction::PushImm8(0xFE));
+    assert_eq!(Instruction::from_bytes(0x68, &[0x34, 0x12]).unwrap(), Instruction::PushImm16(0x1234));
+    assert_eq!(Instruction::from_bytes(0x60, &[]).unwrap(), Instruction::Pusha);
+    assert_eq!(Instruction::from_bytes(0x61, &[]).unwrap(), Instruction::Popa);
+    assert_eq!(Instruction::from_bytes(0xC8, &[0x10, 0x00, 0x02]).unwrap(), Instruction::Enter(0x0010, 2));
+    assert_eq!(Instruction::from_bytes(0xC9, &[]).unwrap(), Instruction::Leave);
+    assert_eq!(Instruction::from_bytes(0x6C, &[]).unwrap(), Instruction::Ins(None, None, true));
+    assert_eq!(Instruction::from_bytes(0x6F, &[]).unwrap(), Instruction::Outs(None, None, false));
+    assert_eq!(Instruction::from_bytes(0xF3, &[0x6D]).unwrap(), Instruction::Ins(Some(RepPrefix::Rep), None, false));
+    assert_eq!(Instruction::from_bytes(0x26, &[0x6E]).unwrap(), Instruction::Outs(None, Some(Register::ES), true));
+
+    let instr = Instruction::from_bytes(0x6B, &[0b11000011, 0xFE]).unwrap();
+    assert_eq!(instr, Instruction::ImulImm(Register::AX, Operand::Register(Register::BX), Operand::Immediate8(0xFE)));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    let instr = Instruction::from_bytes(0x69, &[0b01001111, 0x04, 0x34, 0x12]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::ImulImm(Register::CX, Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0x04,
+            displacement_size: 1,
+            segment: None,
+        }), Operand::Immediate16(0x1234))
+    );
+    assert_eq!(instr.get_instr_size(), 5);
+
+    let instr = Instruction::from_bytes(0x62, &[0b00010111]).unwrap();
+    assert_eq!(
+        instr,
+        Instruction::Bound(Register::DX, Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }))
+    );
+    assert_eq!(instr.get_instr_size(), 2);
+
+    let result = Instruction::from_bytes(0x62, &[0b11010111]);
+    assert_eq!(result, Err(DecodeError::InvalidOperand));
+}
+
+#[test]
+fn test_80186_instructions_decode_as_aliases_on_8086() {
+    assert_eq!(Instruction::decode(0x6A, &[0xFE], CpuModel::I8086).unwrap(), Instruction::Jp(-2));
+    assert_eq!(Instruction::decode(0x60, &[0x02], CpuModel::I8086).unwrap(), Instruction::Jo(2));
+    assert_eq!(Instruction::decode(0xC0, &[0x04, 0x00], CpuModel::I8086).unwrap(), Instruction::RetImm(4));
+    assert_eq!(Instruction::decode(0xC9, &[], CpuModel::I8086).unwrap(), Instruction::RetFar);
+
+    // prefixed instructions are decoded for the same model
+    assert_eq!(Instruction::decode(0xF3, &[0x6C], CpuModel::I8086), Err(DecodeError::TruncatedInstruction));
+    assert_eq!(
+        Instruction::decode(0xF3, &[0x6C], CpuModel::I80186).unwrap(),
+        Instruction::Ins(Some(RepPrefix::Rep), None, true)
+    );
+}
+
+#[test]
+fn test_operand_size_prefix_from_bytes() {
+    let decode = |bytes: &[u8]| Instruction::decode(bytes[0], &bytes[1..], CpuModel::I80386);
+
+    let instr = decode(&[0x66, 0xB8, 0x78, 0x56, 0x34, 0x12]).unwrap();
+    assert_eq!(instr, Instruction::MovImm32(Register::EAX, 0x12345678));
+    assert_eq!(instr.get_instr_size(), 6);
+
+    let instr = decode(&[0x66, 0x89, 0b11011001]).unwrap();
+    assert_eq!(instr, Instruction::Mov32(Operand::Register(Register::ECX), Operand::Register(Register::EBX)));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    let instr = decode(&[0x66, 0x8B, 0b01000111, 0x02]).unwrap();
+    assert_eq!(instr, Instruction::Mov32(Operand::Register(Register::EAX), Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0x02,
+            displacement_size: 1,
+            segment: None,
+        })));
+    assert_eq!(instr.get_instr_size(), 4);
+
+    let instr = decode(&[0x66, 0xC7, 0b00000111, 0x78, 0x56, 0x34, 0x12]).unwrap();
+    assert_eq!(instr, Instruction::Mov32(Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }), Operand::Immediate32(0x12345678)));
+    assert_eq!(instr.get_instr_size(), 7);
+
+    let instr = decode(&[0x66, 0x01, 0b11000011]).unwrap();
+    assert_eq!(instr, Instruction::Add32(Operand::Register(Register::EBX), Operand::Register(Register::EAX)));
+
+    let instr = decode(&[0x66, 0x05, 0x78, 0x56, 0x34, 0x12]).unwrap();
+    assert_eq!(instr, Instruction::AddAcc32(0x12345678));
+    assert_eq!(instr.get_instr_size(), 6);
+
+    let instr = decode(&[0x66, 0x81, 0b11101001, 0x00, 0x00, 0x01, 0x00]).unwrap();
+    assert_eq!(instr, Instruction::Sub32(Operand::Register(Register::ECX), Operand::Immediate32(0x00010000)));
+    assert_eq!(instr.get_instr_size(), 7);
+
+    let instr = decode(&[0x66, 0x83, 0b11111010, 0xFF]).unwrap();
+    assert_eq!(instr, Instruction::Cmp32(Operand::Register(Register::EDX), Operand::Immediate8(0xFF)));
+    assert_eq!(instr.get_instr_size(), 4);
+
+    let instr = decode(&[0x66, 0xF7, 0b11000000, 0x00, 0x00, 0x00, 0x80]).unwrap();
+    assert_eq!(instr, Instruction::Test32(Operand::Register(Register::EAX), Operand::Immediate32(0x80000000)));
+
+    assert_eq!(decode(&[0x66, 0x21, 0b11000011]).unwrap(), Instruction::And32(Operand::Register(Register::EBX), Operand::Register(Register::EAX)));
+    assert_eq!(decode(&[0x66, 0x09, 0b11000011]).unwrap(), Instruction::Or32(Operand::Register(Register::EBX), Operand::Register(Register::EAX)));
+    assert_eq!(decode(&[0x66, 0x31, 0b11000011]).unwrap(), Instruction::Xor32(Operand::Register(Register::EBX), Operand::Register(Register::EAX)));
+    assert_eq!(decode(&[0x66, 0x11, 0b11000011]).unwrap(), Instruction::Adc32(Operand::Register(Register::EBX), Operand::Register(Register::EAX)));
+    assert_eq!(decode(&[0x66, 0x19, 0b11000011]).unwrap(), Instruction::Sbb32(Operand::Register(Register::EBX), Operand::Register(Register::EAX)));
+    assert_eq!(decode(&[0x66, 0x3D, 0x01, 0x00, 0x00, 0x00]).unwrap(), Instruction::CmpAcc32(1));
+}
+
+#[test]
+fn test_operand_size_prefix_unary_from_bytes() {
+    let decode = |bytes: &[u8]| Instruction::decode(bytes[0], &bytes[1..], CpuModel::I80386);
+
+    let instr = decode(&[0x66, 0x40]).unwrap();
+    assert_eq!(instr, Instruction::Inc(Register::EAX));
+    assert_eq!(instr.get_instr_size(), 2);
+
+    let instr = decode(&[0x66, 0x4B]).unwrap();
+    assert_eq!(instr, Instruction::Dec(Register::EBX));
+    assert_eq!(instr.get_instr_size(), 2);
+
+    let instr = decode(&[0x66, 0xFF, 0b01000111, 0x02]).unwrap();
+    assert_eq!(instr, Instruction::Inc32(Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0x02,
+            displacement_size: 1,
+            segment: None,
+        })));
+    assert_eq!(instr.get_instr_size(), 4);
+
+    assert_eq!(decode(&[0x66, 0xFF, 0b11001001]).unwrap(), Instruction::Dec32(Operand::Register(Register::ECX)));
+    assert_eq!(decode(&[0x66, 0xF7, 0b11010010]).unwrap(), Instruction::Not32(Operand::Register(Register::EDX)));
+    let instr = decode(&[0x66, 0xF7, 0b11011110]).unwrap();
+    assert_eq!(instr, Instruction::Neg32(Operand::Register(Register::ESI)));
+    assert_eq!(instr.get_instr_size(), 3);
+}
+
+#[test]
+fn test_operand_size_prefix_mov_acc_mem_from_bytes() {
+    let decode = |bytes: &[u8]| Instruction::decode(bytes[0], &bytes[1..], CpuModel::I80386);
+
+    let instr = decode(&[0x66, 0xA1, 0x00, 0x01]).unwrap();
+    assert_eq!(instr, Instruction::MovAccMem(MovMemOperand::Register(Register::EAX), MovMemOperand::MemoryPtr(0x0100)));
+    assert_eq!(instr.get_instr_size(), 4);
+
+    let instr = decode(&[0x66, 0xA3, 0x00, 0x01]).unwrap();
+    assert_eq!(instr, Instruction::MovAccMem(MovMemOperand::MemoryPtr(0x0100), MovMemOperand::Register(Register::EAX)));
+    assert_eq!(instr.get_instr_size(), 4);
+
+    let instr = decode(&[0x26, 0x66, 0xA1, 0x00, 0x01]).unwrap();
+    assert_eq!(instr, Instruction::MovAccMem(
+        MovMemOperand::Register(Register::EAX),
+        MovMemOperand::SegmentedMemoryPtr(Register::ES, 0x0100),
+    ));
+    assert_eq!(instr.get_instr_size(), 5);
+    assert_eq!(decode(&[0x66, 0x26, 0xA1, 0x00, 0x01]).unwrap(), instr);
+}
+
+#[test]
+fn test_operand_size_prefix_mul_div_from_bytes() {
+    let decode = |bytes: &[u8]| Instruction::decode(bytes[0], &bytes[1..], CpuModel::I80386);
+
+    let instr = decode(&[0x66, 0xF7, 0b11100011]).unwrap();
+    assert_eq!(instr, Instruction::Mul32(Operand::Register(Register::EBX)));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    assert_eq!(decode(&[0x66, 0xF7, 0b11101011]).unwrap(), Instruction::Imul32(Operand::Register(Register::EBX)));
+    assert_eq!(decode(&[0x66, 0xF7, 0b11110011]).unwrap(), Instruction::Div32(Operand::Register(Register::EBX)));
+    assert_eq!(decode(&[0x66, 0xF7, 0b11111011]).unwrap(), Instruction::Idiv32(Operand::Register(Register::EBX)));
+
+    let instr = decode(&[0x66, 0xF7, 0b01110111, 0x02]).unwrap();
+    assert_eq!(instr, Instruction::Div32(Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0x02,
+            displacement_size: 1,
+            segment: None,
+        })));
+    assert_eq!(instr.get_instr_size(), 4);
+}
+
+#[test]
+fn test_operand_size_prefix_shift_from_bytes() {
+    let decode = |bytes: &[u8]| Instruction::decode(bytes[0], &bytes[1..], CpuModel::I80386);
+
+    let instr = decode(&[0x66, 0xD1, 0b11100000]).unwrap();
+    assert_eq!(instr, Instruction::Shl32(Operand::Register(Register::EAX), ShiftCount::One));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    let instr = decode(&[0x66, 0xD3, 0b11000011]).unwrap();
+    assert_eq!(instr, Instruction::Rol32(Operand::Register(Register::EBX), ShiftCount::CL));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    let instr = decode(&[0x66, 0xC1, 0b11101011, 0x02]).unwrap();
+    assert_eq!(instr, Instruction::Shr32(Operand::Register(Register::EBX), ShiftCount::Immediate(0x02)));
+    assert_eq!(instr.get_instr_size(), 4);
+
+    let instr = decode(&[0x66, 0xC1, 0b01111111, 0x04, 0x1F]).unwrap();
+    assert_eq!(instr, Instruction::Sar32(Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0x04,
+            displacement_size: 1,
+            segment: None,
+        }), ShiftCount::Immediate(0x1F)));
+    assert_eq!(instr.get_instr_size(), 5);
+
+    assert_eq!(decode(&[0x66, 0xD1, 0b11001000]).unwrap(), Instruction::Ror32(Operand::Register(Register::EAX), ShiftCount::One));
+    assert_eq!(decode(&[0x66, 0xD1, 0b11010000]).unwrap(), Instruction::Rcl32(Operand::Register(Register::EAX), ShiftCount::One));
+    assert_eq!(decode(&[0x66, 0xD1, 0b11011000]).unwrap(), Instruction::Rcr32(Operand::Register(Register::EAX), ShiftCount::One));
+}
+
+#[test]
+fn test_operand_size_prefix_stack_and_exchange_from_bytes() {
+    let decode = |bytes: &[u8]| Instruction::decode(bytes[0], &bytes[1..], CpuModel::I80386);
+
+    let instr = decode(&[0x66, 0x50]).unwrap();
+    assert_eq!(instr, Instruction::Push(Register::EAX));
+    assert_eq!(instr.get_instr_size(), 2);
+
+    let instr = decode(&[0x66, 0x5B]).unwrap();
+    assert_eq!(instr, Instruction::Pop(Register::EBX));
+    assert_eq!(instr.get_instr_size(), 2);
+
+    let instr = decode(&[0x66, 0x87, 0b11000011]).unwrap();
+    assert_eq!(instr, Instruction::Xchg32(Operand::Register(Register::EAX), Operand::Register(Register::EBX)));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    let instr = decode(&[0x66, 0x93]).unwrap();
+    assert_eq!(instr, Instruction::XchgAcc(Register::EBX));
+    assert_eq!(instr.get_instr_size(), 2);
+
+    let instr = decode(&[0x66, 0x98]).unwrap();
+    assert_eq!(instr, Instruction::Cwde);
+    assert_eq!(instr.get_instr_size(), 2);
+
+    let instr = decode(&[0x66, 0x99]).unwrap();
+    assert_eq!(instr, Instruction::Cdq);
+    assert_eq!(instr.get_instr_size(), 2);
+}
+
+#[test]
+fn test_operand_size_prefix_with_segment_override_from_bytes() {
+    let decode = |bytes: &[u8]| Instruction::decode(bytes[0], &bytes[1..], CpuModel::I80386);
+    let expected = Instruction::Add32(Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0,
+            displacement_size: 0,
+            segment: Some(Register::ES),
+        }), Operand::Immediate32(0x12345678));
+
+    // the prefixes can come in any order
+    let instr = decode(&[0x26, 0x66, 0x81, 0b00000111, 0x78, 0x56, 0x34, 0x12]).unwrap();
+    assert_eq!(instr, expected);
+    assert_eq!(instr.get_instr_size(), 8);
+
+    let instr = decode(&[0x66, 0x26, 0x81, 0b00000111, 0x78, 0x56, 0x34, 0x12]).unwrap();
+    assert_eq!(instr, expected);
+    assert_eq!(instr.get_instr_size(), 8);
+}
+
+#[test]
+fn test_operand_size_prefix_rejects_instructions_without_32bit_form() {
+    let decode = |bytes: &[u8]| Instruction::decode(bytes[0], &bytes[1..], CpuModel::I80386);
+
+    // 8bit operations
+    assert_eq!(decode(&[0x66, 0x00, 0b11000011]), Err(DecodeError::InvalidOperandSizePrefix));
+    assert_eq!(decode(&[0x66, 0xB0, 0x01]), Err(DecodeError::InvalidOperandSizePrefix));
+    assert_eq!(decode(&[0x66, 0xC6, 0b00000111, 0x01]), Err(DecodeError::InvalidOperandSizePrefix));
+    // segment registers
+    assert_eq!(decode(&[0x66, 0x8C, 0b11011000]), Err(DecodeError::InvalidOperandSizePrefix));
+    // instructions without operand size support
+    assert_eq!(decode(&[0x66, 0x90]), Err(DecodeError::InvalidOperandSizePrefix));
+    assert_eq!(decode(&[0x66, 0x66, 0x01, 0b11000011]), Err(DecodeError::InvalidOperandSizePrefix));
+    assert_eq!(decode(&[0x66, 0xA0, 0x00, 0x01]), Err(DecodeError::InvalidOperandSizePrefix));
+    assert_eq!(decode(&[0x66, 0xD0, 0b11100011]), Err(DecodeError::InvalidOperandSizePrefix));
+    assert_eq!(decode(&[0x66, 0x06]), Err(DecodeError::InvalidOperandSizePrefix));
+    // PUSH r/m and imm are only implemented for 16bit operands
+    assert_eq!(decode(&[0x66, 0xFF, 0b11110011]), Err(DecodeError::InvalidOperandSizePrefix));
+    assert_eq!(decode(&[0x66, 0x68, 0x00, 0x01]), Err(DecodeError::InvalidOperandSizePrefix));
+
+    assert_eq!(decode(&[0x66, 0xB8, 0x78, 0x56, 0x34]), Err(DecodeError::TruncatedInstruction));
+    assert_eq!(decode(&[0x66]), Err(DecodeError::TruncatedInstruction));
+}
+
+#[test]
+fn test_movzx_movsx_from_bytes() {
+    let decode = |bytes: &[u8]| Instruction::decode(bytes[0], &bytes[1..], CpuModel::I80386);
+
+    let instr = decode(&[0x0F, 0xB6, 0b11000011]).unwrap();
+    assert_eq!(instr, Instruction::Movzx(Register::AX, Operand::Register(Register::BL), true));
+    assert_eq!(instr.get_instr_size(), 3);
+
+    let instr = decode(&[0x66, 0x0F, 0xB7, 0b11001011]).unwrap();
+    assert_eq!(instr, Instruction::Movzx(Register::ECX, Operand::Register(Register::BX), false));
+    assert_eq!(instr.get_instr_size(), 4);
+
+    let instr = decode(&[0x66, 0x0F, 0xBE, 0b00010111]).unwrap();
+    assert_eq!(instr, Instruction::Movsx(Register::EDX, Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }), true));
+    assert_eq!(instr.get_instr_size(), 4);
+
+    let instr = decode(&[0x26, 0x0F, 0xBF, 0b01000111, 0x04]).unwrap();
+    assert_eq!(instr, Instruction::Movsx(Register::AX, Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0x04,
+            displacement_size: 1,
+            segment: Some(Register::ES),
+        }), false));
+    assert_eq!(instr.get_instr_size(), 5);
+
+    assert_eq!(decode(&[0x0F, 0x05]), Err(DecodeError::InvalidOpcode(0x0F)));
+    assert_eq!(decode(&[0x0F, 0xB6]), Err(DecodeError::TruncatedInstruction));
+}
+
+#[test]
+fn test_80386_instructions_need_80386_model() {
+    assert_eq!(Instruction::from_bytes(0x66, &[0x05, 0x01, 0x00, 0x00, 0x00]), Err(DecodeError::InvalidOpcode(0x66)));
+    assert_eq!(Instruction::from_bytes(0x0F, &[0xB6, 0b11000011]), Err(DecodeError::InvalidOpcode(0x0F)));
+    assert_eq!(
+        Instruction::decode(0x66, &[0x05, 0x01, 0x00], CpuModel::I8086).unwrap(),
+        Instruction::Jbe(0x05)
+    );
+}
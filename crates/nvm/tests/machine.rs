@@ -1,8 +1,11 @@
+use nvm::cpu::CpuModel;
+use nvm::error::{BuildError, DecodeError, MachineError};
 use nvm::instruction::Opcode;
 use nvm::modrm::MemAddress;
-use nvm::register::Register;
-use nvm::{Machine, memory};
-use nvm_test_utils::machine_test;
+use nvm::register::{Flag, Register};
+use nvm::machine::StopReason;
+use nvm::{Machine, MachineBuilder, memory};
+use nvm_test_utils::{machine_state, machine_test};
 use std::fs::File;
 use std::io::BufReader;
 use std::path::PathBuf;
@@ -13,7 +16,7 @@ mod machine_instr;
 fn test_machine_default() {
     let machine = Machine::default();
 
-    assert_eq!(machine.get_register(Register::IP), 0);
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET);
     assert!(machine.get_register(Register::SP) > 100);
 }
 
@@ -27,10 +30,11 @@ fn test_load_program_from_buffer() {
     let buffer_len = program.get_ref().metadata().unwrap().len() as usize;
 
     let mut machine = Machine::default();
-    machine.load_program(program);
+    machine.load_program(program).unwrap();
 
+    let start = Machine::DEFAULT_LOAD_OFFSET as usize;
     for x in 0..machine.memory().data.len() {
-        if x < buffer_len {
+        if (start..start + buffer_len).contains(&x) {
             assert_ne!(machine.memory().data[x], 0x00);
         } else {
             assert_eq!(machine.memory().data[x], 0x00);
@@ -38,36 +42,69 @@ fn test_load_program_from_buffer() {
     }
 }
 
+#[test]
+fn test_load_program_read_error() {
+    // a directory opens fine but cannot be read
+    let file = File::open(env!("CARGO_MANIFEST_DIR")).expect("Directory not found");
+
+    let mut machine = Machine::default();
+    assert!(matches!(machine.load_program(BufReader::new(file)), Err(BuildError::Io(_))));
+}
+
+#[test]
+fn test_load_program_bytes_at_cs_ip() {
+    let mut machine = MachineBuilder::new().load_address(0x1000, 0x0000).build().unwrap();
+    machine.load_program_bytes(&[0xF4]).unwrap();
+
+    assert_eq!(machine.memory().data[0x10000], 0xF4);
+    assert_eq!(machine.memory().data[Machine::DEFAULT_LOAD_OFFSET as usize], 0x00);
+    assert_eq!(machine.run(), StopReason::Halted);
+}
+
 #[test]
 fn test_load_byte_program() {
     let mut machine = Machine::default();
-    machine.load_program_bytes(&[Opcode::NOOP as u8, 0xB4, 0x01]);
+    machine.load_program_bytes(&[Opcode::NOOP as u8, 0xB4, 0x01]).unwrap();
 
-    assert_eq!(machine.memory().data[0], Opcode::NOOP as u8);
-    assert_eq!(machine.memory().data[1], 0xB4);
-    assert_eq!(machine.memory().data[2], 0x01);
-    for x in 3..machine.memory().data.len() {
+    let start = Machine::DEFAULT_LOAD_OFFSET as usize;
+    assert_eq!(machine.memory().data[start], Opcode::NOOP as u8);
+    assert_eq!(machine.memory().data[start + 1], 0xB4);
+    assert_eq!(machine.memory().data[start + 2], 0x01);
+    for x in (0..start).chain(start + 3..machine.memory().data.len()) {
         assert_eq!(machine.memory().data[x], 0x00);
     }
 }
 
 #[test]
-#[should_panic]
 fn test_load_large_program_bytes() {
     let program = [0; memory::MEMORY_SIZE + 100];
 
     let mut machine = Machine::default();
-    machine.load_program_bytes(&program);
+    assert_eq!(machine.load_program_bytes(&program), Err(BuildError::ProgramTooLarge {
+        address: Machine::DEFAULT_LOAD_OFFSET as usize,
+        size: memory::MEMORY_SIZE + 100,
+        memory_size: memory::MEMORY_SIZE,
+    }));
+}
+
+#[test]
+fn test_load_program_past_end_of_memory() {
+    // fits into memory, but not above the load offset
+    let program = vec![0x90; memory::MEMORY_SIZE - 1];
+
+    let mut machine = Machine::default();
+    assert!(machine.load_program_bytes(&program).is_err());
+    assert_eq!(machine.memory().data[Machine::DEFAULT_LOAD_OFFSET as usize], 0x00);
 }
 
 #[test]
 fn test_step() {
     let mut machine = Machine::default();
-    machine.load_program_bytes(&[Opcode::NOOP as u8]);
+    machine.load_program_bytes(&[Opcode::NOOP as u8]).unwrap();
 
-    machine.step();
+    machine.step().unwrap();
 
-    assert_eq!(machine.get_register(Register::IP), 1);
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 1);
 }
 
 #[machine_test]
@@ -77,16 +114,47 @@ fn test_step_with_dynamic_instruction_size(mut machine: Machine) {
     // MOV CX, [BX + SI + 0xD0C]
     machine.load_program_bytes(&[
         0x8B, 0b00001000, 0x8B, 0b01001000, 0x0C, 0x8B, 0b10001000, 0x0C, 0xD,
-    ]);
+    ]).unwrap();
 
-    machine.step();
-    assert_eq!(machine.get_register(Register::IP), 2);
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 2);
 
-    machine.step();
-    assert_eq!(machine.get_register(Register::IP), 5);
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 5);
 
-    machine.step();
-    assert_eq!(machine.get_register(Register::IP), 9);
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 9);
+}
+
+#[machine_test]
+#[machine_state(Flag::ZERO = true)]
+#[machine_state(Register::AX = 0x1234)]
+fn test_step_ignores_segment_override_without_memory_operand(mut machine: Machine) {
+    // CS: JZ +2
+    // NOP
+    // NOP
+    // DS: PUSH AX
+    machine.load_program_bytes(&[0x2E, 0x74, 0x02, 0x90, 0x90, 0x3E, 0x50]).unwrap();
+
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 5);
+
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 7);
+    assert_eq!(machine.read_word_at(Register::SS, machine.get_register(Register::SP)).unwrap(), 0x1234);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x0200)]
+#[machine_state(0x0200 = 0x34)]
+#[machine_state(0x0201 = 0x12)]
+fn test_step_ignores_rep_prefix_on_non_string_instruction(mut machine: Machine) {
+    // REP RET
+    machine.load_program_bytes(&[0xF3, 0xC3]).unwrap();
+
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), 0x1234);
+    assert_eq!(machine.get_register(Register::SP), 0x0202);
 }
 
 #[test]
@@ -126,6 +194,28 @@ fn test_set_16bit_register() {
     assert_eq!(machine.get_register(Register::DI), 0xAABB);
 }
 
+#[test]
+fn test_set_32bit_register() {
+    let mut machine = Machine::default();
+
+    machine.set_register32(Register::EAX, 0x11223344);
+    assert_eq!(machine.get_register32(Register::EAX), 0x11223344);
+    assert_eq!(machine.get_register(Register::EAX), 0x3344);
+    assert_eq!(machine.get_register(Register::AX), 0x3344);
+    assert_eq!(machine.get_register(Register::AH), 0x33);
+
+    // 16bit and 8bit writes keep the upper word
+    machine.set_register(Register::AX, 0xAABB);
+    assert_eq!(machine.get_register32(Register::EAX), 0x1122AABB);
+    machine.set_register(Register::AL, 0xCC);
+    assert_eq!(machine.get_register32(Register::EAX), 0x1122AACC);
+
+    // narrower registers are zero extended and truncated
+    machine.set_register32(Register::SI, 0x11223344);
+    assert_eq!(machine.get_register32(Register::ESI), 0x3344);
+    assert_eq!(machine.get_register32(Register::AH), 0xAA);
+}
+
 #[test]
 fn test_set_8bit_lower_register() {
     let mut machine = Machine::default();
@@ -208,6 +298,7 @@ fn test_get_ptr_from_mem_address() {
         index: None,
         displacement: 0,
         displacement_size: 0,
+        segment: None,
     });
     assert_eq!(ptr, 0);
 
@@ -216,6 +307,7 @@ fn test_get_ptr_from_mem_address() {
         index: None,
         displacement: 0xCC,
         displacement_size: 0,
+        segment: None,
     });
     assert_eq!(ptr, 0xCC);
 
@@ -224,6 +316,7 @@ fn test_get_ptr_from_mem_address() {
         index: None,
         displacement: 0,
         displacement_size: 0,
+        segment: None,
     });
     assert_eq!(ptr, 0xAA);
 
@@ -232,6 +325,7 @@ fn test_get_ptr_from_mem_address() {
         index: Some(Register::SI),
         displacement: 0,
         displacement_size: 0,
+        segment: None,
     });
     assert_eq!(ptr, 0xBB);
 
@@ -240,6 +334,7 @@ fn test_get_ptr_from_mem_address() {
         index: Some(Register::SI),
         displacement: 0,
         displacement_size: 0,
+        segment: None,
     });
     assert_eq!(ptr, 0xAA + 0xBB);
 
@@ -248,6 +343,7 @@ fn test_get_ptr_from_mem_address() {
         index: Some(Register::SI),
         displacement: 0xCC,
         displacement_size: 0,
+        segment: None,
     });
     assert_eq!(ptr, 0xAA + 0xBB + 0xCC);
 
@@ -256,6 +352,7 @@ fn test_get_ptr_from_mem_address() {
         index: None,
         displacement: 0xCC,
         displacement_size: 0,
+        segment: None,
     });
     assert_eq!(ptr, 0xAA + 0xCC);
 
@@ -264,27 +361,591 @@ fn test_get_ptr_from_mem_address() {
         index: Some(Register::SI),
         displacement: 0xCC,
         displacement_size: 0,
+        segment: None,
     });
     assert_eq!(ptr, 0xBB + 0xCC);
 }
 
 #[test]
-fn test_dump_self() {
+fn test_display_registers() {
     let mut machine = Machine::default();
     machine.set_register(Register::AX, 0xAA);
     machine.set_register(Register::CX, 0xAB);
     machine.set_register(Register::DX, 0xAC);
     machine.set_register(Register::BX, 0xAD);
 
-    machine.dump_self();
+    assert_eq!(
+        machine.to_string(),
+        "AX: 170 | CX: 171 | DX: 172 | BX: 173 | SP: 1024 | BP: 0 | SI: 0 | DI: 0 | \
+        CS: 0 | DS: 0 | SS: 0 | ES: 0 | IP: 256 | FLAGS: 1111000000000010",
+    );
 }
 
 #[test]
 fn test_noop_instruction() {
     let mut machine = Machine::default();
-    machine.load_program_bytes(&[Opcode::NOOP as u8]);
-    machine.step();
+    machine.load_program_bytes(&[Opcode::NOOP as u8]).unwrap();
+    machine.step().unwrap();
 
-    assert_eq!(machine.get_register(Register::IP), 1);
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 1);
 }
 
+
+#[machine_test]
+fn test_step_with_alu_imm_instructions(mut machine: Machine) {
+    // ADD BX, 5
+    // ADD BYTE [BX + 0x10], 0x02
+    // SUB WORD [0x0200], 0x1234
+    machine.load_program_bytes(&[
+        0x83, 0b11000011, 0x05, 0x80, 0b01000111, 0x10, 0x02, 0x81, 0b00101110, 0x00, 0x02, 0x34,
+        0x12,
+    ]).unwrap();
+
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 3);
+    assert_eq!(machine.get_register(Register::BX), 5);
+
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 7);
+    assert_eq!(machine.memory().data[0x15], 0x02);
+
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 13);
+    assert_eq!(machine.memory().read_word(0x0200), 0u16.wrapping_sub(0x1234));
+}
+
+#[machine_test]
+fn test_step_call_and_ret(mut machine: Machine) {
+    // 0x00: CALL 0x0008
+    // 0x03: MOV AX, 0x1111
+    // 0x06: NOP
+    // 0x07: NOP
+    // 0x08: MOV BX, 0x2222
+    // 0x0B: RET
+    machine.load_program_bytes(&[
+        0xE8, 0x05, 0x00, 0xB8, 0x11, 0x11, 0x90, 0x90, 0xBB, 0x22, 0x22, 0xC3,
+    ]).unwrap();
+    let sp = machine.get_register(Register::SP);
+
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 0x08);
+    assert_eq!(machine.get_register(Register::SP), sp - 2);
+    assert_eq!(machine.memory().read_word(sp as usize - 2), Machine::DEFAULT_LOAD_OFFSET + 0x03);
+
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::BX), 0x2222);
+
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 0x03);
+    assert_eq!(machine.get_register(Register::SP), sp);
+
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::AX), 0x1111);
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 0x06);
+}
+
+#[machine_test]
+fn test_step_jmp_far_lands_on_target(mut machine: Machine) {
+    // JMP FAR 0x0000:0x0010
+    machine.load_program_bytes(&[0xEA, 0x10, 0x00, 0x00, 0x00]).unwrap();
+
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), 0x10);
+}
+
+#[machine_test]
+fn test_step_loop_counts_down_cx(mut machine: Machine) {
+    // MOV CX, 3
+    // loop: INC AX
+    // LOOP loop
+    // NOP
+    machine.load_program_bytes(&[0xB9, 0x03, 0x00, 0x40, 0xE2, 0xFD, 0x90]).unwrap();
+
+    for _ in 0..7 {
+        machine.step().unwrap();
+    }
+
+    assert_eq!(machine.get_register(Register::AX), 3);
+    assert_eq!(machine.get_register(Register::CX), 0);
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 6);
+}
+
+#[test]
+fn test_get_ptr_from_mem_address_with_segment() {
+    let mut machine = Machine::default();
+    machine.set_register(Register::DS, 0x1000);
+    machine.set_register(Register::SS, 0x2000);
+    machine.set_register(Register::BX, 0x10);
+    machine.set_register(Register::BP, 0x20);
+
+    let ptr = machine.get_ptr_from_mem_address(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0x01,
+        displacement_size: 1,
+        segment: None,
+    });
+    assert_eq!(ptr, 0x10011);
+
+    let ptr = machine.get_ptr_from_mem_address(MemAddress {
+        base: Some(Register::BP),
+        index: Some(Register::SI),
+        displacement: 0x01,
+        displacement_size: 1,
+        segment: None,
+    });
+    assert_eq!(ptr, 0x20021);
+}
+
+#[test]
+fn test_physical_address() {
+    let mut machine = Machine::default();
+    machine.set_register(Register::ES, 0x1234);
+
+    assert_eq!(machine.physical_address(Register::ES, 0x5678), 0x179B8);
+    assert_eq!(machine.physical_address(Register::CS, 0xFFFF), 0xFFFF);
+
+    machine.set_register(Register::ES, 0xFFFF);
+    assert_eq!(machine.physical_address(Register::ES, 0x000F), 0xFFFFF);
+    assert_eq!(machine.physical_address(Register::ES, 0x0010), 0x00000);
+}
+
+#[test]
+fn test_word_at_segment_end_wraps_to_segment_start() {
+    let mut machine = Machine::default();
+    machine.set_register(Register::DS, 0x0100);
+    machine.write_word_at(Register::DS, 0xFFFF, 0xAABB).unwrap();
+
+    assert_eq!(machine.memory().data[0x1000 + 0xFFFF], 0xBB);
+    assert_eq!(machine.memory().data[0x1000], 0xAA);
+    assert_eq!(machine.read_word_at(Register::DS, 0xFFFF).unwrap(), 0xAABB);
+}
+
+#[test]
+fn test_step_fetches_from_code_segment() {
+    let mut machine = Machine::default();
+    // MOV AX, 0x1234 at 0x0100:0x0002
+    machine.memory_mut().data[0x1002..0x1005].copy_from_slice(&[0xB8, 0x34, 0x12]);
+    machine.set_register(Register::CS, 0x0100);
+    machine.set_register(Register::IP, 0x0002);
+
+    machine.step().unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x1234);
+    assert_eq!(machine.get_register(Register::IP), 0x0005);
+    assert_eq!(machine.get_register(Register::CS), 0x0100);
+}
+
+#[test]
+fn test_step_jmp_far_continues_in_new_code_segment() {
+    let mut machine = Machine::default();
+    // JMP FAR 0x0200:0x0000
+    // MOV AX, 0x1234 at 0x0200:0x0000
+    machine.load_program_bytes(&[0xEA, 0x00, 0x00, 0x00, 0x02]).unwrap();
+    machine.memory_mut().data[0x2000..0x2003].copy_from_slice(&[0xB8, 0x34, 0x12]);
+
+    machine.step().unwrap();
+    machine.step().unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x1234);
+    assert_eq!(machine.get_register(Register::CS), 0x0200);
+    assert_eq!(machine.get_register(Register::IP), 0x0003);
+}
+
+#[test]
+fn test_step_divide_error_returns_to_next_instruction() {
+    let mut machine = Machine::default();
+    // INT 0 handler at 0x0000:0x0100
+    machine.memory_mut().data[0..4].copy_from_slice(&[0x00, 0x01, 0x00, 0x00]);
+    // 0x10: DIV CL
+    // 0x12: NOP
+    machine.memory_mut().data[0x10..0x13].copy_from_slice(&[0xF6, 0xF1, 0x90]);
+    machine.set_register(Register::IP, 0x10);
+    let sp = machine.get_register(Register::SP);
+
+    machine.step().unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x0100);
+    assert_eq!(machine.memory().read_word(sp as usize - 6), 0x12);
+}
+
+#[test]
+fn test_step_unhandled_divide_error() {
+    let mut machine = Machine::default();
+    // 0x10: DIV CL
+    machine.memory_mut().data[0x10..0x12].copy_from_slice(&[0xF6, 0xF1]);
+    machine.set_register(Register::IP, 0x10);
+
+    assert_eq!(machine.step(), Err(MachineError::UnhandledInterrupt(0)));
+}
+
+#[test]
+fn test_step_divide_by_zero_without_handler() {
+    // MOV AL, 5
+    // MOV BL, 0
+    // DIV BL
+    // HLT
+    let mut machine = MachineBuilder::new()
+        .program([0xB0, 0x05, 0xB3, 0x00, 0xF6, 0xF3, 0xF4])
+        .build()
+        .unwrap();
+
+    machine.step().unwrap();
+    machine.step().unwrap();
+
+    assert_eq!(machine.step(), Err(MachineError::UnhandledInterrupt(0)));
+}
+
+#[test]
+fn test_step_int_handler_and_iret() {
+    let mut machine = Machine::default();
+    // INT 0x21 handler at 0x0000:0x0100
+    machine.memory_mut().write_word(0x21 * 4, 0x0100);
+    machine.memory_mut().write_word(0x21 * 4 + 2, 0x0000);
+    // 0x0100: MOV AX, 0x4C00
+    // 0x0103: IRET
+    machine.memory_mut().data[0x0100..0x0104].copy_from_slice(&[0xB8, 0x00, 0x4C, 0xCF]);
+    // 0x0400: INT 0x21
+    // 0x0402: NOP
+    machine.memory_mut().data[0x0400..0x0403].copy_from_slice(&[0xCD, 0x21, 0x90]);
+    machine.set_register(Register::IP, 0x0400);
+
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), 0x0100);
+
+    machine.step().unwrap();
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::AX), 0x4C00);
+    assert_eq!(machine.get_register(Register::IP), 0x0402);
+}
+
+#[test]
+fn test_step_invalid_opcode() {
+    let mut machine = Machine::default();
+    machine.memory_mut().data[0x1234 * 16 + 0x10] = 0x0F;
+    machine.set_register(Register::CS, 0x1234);
+    machine.set_register(Register::IP, 0x10);
+
+    assert_eq!(machine.step(), Err(MachineError::InvalidInstruction {
+        segment: 0x1234,
+        offset: 0x10,
+        error: DecodeError::InvalidOpcode(0x0F),
+    }));
+    assert_eq!(machine.get_register(Register::IP), 0x10);
+}
+
+#[test]
+fn test_step_at_end_of_memory() {
+    // 0xFF: NOP
+    let mut machine = MachineBuilder::new()
+        .memory_size(0x100)
+        .program([0x90])
+        .load_address(0x0000, 0x00FF)
+        .register(Register::SP, 0x0080)
+        .build()
+        .unwrap();
+
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), 0x100);
+
+    assert_eq!(machine.step(), Err(MachineError::MemoryOutOfRange(0x100)));
+}
+
+#[test]
+fn test_step_truncated_instruction_at_end_of_memory() {
+    // 0xFE: MOV AX, imm16 missing its high byte
+    let mut machine = MachineBuilder::new()
+        .memory_size(0x100)
+        .program([0xB8, 0x01])
+        .load_address(0x0000, 0x00FE)
+        .register(Register::SP, 0x0080)
+        .build()
+        .unwrap();
+
+    assert_eq!(machine.step(), Err(MachineError::InvalidInstruction {
+        segment: 0x0000,
+        offset: 0x00FE,
+        error: DecodeError::TruncatedInstruction,
+    }));
+}
+
+#[test]
+fn test_step_memory_out_of_range() {
+    // MOV AX, [0x2000]
+    let mut machine = MachineBuilder::new()
+        .memory_size(0x1000)
+        .program([0xA1, 0x00, 0x20])
+        .build()
+        .unwrap();
+
+    assert_eq!(machine.step(), Err(MachineError::MemoryOutOfRange(0x2000)));
+}
+
+#[test]
+fn test_step_stack_fault() {
+    // PUSH AX
+    let mut machine = MachineBuilder::new()
+        .memory_size(0x1000)
+        .program([0x50])
+        .register(Register::SS, 0x0100)
+        .register(Register::SP, 0x0010)
+        .build()
+        .unwrap();
+
+    assert_eq!(machine.step(), Err(MachineError::StackFault(0x100E)));
+    assert_eq!(machine.get_register(Register::SP), 0x0010);
+}
+
+#[test]
+fn test_run_until_halt() {
+    // MOV CX, 3
+    // DEC CX
+    // JNZ -3
+    // HLT
+    let mut machine = MachineBuilder::new()
+        .program([0xB9, 0x03, 0x00, 0x49, 0x75, 0xFD, 0xF4])
+        .build()
+        .unwrap();
+
+    assert_eq!(machine.run(), StopReason::Halted);
+    assert!(machine.is_halted());
+    assert_eq!(machine.get_register(Register::CX), 0);
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 7);
+}
+
+#[test]
+fn test_step_while_halted() {
+    let mut machine = MachineBuilder::new().program([0xF4, 0x90]).build().unwrap();
+
+    machine.step().unwrap();
+    machine.step().unwrap();
+
+    assert!(machine.is_halted());
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 1);
+    assert_eq!(machine.run(), StopReason::Halted);
+}
+
+#[test]
+fn test_interrupt_wakes_halted_machine() {
+    let mut machine = MachineBuilder::new().program([0xF4]).build().unwrap();
+    machine.memory_mut().write_word(0x08 * 4, 0x0100);
+    machine.memory_mut().write_word(0x08 * 4 + 2, 0x0000);
+
+    assert_eq!(machine.run(), StopReason::Halted);
+    machine.interrupt(0x08).unwrap();
+
+    assert!(!machine.is_halted());
+    assert_eq!(machine.get_register(Register::IP), 0x0100);
+}
+
+#[test]
+fn test_run_for_budget_exhausted() {
+    // JMP -2
+    let mut machine = MachineBuilder::new().program([0xEB, 0xFE]).build().unwrap();
+
+    assert_eq!(machine.run_for(100), StopReason::BudgetExhausted);
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET);
+}
+
+#[test]
+fn test_run_for_stops_before_budget() {
+    // NOP
+    // HLT
+    let mut machine = MachineBuilder::new().program([0x90, 0xF4]).build().unwrap();
+
+    assert_eq!(machine.run_for(100), StopReason::Halted);
+}
+
+#[test]
+fn test_run_stops_on_breakpoint() {
+    // INT 3
+    // MOV AL, 1
+    // HLT
+    let mut machine = MachineBuilder::new().program([0xCC, 0xB0, 0x01, 0xF4]).build().unwrap();
+
+    assert_eq!(machine.run(), StopReason::Breakpoint);
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 1);
+
+    assert_eq!(machine.run(), StopReason::Halted);
+    assert_eq!(machine.get_register(Register::AL), 1);
+}
+
+#[test]
+fn test_run_dispatches_through_jump_table() {
+    // MOV BX, 0x0002
+    // MOV BYTE [0x0120], 0x01
+    // JMP [BX + 0x0110]
+    // HLT
+    // 0x0110: dw 0x0000, 0x0118
+    // 0x0118: INC BYTE [0x0120]
+    // HLT
+    let mut program = vec![0u8; 0x20];
+    program[..0x0D].copy_from_slice(&[
+        0xBB, 0x02, 0x00,
+        0xC6, 0x06, 0x20, 0x01, 0x01,
+        0xFF, 0xA7, 0x10, 0x01,
+        0xF4,
+    ]);
+    program[0x12..0x14].copy_from_slice(&[0x18, 0x01]);
+    program[0x18..0x1D].copy_from_slice(&[0xFE, 0x06, 0x20, 0x01, 0xF4]);
+    let mut machine = MachineBuilder::new().program(program).build().unwrap();
+
+    assert_eq!(machine.run(), StopReason::Halted);
+    assert_eq!(machine.memory().data[0x0120], 0x02);
+    assert_eq!(machine.get_register(Register::IP), 0x011D);
+}
+
+#[test]
+fn test_step_decodes_for_cpu_model() {
+    // 80186: PUSH 0x05
+    // 8086:  JP +5
+    let program = [0x6A, 0x05];
+
+    let mut machine = MachineBuilder::new()
+        .program(program)
+        .register(Register::F, 0x0004)
+        .build()
+        .unwrap();
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 2);
+    assert_eq!(machine.get_register(Register::SP), 0xFFFE);
+
+    let mut machine = MachineBuilder::new()
+        .program(program)
+        .register(Register::F, 0x0004)
+        .cpu_model(CpuModel::I8086)
+        .build()
+        .unwrap();
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 7);
+    assert_eq!(machine.get_register(Register::SP), 0x0000);
+}
+
+#[test]
+fn test_step_operand_size_prefix_on_80386() {
+    // MOV EAX, 0x00018080
+    // ADD EAX, EAX
+    // MOVZX ECX, AH
+    let program = [0x66, 0xB8, 0x80, 0x80, 0x01, 0x00, 0x66, 0x01, 0xC0, 0x66, 0x0F, 0xB6, 0xCC];
+
+    let mut machine = MachineBuilder::new()
+        .program(program)
+        .cpu_model(CpuModel::I80386)
+        .build()
+        .unwrap();
+    machine.step().unwrap();
+    machine.step().unwrap();
+    machine.step().unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0x00030100);
+    assert_eq!(machine.get_register32(Register::ECX), 0x00000001);
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 13);
+}
+
+#[test]
+fn test_step_operand_size_prefix_faults_on_80186() {
+    let mut machine = MachineBuilder::new()
+        .program([0x66, 0x01, 0xC0])
+        .load_address(0x0100, 0x0000)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        machine.step(),
+        Err(MachineError::InvalidInstruction { segment: 0x0100, offset: 0x0000, error: DecodeError::InvalidOpcode(0x66) })
+    );
+}
+
+#[test]
+fn test_step_invalid_opcode_faults_on_80186() {
+    // INT 6 handler at 0x0200:0x0010
+    let mut machine = MachineBuilder::new()
+        .program([0x90, 0x63])
+        .load_address(0x0100, 0x0000)
+        .build()
+        .unwrap();
+    machine.memory_mut().data[0x18..0x1C].copy_from_slice(&[0x10, 0x00, 0x00, 0x02]);
+
+    machine.step().unwrap();
+    machine.step().unwrap();
+
+    assert_eq!(machine.get_register(Register::CS), 0x0200);
+    assert_eq!(machine.get_register(Register::IP), 0x0010);
+    // the handler returns to the invalid opcode
+    let sp = machine.get_register(Register::SP);
+    assert_eq!(machine.read_word_at(Register::SS, sp).unwrap(), 0x0001);
+    assert_eq!(machine.read_word_at(Register::SS, sp + 2).unwrap(), 0x0100);
+}
+
+#[test]
+fn test_step_invalid_opcode_without_fault_handler() {
+    let mut machine = MachineBuilder::new()
+        .program([0x63])
+        .load_address(0x0100, 0x0000)
+        .build()
+        .unwrap();
+
+    assert_eq!(machine.step(), Err(MachineError::InvalidInstruction {
+        segment: 0x0100,
+        offset: 0,
+        error: DecodeError::InvalidOpcode(0x63),
+    }));
+}
+
+#[test]
+fn test_run_invalid_opcode_ignores_program_bytes_at_vector_6() {
+    // invalid, followed by program bytes that would form a vector 6 entry if
+    // the program was loaded at 0000:0000
+    let mut program = [0x90; 28];
+    program[0] = 0x63;
+    program[24..28].copy_from_slice(&[0x10, 0x00, 0x00, 0x02]);
+    let mut machine = MachineBuilder::new().program(program).build().unwrap();
+
+    assert_eq!(machine.run(), StopReason::InvalidOpcode {
+        segment: 0,
+        offset: Machine::DEFAULT_LOAD_OFFSET,
+        error: DecodeError::InvalidOpcode(0x63),
+    });
+    assert_eq!(machine.get_register(Register::CS), 0);
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET);
+}
+
+#[test]
+fn test_step_invalid_opcode_does_not_fault_on_8086() {
+    // vector 6 is installed but the 8086 has no invalid opcode fault
+    let mut machine = MachineBuilder::new()
+        .program([0x0F])
+        .load_address(0x0100, 0x0000)
+        .cpu_model(CpuModel::I8086)
+        .build()
+        .unwrap();
+    machine.memory_mut().data[0x18..0x1C].copy_from_slice(&[0x10, 0x00, 0x00, 0x02]);
+
+    assert_eq!(machine.step(), Err(MachineError::InvalidInstruction {
+        segment: 0x0100,
+        offset: 0,
+        error: DecodeError::InvalidOpcode(0x0F),
+    }));
+    assert_eq!(machine.get_register(Register::CS), 0x0100);
+}
+
+#[test]
+fn test_run_stops_on_invalid_opcode() {
+    // NOP
+    // invalid
+    let mut machine = MachineBuilder::new().program([0x90, 0x0F]).build().unwrap();
+
+    assert_eq!(machine.run(), StopReason::InvalidOpcode {
+        segment: 0,
+        offset: Machine::DEFAULT_LOAD_OFFSET + 1,
+        error: DecodeError::InvalidOpcode(0x0F),
+    });
+}
+
+#[test]
+fn test_run_stops_on_fault() {
+    // DIV CL
+    let mut machine = MachineBuilder::new().program([0xF6, 0xF1]).build().unwrap();
+
+    assert_eq!(machine.run(), StopReason::Fault(MachineError::UnhandledInterrupt(0)));
+}
@@ -0,0 +1,97 @@
+use nvm::Machine;
+use nvm::instruction::Instruction;
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::AL = 0x0A)]
+fn test_adc_acc_8_without_carry(mut machine: Machine) {
+    // ADC AL, 0x02
+    machine.run_instruction(Instruction::AdcAcc8(0x02)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x0A + 0x02);
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x0A)]
+#[machine_state(Flag::CARRY = true)]
+fn test_adc_acc_8_with_carry(mut machine: Machine) {
+    // ADC AL, 0x02
+    machine.run_instruction(Instruction::AdcAcc8(0x02)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x0A + 0x02 + 1);
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0xFFFF)]
+#[machine_state(Flag::CARRY = true)]
+fn test_adc_acc_16_with_carry_wrapping(mut machine: Machine) {
+    // ADC AX, 0x0000
+    machine.run_instruction(Instruction::AdcAcc16(0x0000)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0000);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x11)]
+#[machine_state(Register::AL = 0x22)]
+#[machine_state(0x11 = 0x11)]
+#[machine_state(Flag::CARRY = true)]
+fn test_adc_8bit_reg_to_mem(mut machine: Machine) {
+    // ADC [BX], AL
+    machine.run_instruction(Instruction::Adc(
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }),
+        Operand::Register(Register::AL),
+        true,
+    )).unwrap();
+
+    assert_eq!(machine.memory().data[0x11], 0x22 + 0x11 + 1);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0xFFFF)]
+#[machine_state(Register::DX = 0x0001)]
+#[machine_state(Register::CX = 0x0001)]
+#[machine_state(Register::BX = 0x0000)]
+fn test_adc_32bit_addition_with_add(mut machine: Machine) {
+    // DX:AX += BX:CX
+    // ADD AX, CX
+    // ADC DX, BX
+    machine.run_instruction(Instruction::Add(
+        Operand::Register(Register::AX),
+        Operand::Register(Register::CX),
+        false,
+    )).unwrap();
+    machine.run_instruction(Instruction::Adc(
+        Operand::Register(Register::DX),
+        Operand::Register(Register::BX),
+        false,
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0000);
+    assert_eq!(machine.get_register(Register::DX), 0x0002);
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x7F)]
+#[machine_state(Flag::CARRY = true)]
+fn test_adc_acc_8_overflow_from_carry(mut machine: Machine) {
+    // ADC AL, 0x00
+    machine.run_instruction(Instruction::AdcAcc8(0x00)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x80);
+    assert!(machine.get_flag(Flag::OVERFLOW));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+}
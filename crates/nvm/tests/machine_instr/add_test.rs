@@ -8,7 +8,7 @@ use nvm_test_utils::{machine_state, machine_test};
 #[machine_state(Register::AL = 0x0A)]
 fn test_add_acc_8(mut machine: Machine) {
     // ADD AL, 0x02
-    machine.run_instruction(Instruction::AddAcc8(0x02));
+    machine.run_instruction(Instruction::AddAcc8(0x02)).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x0A + 0x02);
 }
@@ -17,7 +17,7 @@ fn test_add_acc_8(mut machine: Machine) {
 #[machine_state(Register::AX = 0x1122)]
 fn test_add_acc_16(mut machine: Machine) {
     // ADD AX, 0x2211
-    machine.run_instruction(Instruction::AddAcc16(0x2211));
+    machine.run_instruction(Instruction::AddAcc16(0x2211)).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x1122 + 0x2211);
 }
@@ -26,7 +26,7 @@ fn test_add_acc_16(mut machine: Machine) {
 #[machine_state(Register::AL = 0xFF)]
 fn test_add_acc_8_wrapping(mut machine: Machine) {
     // ADD AL, 0x02
-    machine.run_instruction(Instruction::AddAcc8(0x02));
+    machine.run_instruction(Instruction::AddAcc8(0x02)).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x01);
 }
@@ -35,7 +35,7 @@ fn test_add_acc_8_wrapping(mut machine: Machine) {
 #[machine_state(Register::AX = 0xFFFF)]
 fn test_add_acc_16_wrapping(mut machine: Machine) {
     // ADD AX, 0x2211
-    machine.run_instruction(Instruction::AddAcc16(0x2));
+    machine.run_instruction(Instruction::AddAcc16(0x2)).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x01);
 }
@@ -53,10 +53,11 @@ fn test_add_8bit_reg_to_mem(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         Operand::Register(Register::AL),
         true,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.memory().data[0x11 + 0x22], 0x22 + 0x11);
 }
@@ -74,10 +75,11 @@ fn test_add_8bit_reg_to_mem_wrapping(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         Operand::Register(Register::AL),
         true,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.memory().data[0x11 + 0x22], 0x01);
 }
@@ -95,10 +97,11 @@ fn test_add_16bit_reg_to_mem(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         Operand::Register(Register::AX),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.memory().read_word(0x11 + 0x22), 0x2233 + 0xFF);
 }
@@ -116,10 +119,11 @@ fn test_add_16bit_reg_to_mem_wrapping(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         Operand::Register(Register::AX),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.memory().read_word(0x11 + 0x22), 0x01);
 }
@@ -138,9 +142,10 @@ fn test_add_mem_to_8bit_reg(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         true,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x22 + 0x11);
 }
@@ -159,9 +164,10 @@ fn test_add_mem_to_8bit_reg_wrapping(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         true,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x01);
 }
@@ -180,9 +186,10 @@ fn test_add_mem_to_16bit_reg(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x2233 + 0x11);
 }
@@ -201,9 +208,10 @@ fn test_add_mem_to_16bit_reg_wrapping(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x01);
 }
@@ -222,9 +230,10 @@ fn test_add_mem_to_16bit_reg_1byte_displacement(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0x33,
             displacement_size: 1,
+            segment: None,
         }),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x2233 + 0x11);
 }
@@ -243,9 +252,10 @@ fn test_add_mem_to_16bit_reg_2byte_displacement(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0x3333,
             displacement_size: 2,
+            segment: None,
         }),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x2233 + 0x11);
 }
@@ -259,7 +269,7 @@ fn test_add_8bit_reg_to_8bit_reg(mut machine: Machine) {
         Operand::Register(Register::AL),
         Operand::Register(Register::CL),
         true,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x22 + 0x11);
 }
@@ -273,7 +283,7 @@ fn test_add_8bit_reg_to_8bit_reg_wrapping(mut machine: Machine) {
         Operand::Register(Register::AL),
         Operand::Register(Register::CL),
         true,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x01);
 }
@@ -287,7 +297,7 @@ fn test_add_16bit_reg_to_16bit_reg(mut machine: Machine) {
         Operand::Register(Register::AX),
         Operand::Register(Register::CX),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x2233 + 0x11);
 }
@@ -301,7 +311,7 @@ fn test_add_16bit_reg_to_16bit_reg_wrapping(mut machine: Machine) {
         Operand::Register(Register::AX),
         Operand::Register(Register::CX),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x01);
 }
@@ -311,8 +321,8 @@ fn test_add_16bit_reg_to_16bit_reg_wrapping(mut machine: Machine) {
 fn test_inc_reg(mut machine: Machine) {
     // INC AX
     // INC CX
-    machine.run_instruction(Instruction::Inc(Register::AX));
-    machine.run_instruction(Instruction::Inc(Register::CX));
+    machine.run_instruction(Instruction::Inc(Register::AX)).unwrap();
+    machine.run_instruction(Instruction::Inc(Register::CX)).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x0100);
     assert_eq!(machine.get_register(Register::CX), 0x01);
@@ -322,7 +332,7 @@ fn test_inc_reg(mut machine: Machine) {
 #[machine_state(Register::AX = 0xFFFF)]
 fn test_inc_reg_wrapping(mut machine: Machine) {
     // INC AX
-    machine.run_instruction(Instruction::Inc(Register::AX));
+    machine.run_instruction(Instruction::Inc(Register::AX)).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x0000);
 }
@@ -334,7 +344,7 @@ pub fn test_add_updating_flags_true(mut machine: Machine) {
         Operand::Register(Register::AX),
         Operand::Register(Register::AX),
         false,
-    ));
+    )).unwrap();
 
     assert!(machine.get_flag(Flag::ZERO));
 }
@@ -346,7 +356,7 @@ pub fn test_add_updating_flags_false(mut machine: Machine) {
         Operand::Register(Register::AX),
         Operand::Register(Register::AX),
         false,
-    ));
+    )).unwrap();
 
     assert!(!machine.get_flag(Flag::ZERO));
 }
@@ -354,7 +364,7 @@ pub fn test_add_updating_flags_false(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0x00)]
 pub fn test_add_acc_8bit_updating_flags_true(mut machine: Machine) {
-    machine.run_instruction(Instruction::AddAcc8(0));
+    machine.run_instruction(Instruction::AddAcc8(0)).unwrap();
 
     assert!(machine.get_flag(Flag::ZERO));
 }
@@ -362,7 +372,7 @@ pub fn test_add_acc_8bit_updating_flags_true(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0x00)]
 pub fn test_add_acc_8bit_updating_flags_false(mut machine: Machine) {
-    machine.run_instruction(Instruction::AddAcc8(1));
+    machine.run_instruction(Instruction::AddAcc8(1)).unwrap();
 
     assert!(!machine.get_flag(Flag::ZERO));
 }
@@ -370,7 +380,7 @@ pub fn test_add_acc_8bit_updating_flags_false(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0x00)]
 pub fn test_add_acc_16bit_updating_flags_true(mut machine: Machine) {
-    machine.run_instruction(Instruction::AddAcc16(0));
+    machine.run_instruction(Instruction::AddAcc16(0)).unwrap();
 
     assert!(machine.get_flag(Flag::ZERO));
 }
@@ -378,7 +388,7 @@ pub fn test_add_acc_16bit_updating_flags_true(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0x00)]
 pub fn test_add_acc_16bit_updating_flags_false(mut machine: Machine) {
-    machine.run_instruction(Instruction::AddAcc16(1));
+    machine.run_instruction(Instruction::AddAcc16(1)).unwrap();
 
     assert!(!machine.get_flag(Flag::ZERO));
 }
@@ -386,7 +396,7 @@ pub fn test_add_acc_16bit_updating_flags_false(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0xFFFF)]
 pub fn test_inc_updating_flags_true(mut machine: Machine) {
-    machine.run_instruction(Instruction::Inc(Register::AX));
+    machine.run_instruction(Instruction::Inc(Register::AX)).unwrap();
 
     assert!(machine.get_flag(Flag::ZERO));
 }
@@ -394,7 +404,184 @@ pub fn test_inc_updating_flags_true(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0x00)]
 pub fn test_inc_updating_flags_false(mut machine: Machine) {
-    machine.run_instruction(Instruction::Inc(Register::AX));
+    machine.run_instruction(Instruction::Inc(Register::AX)).unwrap();
 
     assert!(!machine.get_flag(Flag::ZERO));
 }
+
+#[machine_test]
+#[machine_state(Register::AL = 0xFF)]
+pub fn test_add_acc_8bit_carry_flag(mut machine: Machine) {
+    // ADD AL, 0x01
+    machine.run_instruction(Instruction::AddAcc8(0x01)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x00);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+    assert!(machine.get_flag(Flag::PARITY));
+    assert!(!machine.get_flag(Flag::SIGN));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x7F)]
+pub fn test_add_acc_8bit_overflow_flag(mut machine: Machine) {
+    // ADD AL, 0x01
+    machine.run_instruction(Instruction::AddAcc8(0x01)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x80);
+    assert!(machine.get_flag(Flag::OVERFLOW));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::ZERO));
+    assert!(!machine.get_flag(Flag::PARITY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x00FF)]
+pub fn test_add_acc_16bit_flags_use_16bit_width(mut machine: Machine) {
+    // ADD AX, 0x0001
+    machine.run_instruction(Instruction::AddAcc16(0x01)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0100);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::SIGN));
+    assert!(!machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x8000)]
+#[machine_state(Register::CX = 0x8000)]
+pub fn test_add_16bit_carry_and_overflow_flags(mut machine: Machine) {
+    // ADD AX, CX
+    machine.run_instruction(Instruction::Add(
+        Operand::Register(Register::AX),
+        Operand::Register(Register::CX),
+        false,
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0000);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::OVERFLOW));
+    assert!(machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x11)]
+#[machine_state(Register::AL = 0xFF)]
+#[machine_state(0x11 = 0x01)]
+pub fn test_add_8bit_reg_to_mem_zero_flag_on_wrap(mut machine: Machine) {
+    // ADD [BX], AL
+    machine.run_instruction(Instruction::Add(
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }),
+        Operand::Register(Register::AL),
+        true,
+    )).unwrap();
+
+    assert_eq!(machine.memory().data[0x11], 0x00);
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0xFFFF)]
+#[machine_state(Flag::CARRY = true)]
+pub fn test_inc_preserves_carry_flag(mut machine: Machine) {
+    // INC AX
+    machine.run_instruction(Instruction::Inc(Register::AX)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0000);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x7FFF)]
+pub fn test_inc_overflow_flag(mut machine: Machine) {
+    // INC AX
+    machine.run_instruction(Instruction::Inc(Register::AX)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x8000);
+    assert!(machine.get_flag(Flag::OVERFLOW));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x10)]
+fn test_add_16bit_reg_imm8_sign_extended(mut machine: Machine) {
+    // ADD BX, -1
+    machine.run_instruction(Instruction::Add(
+        Operand::Register(Register::BX),
+        Operand::Immediate8(0xFF),
+        false,
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::BX), 0x0F);
+    assert!(machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::BL = 0x10)]
+fn test_add_8bit_reg_imm8(mut machine: Machine) {
+    // ADD BL, 0xFF
+    machine.run_instruction(Instruction::Add(
+        Operand::Register(Register::BL),
+        Operand::Immediate8(0xFF),
+        true,
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::BX), 0x0F);
+}
+
+#[machine_test]
+#[machine_state(Register::SI = 0x20)]
+#[machine_state(0x20 = 0xFF)]
+#[machine_state(0x20 + 1 = 0x00)]
+fn test_add_16bit_mem_imm16(mut machine: Machine) {
+    // ADD WORD [SI], 0x0101
+    machine.run_instruction(Instruction::Add(
+        Operand::Memory(MemAddress {
+            base: None,
+            index: Some(Register::SI),
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }),
+        Operand::Immediate16(0x0101),
+        false,
+    )).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x20), 0x0200);
+}
+
+#[machine_test]
+#[machine_state(Register::SI = 0x20)]
+#[machine_state(0x20 = 0xFF)]
+#[machine_state(0x20 + 1 = 0x00)]
+fn test_add_8bit_mem_imm8_does_not_touch_next_byte(mut machine: Machine) {
+    // ADD BYTE [SI], 0x01
+    machine.run_instruction(Instruction::Add(
+        Operand::Memory(MemAddress {
+            base: None,
+            index: Some(Register::SI),
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }),
+        Operand::Immediate8(0x01),
+        true,
+    )).unwrap();
+
+    assert_eq!(machine.memory().data[0x20], 0x00);
+    assert_eq!(machine.memory().data[0x21], 0x00);
+    assert!(machine.get_flag(Flag::CARRY));
+}
@@ -0,0 +1,454 @@
+use nvm::Machine;
+use nvm::error::MachineError;
+use nvm::instruction::{Instruction, MovMemOperand, ShiftCount};
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+fn test_mov_imm_32(mut machine: Machine) {
+    // MOV EAX, 0x12345678
+    machine.run_instruction(Instruction::MovImm32(Register::EAX, 0x12345678)).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0x12345678);
+    assert_eq!(machine.get_register(Register::AX), 0x5678);
+}
+
+#[machine_test]
+#[machine_state(Register::EBX = 0xCAFEBABE)]
+fn test_mov_32_reg_reg(mut machine: Machine) {
+    // MOV ECX, EBX
+    machine.run_instruction(Instruction::Mov32(
+        Operand::Register(Register::ECX),
+        Operand::Register(Register::EBX),
+    )).unwrap();
+
+    assert_eq!(machine.get_register32(Register::ECX), 0xCAFEBABE);
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0x11223344)]
+#[machine_state(Register::BX = 0x0100)]
+fn test_mov_32_reg_mem(mut machine: Machine) {
+    let mem = Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        ..Default::default()
+    });
+
+    // MOV [BX], EAX
+    machine.run_instruction(Instruction::Mov32(mem, Operand::Register(Register::EAX))).unwrap();
+    assert_eq!(machine.memory().data[0x0100..0x0104], [0x44, 0x33, 0x22, 0x11]);
+
+    // MOV EDX, [BX]
+    machine.run_instruction(Instruction::Mov32(Operand::Register(Register::EDX), mem)).unwrap();
+    assert_eq!(machine.get_register32(Register::EDX), 0x11223344);
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0x0000FFFF)]
+fn test_add_acc_32_carries_into_upper_word(mut machine: Machine) {
+    // ADD EAX, 0x00000001
+    machine.run_instruction(Instruction::AddAcc32(0x00000001)).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0x00010000);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0xFFFFFFFF)]
+fn test_add_32_wrapping(mut machine: Machine) {
+    // ADD EAX, 0x01
+    machine.run_instruction(Instruction::Add32(
+        Operand::Register(Register::EAX),
+        Operand::Immediate8(0x01),
+    )).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0x7FFFFFFF)]
+fn test_add_32_overflow(mut machine: Machine) {
+    // ADD EAX, 0x00000001
+    machine.run_instruction(Instruction::Add32(
+        Operand::Register(Register::EAX),
+        Operand::Immediate32(0x00000001),
+    )).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0x80000000);
+    assert!(machine.get_flag(Flag::OVERFLOW));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0x00010000)]
+fn test_sub_32_borrows_from_upper_word(mut machine: Machine) {
+    // SUB EAX, 0x01 (sign extended)
+    machine.run_instruction(Instruction::Sub32(
+        Operand::Register(Register::EAX),
+        Operand::Immediate8(0x01),
+    )).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0x0000FFFF);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::SIGN));
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0x00000001)]
+fn test_sub_acc_32_negative(mut machine: Machine) {
+    // SUB EAX, 0x00000002
+    machine.run_instruction(Instruction::SubAcc32(0x00000002)).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0xFFFFFFFF);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::SIGN));
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0xFFFFFFFF)]
+#[machine_state(Register::EDX = 0x00000001)]
+#[machine_state(Register::EBX = 0x00000001)]
+#[machine_state(Register::ECX = 0x00000000)]
+fn test_adc_32_adds_64bit_values(mut machine: Machine) {
+    // ADD EAX, EBX
+    // ADC EDX, ECX
+    machine.run_instruction(Instruction::Add32(
+        Operand::Register(Register::EAX),
+        Operand::Register(Register::EBX),
+    )).unwrap();
+    machine.run_instruction(Instruction::Adc32(
+        Operand::Register(Register::EDX),
+        Operand::Register(Register::ECX),
+    )).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0x00000000);
+    assert_eq!(machine.get_register32(Register::EDX), 0x00000002);
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0x00000000)]
+#[machine_state(Flag::CARRY = true)]
+fn test_sbb_acc_32(mut machine: Machine) {
+    // SBB EAX, 0x00000000
+    machine.run_instruction(Instruction::SbbAcc32(0x00000000)).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0xFFFFFFFF);
+    assert!(machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::ECX = 0x12345678)]
+fn test_cmp_32_leaves_operand(mut machine: Machine) {
+    // CMP ECX, 0x12345678
+    machine.run_instruction(Instruction::Cmp32(
+        Operand::Register(Register::ECX),
+        Operand::Immediate32(0x12345678),
+    )).unwrap();
+
+    assert_eq!(machine.get_register32(Register::ECX), 0x12345678);
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0x00010000)]
+fn test_cmp_acc_32_only_differs_in_upper_word(mut machine: Machine) {
+    // CMP EAX, 0x00000000
+    machine.run_instruction(Instruction::CmpAcc32(0x00000000)).unwrap();
+
+    assert!(!machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0xFF00FF00)]
+#[machine_state(Register::EBX = 0x0FF00FF0)]
+#[machine_state(Flag::CARRY = true)]
+#[machine_state(Flag::OVERFLOW = true)]
+fn test_logic_32(mut machine: Machine) {
+    // AND EAX, EBX
+    machine.run_instruction(Instruction::And32(
+        Operand::Register(Register::EAX),
+        Operand::Register(Register::EBX),
+    )).unwrap();
+    assert_eq!(machine.get_register32(Register::EAX), 0x0F000F00);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+
+    // OR EAX, 0xF0000000
+    machine.run_instruction(Instruction::OrAcc32(0xF0000000)).unwrap();
+    assert_eq!(machine.get_register32(Register::EAX), 0xFF000F00);
+    assert!(machine.get_flag(Flag::SIGN));
+
+    // XOR EAX, EAX
+    machine.run_instruction(Instruction::Xor32(
+        Operand::Register(Register::EAX),
+        Operand::Register(Register::EAX),
+    )).unwrap();
+    assert_eq!(machine.get_register32(Register::EAX), 0);
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::PARITY));
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0x80000000)]
+fn test_test_32(mut machine: Machine) {
+    // TEST EAX, 0x80000000
+    machine.run_instruction(Instruction::TestAcc32(0x80000000)).unwrap();
+    assert!(!machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::SIGN));
+
+    // TEST EAX, 0x0000FFFF
+    machine.run_instruction(Instruction::Test32(
+        Operand::Register(Register::EAX),
+        Operand::Immediate32(0x0000FFFF),
+    )).unwrap();
+    assert!(machine.get_flag(Flag::ZERO));
+    assert_eq!(machine.get_register32(Register::EAX), 0x80000000);
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x0100)]
+#[machine_state(0x0100 = 0xFF)]
+#[machine_state(0x0101 = 0xFF)]
+#[machine_state(0x0102 = 0x00)]
+#[machine_state(0x0103 = 0x00)]
+fn test_add_32_mem_imm(mut machine: Machine) {
+    // ADD DWORD [BX], 0x00000001
+    machine.run_instruction(Instruction::Add32(
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            ..Default::default()
+        }),
+        Operand::Immediate32(0x00000001),
+    )).unwrap();
+
+    assert_eq!(machine.memory().data[0x0100..0x0104], [0x00, 0x00, 0x01, 0x00]);
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0x0000FFFF)]
+#[machine_state(Flag::CARRY = true)]
+fn test_inc_32(mut machine: Machine) {
+    // INC EAX
+    machine.run_instruction(Instruction::Inc(Register::EAX)).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0x00010000);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x0100)]
+fn test_dec_32_mem(mut machine: Machine) {
+    machine.memory_mut().data[0x0100..0x0104].copy_from_slice(&[0x00, 0x00, 0x01, 0x00]);
+
+    // DEC DWORD [BX]
+    machine.run_instruction(Instruction::Dec32(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        ..Default::default()
+    }))).unwrap();
+
+    assert_eq!(machine.memory().data[0x0100..0x0104], [0xFF, 0xFF, 0x00, 0x00]);
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::ECX = 0x00000001)]
+fn test_dec_32_to_zero(mut machine: Machine) {
+    // DEC ECX
+    machine.run_instruction(Instruction::Dec32(Operand::Register(Register::ECX))).unwrap();
+
+    assert_eq!(machine.get_register32(Register::ECX), 0);
+    assert!(machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::EDX = 0x0F0F0000)]
+fn test_not_32(mut machine: Machine) {
+    // NOT EDX
+    machine.run_instruction(Instruction::Not32(Operand::Register(Register::EDX))).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EDX), 0xF0F0FFFF);
+}
+
+#[machine_test]
+#[machine_state(Register::ESI = 0x00010000)]
+fn test_neg_32(mut machine: Machine) {
+    // NEG ESI
+    machine.run_instruction(Instruction::Neg32(Operand::Register(Register::ESI))).unwrap();
+
+    assert_eq!(machine.get_register32(Register::ESI), 0xFFFF0000);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::SIGN));
+}
+
+#[machine_test]
+#[machine_state(0x0100 = 0x78)]
+#[machine_state(0x0101 = 0x56)]
+#[machine_state(0x0102 = 0x34)]
+#[machine_state(0x0103 = 0x12)]
+fn test_mov_acc_mem_32(mut machine: Machine) {
+    // MOV EAX, [0x0100]
+    machine.run_instruction(Instruction::MovAccMem(
+        MovMemOperand::Register(Register::EAX),
+        MovMemOperand::MemoryPtr(0x0100),
+    )).unwrap();
+    assert_eq!(machine.get_register32(Register::EAX), 0x12345678);
+
+    // MOV [0x0200], EAX
+    machine.run_instruction(Instruction::MovAccMem(
+        MovMemOperand::MemoryPtr(0x0200),
+        MovMemOperand::Register(Register::EAX),
+    )).unwrap();
+    assert_eq!(machine.memory().data[0x0200..0x0204], [0x78, 0x56, 0x34, 0x12]);
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0x80000000)]
+#[machine_state(Register::EBX = 0x00000004)]
+fn test_mul_32(mut machine: Machine) {
+    // MUL EBX
+    machine.run_instruction(Instruction::Mul32(Operand::Register(Register::EBX))).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0x00000000);
+    assert_eq!(machine.get_register32(Register::EDX), 0x00000002);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0xFFFFFFFE)]
+#[machine_state(Register::EBX = 0x00000003)]
+fn test_imul_32(mut machine: Machine) {
+    // IMUL EBX
+    machine.run_instruction(Instruction::Imul32(Operand::Register(Register::EBX))).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0xFFFFFFFA);
+    assert_eq!(machine.get_register32(Register::EDX), 0xFFFFFFFF);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::EDX = 0x00000001)]
+#[machine_state(Register::EAX = 0x00000005)]
+#[machine_state(Register::ECX = 0x00000010)]
+fn test_div_32(mut machine: Machine) {
+    // DIV ECX, 0x100000005 / 0x10
+    machine.run_instruction(Instruction::Div32(Operand::Register(Register::ECX))).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0x10000000);
+    assert_eq!(machine.get_register32(Register::EDX), 0x00000005);
+}
+
+#[machine_test]
+#[machine_state(Register::EDX = 0x00000001)]
+#[machine_state(Register::EAX = 0x00000000)]
+#[machine_state(Register::ECX = 0x00000001)]
+fn test_div_32_overflow_without_handler(mut machine: Machine) {
+    let result = machine.run_instruction(Instruction::Div32(Operand::Register(Register::ECX)));
+
+    assert_eq!(result, Err(MachineError::UnhandledInterrupt(0)));
+    assert_eq!(machine.get_register32(Register::EDX), 0x00000001);
+}
+
+#[machine_test]
+#[machine_state(Register::EDX = 0xFFFFFFFF)]
+#[machine_state(Register::EAX = 0xFFFFFFF9)]
+#[machine_state(Register::ECX = 0x00000002)]
+fn test_idiv_32(mut machine: Machine) {
+    // IDIV ECX, -7 / 2
+    machine.run_instruction(Instruction::Idiv32(Operand::Register(Register::ECX))).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0xFFFFFFFD);
+    assert_eq!(machine.get_register32(Register::EDX), 0xFFFFFFFF);
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0xC0000001)]
+fn test_shl_32(mut machine: Machine) {
+    // SHL EAX, 1
+    machine.run_instruction(Instruction::Shl32(Operand::Register(Register::EAX), ShiftCount::One)).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0x80000002);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::EBX = 0x80000010)]
+#[machine_state(Register::CL = 0x04)]
+fn test_shr_sar_32(mut machine: Machine) {
+    // SAR EBX, CL
+    machine.run_instruction(Instruction::Sar32(Operand::Register(Register::EBX), ShiftCount::CL)).unwrap();
+    assert_eq!(machine.get_register32(Register::EBX), 0xF8000001);
+
+    // SHR EBX, 0x1F
+    machine.run_instruction(Instruction::Shr32(Operand::Register(Register::EBX), ShiftCount::Immediate(0x1F))).unwrap();
+    assert_eq!(machine.get_register32(Register::EBX), 0x00000001);
+    assert!(machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::EDX = 0x80000001)]
+fn test_rotate_32(mut machine: Machine) {
+    // ROL EDX, 1
+    machine.run_instruction(Instruction::Rol32(Operand::Register(Register::EDX), ShiftCount::One)).unwrap();
+    assert_eq!(machine.get_register32(Register::EDX), 0x00000003);
+    assert!(machine.get_flag(Flag::CARRY));
+
+    // RCR EDX, 1
+    machine.run_instruction(Instruction::Rcr32(Operand::Register(Register::EDX), ShiftCount::One)).unwrap();
+    assert_eq!(machine.get_register32(Register::EDX), 0x80000001);
+    assert!(machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0x12345678)]
+fn test_push_pop_32(mut machine: Machine) {
+    // PUSH EAX
+    machine.run_instruction(Instruction::Push(Register::EAX)).unwrap();
+    assert_eq!(machine.get_register(Register::SP), 1020);
+    assert_eq!(machine.memory().data[1020..1024], [0x78, 0x56, 0x34, 0x12]);
+
+    // POP EBX
+    machine.run_instruction(Instruction::Pop(Register::EBX)).unwrap();
+    assert_eq!(machine.get_register(Register::SP), 1024);
+    assert_eq!(machine.get_register32(Register::EBX), 0x12345678);
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0x11111111)]
+#[machine_state(Register::EBX = 0x22222222)]
+fn test_xchg_32(mut machine: Machine) {
+    // XCHG EAX, EBX
+    machine.run_instruction(Instruction::Xchg32(Operand::Register(Register::EAX), Operand::Register(Register::EBX))).unwrap();
+    assert_eq!(machine.get_register32(Register::EAX), 0x22222222);
+    assert_eq!(machine.get_register32(Register::EBX), 0x11111111);
+
+    // XCHG EAX, EBX
+    machine.run_instruction(Instruction::XchgAcc(Register::EBX)).unwrap();
+    assert_eq!(machine.get_register32(Register::EAX), 0x11111111);
+    assert_eq!(machine.get_register32(Register::EBX), 0x22222222);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x8000)]
+fn test_cwde_cdq(mut machine: Machine) {
+    // CWDE
+    machine.run_instruction(Instruction::Cwde).unwrap();
+    assert_eq!(machine.get_register32(Register::EAX), 0xFFFF8000);
+
+    // CDQ
+    machine.run_instruction(Instruction::Cdq).unwrap();
+    assert_eq!(machine.get_register32(Register::EDX), 0xFFFFFFFF);
+}
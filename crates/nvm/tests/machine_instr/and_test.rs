@@ -8,7 +8,7 @@ use nvm_test_utils::{machine_state, machine_test};
 #[machine_state(Register::AL = 0x0A)]
 fn test_and_acc_8(mut machine: Machine) {
     // AND AL, 0x02
-    machine.run_instruction(Instruction::AndAcc8(0x02));
+    machine.run_instruction(Instruction::AndAcc8(0x02)).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x0A & 0x02);
 }
@@ -17,7 +17,7 @@ fn test_and_acc_8(mut machine: Machine) {
 #[machine_state(Register::AX = 0x1122)]
 fn test_and_acc_16(mut machine: Machine) {
     // AND AX, 0x2211
-    machine.run_instruction(Instruction::AndAcc16(0x2211));
+    machine.run_instruction(Instruction::AndAcc16(0x2211)).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x1122 & 0x2211);
 }
@@ -35,9 +35,11 @@ fn test_and_8bit_reg_to_mem(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         Operand::Register(Register::AL),
-    ));
+        true,
+    )).unwrap();
 
     assert_eq!(machine.memory().data[0x11 + 0x22], 0x22 & 0x11);
 }
@@ -55,9 +57,11 @@ fn test_and_16bit_reg_to_mem(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         Operand::Register(Register::AX),
-    ));
+        false,
+    )).unwrap();
 
     assert_eq!(machine.memory().read_word(0x11 + 0x22), 0x2233 & 0xFF);
 }
@@ -76,8 +80,10 @@ fn test_and_mem_to_8bit_reg(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
-    ));
+        true,
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x22 & 0x11);
 }
@@ -96,8 +102,10 @@ fn test_and_mem_to_16bit_reg(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
-    ));
+        false,
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x2233 & 0x11);
 }
@@ -116,8 +124,10 @@ fn test_and_mem_to_16bit_reg_1byte_displacement(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0x33,
             displacement_size: 1,
+            segment: None,
         }),
-    ));
+        false,
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x2233 & 0x11);
 }
@@ -136,8 +146,10 @@ fn test_and_mem_to_16bit_reg_2byte_displacement(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0x3333,
             displacement_size: 2,
+            segment: None,
         }),
-    ));
+        false,
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x2233 & 0x11);
 }
@@ -150,7 +162,8 @@ fn test_and_8bit_reg_to_8bit_reg(mut machine: Machine) {
     machine.run_instruction(Instruction::And(
         Operand::Register(Register::AL),
         Operand::Register(Register::CL),
-    ));
+        true,
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x22 & 0x11);
 }
@@ -163,7 +176,8 @@ fn test_and_16bit_reg_to_16bit_reg(mut machine: Machine) {
     machine.run_instruction(Instruction::And(
         Operand::Register(Register::AX),
         Operand::Register(Register::CX),
-    ));
+        false,
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x2233 & 0x11);
 }
@@ -175,7 +189,8 @@ pub fn test_and_updating_flags_true(mut machine: Machine) {
     machine.run_instruction(Instruction::And(
         Operand::Register(Register::AX),
         Operand::Register(Register::CX),
-    ));
+        false,
+    )).unwrap();
 
     assert!(machine.get_flag(Flag::ZERO));
 }
@@ -187,7 +202,8 @@ pub fn test_and_updating_flags_false(mut machine: Machine) {
     machine.run_instruction(Instruction::And(
         Operand::Register(Register::AX),
         Operand::Register(Register::CX),
-    ));
+        false,
+    )).unwrap();
 
     assert!(!machine.get_flag(Flag::ZERO));
 }
@@ -195,7 +211,7 @@ pub fn test_and_updating_flags_false(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0xFF)]
 pub fn test_and_acc_8bit_updating_flags_true(mut machine: Machine) {
-    machine.run_instruction(Instruction::AndAcc8(0x00));
+    machine.run_instruction(Instruction::AndAcc8(0x00)).unwrap();
 
     assert!(machine.get_flag(Flag::ZERO));
 }
@@ -203,7 +219,7 @@ pub fn test_and_acc_8bit_updating_flags_true(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0xFF)]
 pub fn test_and_acc_8bit_updating_flags_false(mut machine: Machine) {
-    machine.run_instruction(Instruction::AndAcc8(0x01));
+    machine.run_instruction(Instruction::AndAcc8(0x01)).unwrap();
 
     assert!(!machine.get_flag(Flag::ZERO));
 }
@@ -211,7 +227,7 @@ pub fn test_and_acc_8bit_updating_flags_false(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0xFF)]
 pub fn test_and_acc_16bit_updating_flags_true(mut machine: Machine) {
-    machine.run_instruction(Instruction::AndAcc16(0x00));
+    machine.run_instruction(Instruction::AndAcc16(0x00)).unwrap();
 
     assert!(machine.get_flag(Flag::ZERO));
 }
@@ -219,7 +235,58 @@ pub fn test_and_acc_16bit_updating_flags_true(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0xFF)]
 pub fn test_and_acc_16bit_updating_flags_false(mut machine: Machine) {
-    machine.run_instruction(Instruction::AndAcc16(0x01));
+    machine.run_instruction(Instruction::AndAcc16(0x01)).unwrap();
 
     assert!(!machine.get_flag(Flag::ZERO));
 }
+
+#[machine_test]
+#[machine_state(Register::AL = 0xF0)]
+#[machine_state(Flag::CARRY = true)]
+#[machine_state(Flag::OVERFLOW = true)]
+pub fn test_and_acc_8bit_clears_carry_and_overflow(mut machine: Machine) {
+    // AND AL, 0x80
+    machine.run_instruction(Instruction::AndAcc8(0x80)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x80);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(!machine.get_flag(Flag::PARITY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x8003)]
+#[machine_state(Register::CX = 0xFFFF)]
+pub fn test_and_16bit_sign_and_parity_flags(mut machine: Machine) {
+    // AND AX, CX
+    machine.run_instruction(Instruction::And(
+        Operand::Register(Register::AX),
+        Operand::Register(Register::CX),
+        false,
+    )).unwrap();
+
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(machine.get_flag(Flag::PARITY));
+    assert!(!machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x11)]
+#[machine_state(0x11 = 0xAB)]
+fn test_and_8bit_mem_imm8(mut machine: Machine) {
+    // AND BYTE [BX], 0x0F
+    machine.run_instruction(Instruction::And(
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }),
+        Operand::Immediate8(0x0F),
+        true,
+    )).unwrap();
+
+    assert_eq!(machine.memory().data[0x11], 0x0B);
+}
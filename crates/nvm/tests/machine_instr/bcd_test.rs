@@ -0,0 +1,206 @@
+use nvm::error::MachineError;
+use nvm::instruction::Instruction;
+use nvm::Machine;
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::AL = 0x79)]
+fn test_daa_after_add(mut machine: Machine) {
+    // ADD AL, 0x35 ; 79 + 35 = 0xAE
+    // DAA        ; 114 decimal
+    machine.run_instruction(Instruction::AddAcc8(0x35)).unwrap();
+    machine.run_instruction(Instruction::Daa).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x14);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x38)]
+fn test_daa_low_digit_from_auxiliary_carry(mut machine: Machine) {
+    // ADD AL, 0x29 ; 0x61 with AF set
+    // DAA          ; 67 decimal
+    machine.run_instruction(Instruction::AddAcc8(0x29)).unwrap();
+    machine.run_instruction(Instruction::Daa).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x67);
+    assert!(machine.get_flag(Flag::AUXILIARY));
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x12)]
+#[machine_state(Flag::CARRY = true)]
+#[machine_state(Flag::AUXILIARY = true)]
+fn test_daa_adjusts_both_digits_from_flags(mut machine: Machine) {
+    // DAA
+    machine.run_instruction(Instruction::Daa).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x78);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x50)]
+#[machine_state(Flag::ZERO = false)]
+fn test_daa_wraps_to_zero(mut machine: Machine) {
+    // ADD AL, 0x50 ; 0xA0
+    // DAA          ; 100 decimal, 00 with carry
+    machine.run_instruction(Instruction::AddAcc8(0x50)).unwrap();
+    machine.run_instruction(Instruction::Daa).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x00);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::PARITY));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x35)]
+fn test_das_after_sub(mut machine: Machine) {
+    // SUB AL, 0x47 ; 0xEE with borrow
+    // DAS          ; 35 - 47 = -12, 88 with borrow
+    machine.run_instruction(Instruction::SubAcc8(0x47)).unwrap();
+    machine.run_instruction(Instruction::Das).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x88);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+    assert!(machine.get_flag(Flag::SIGN));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x86)]
+fn test_das_without_borrow(mut machine: Machine) {
+    // SUB AL, 0x57 ; 0x2F
+    // DAS          ; 29 decimal
+    machine.run_instruction(Instruction::SubAcc8(0x57)).unwrap();
+    machine.run_instruction(Instruction::Das).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x29);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x03)]
+#[machine_state(Flag::AUXILIARY = true)]
+fn test_das_low_digit_borrow_sets_carry(mut machine: Machine) {
+    // DAS
+    machine.run_instruction(Instruction::Das).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0xFD);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0008)]
+fn test_aaa_after_add(mut machine: Machine) {
+    // ADD AL, 0x05 ; 0x0D
+    // AAA          ; AH = 1, AL = 3
+    machine.run_instruction(Instruction::AddAcc8(0x05)).unwrap();
+    machine.run_instruction(Instruction::Aaa).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0103);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0235)]
+#[machine_state(Flag::CARRY = true)]
+fn test_aaa_without_adjust_clears_high_nibble(mut machine: Machine) {
+    // AAA
+    machine.run_instruction(Instruction::Aaa).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0205);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::AUXILIARY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x00FF)]
+fn test_aaa_does_not_carry_from_al_into_ah(mut machine: Machine) {
+    // AAA ; 8086 adds 6 to AL and 1 to AH separately
+    machine.run_instruction(Instruction::Aaa).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0105);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0203)]
+fn test_aas_after_sub(mut machine: Machine) {
+    // SUB AL, 0x05 ; 0xFE with AF
+    // AAS          ; 23 - 5 = 18, AH = 1, AL = 8
+    machine.run_instruction(Instruction::SubAcc8(0x05)).unwrap();
+    machine.run_instruction(Instruction::Aas).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0108);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0047)]
+#[machine_state(Flag::CARRY = true)]
+fn test_aas_without_adjust(mut machine: Machine) {
+    // AAS
+    machine.run_instruction(Instruction::Aas).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0007);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::AUXILIARY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x003F)]
+fn test_aam(mut machine: Machine) {
+    // AAM ; 63 = 6 * 10 + 3
+    machine.run_instruction(Instruction::Aam(10)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0603);
+    assert!(!machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::PARITY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x1234)]
+fn test_aam_with_custom_base(mut machine: Machine) {
+    // AAM 16
+    machine.run_instruction(Instruction::Aam(16)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0304);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0030)]
+fn test_aam_zero_base_raises_divide_error(mut machine: Machine) {
+    // AAM 0
+    let result = machine.run_instruction(Instruction::Aam(0));
+
+    assert_eq!(result, Err(MachineError::UnhandledInterrupt(0)));
+    assert_eq!(machine.get_register(Register::AX), 0x0030);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0705)]
+fn test_aad(mut machine: Machine) {
+    // AAD ; 7 * 10 + 5 = 75
+    machine.run_instruction(Instruction::Aad(10)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x004B);
+    assert!(!machine.get_flag(Flag::SIGN));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x1A06)]
+fn test_aad_truncates_to_byte(mut machine: Machine) {
+    // AAD ; 26 * 10 + 6 = 266, only the low byte is kept
+    machine.run_instruction(Instruction::Aad(10)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x000A);
+}
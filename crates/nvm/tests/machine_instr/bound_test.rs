@@ -0,0 +1,67 @@
+use nvm::error::MachineError;
+use nvm::instruction::Instruction;
+use nvm::Machine;
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::Register;
+use nvm_test_utils::{machine_state, machine_test};
+
+// INT 5 handler at 0x0200:0x0010
+const IVT_BOUND: [u8; 4] = [0x10, 0x00, 0x00, 0x02];
+
+// bounds -2..=10 at DS:0x0100
+const BOUNDS: Operand = Operand::Memory(MemAddress {
+    base: None,
+    index: None,
+    displacement: 0x0100,
+    displacement_size: 2,
+    segment: None,
+});
+
+fn store_bounds(machine: &mut Machine) {
+    machine.memory_mut().data[0x0100..0x0104].copy_from_slice(&[0xFE, 0xFF, 0x0A, 0x00]);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0xFFFE)]
+#[machine_state(Register::IP = 0x0044)]
+fn test_bound_within_range(mut machine: Machine) {
+    store_bounds(&mut machine);
+
+    // BOUND AX, [0x0100]
+    machine.run_instruction(Instruction::Bound(Register::AX, BOUNDS)).unwrap();
+    assert_eq!(machine.get_register(Register::IP), 0x0044);
+
+    machine.set_register(Register::AX, 10);
+    machine.run_instruction(Instruction::Bound(Register::AX, BOUNDS)).unwrap();
+    assert_eq!(machine.get_register(Register::IP), 0x0044);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x000B)]
+fn test_bound_out_of_range_without_handler(mut machine: Machine) {
+    store_bounds(&mut machine);
+
+    // BOUND AX, [0x0100]
+    let result = machine.run_instruction(Instruction::Bound(Register::AX, BOUNDS));
+
+    assert_eq!(result, Err(MachineError::UnhandledInterrupt(Machine::BOUND_VECTOR)));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0xFFFD)]
+#[machine_state(Register::IP = 0x0044)]
+#[machine_state(Register::CS = 0x0001)]
+#[machine_state(Register::SP = 0x0100)]
+fn test_bound_out_of_range_delivers_int_5(mut machine: Machine) {
+    store_bounds(&mut machine);
+    machine.memory_mut().data[0x14..0x18].copy_from_slice(&IVT_BOUND);
+
+    // BOUND AX, [0x0100]
+    machine.run_instruction(Instruction::Bound(Register::AX, BOUNDS)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CS), 0x0200);
+    assert_eq!(machine.get_register(Register::IP), 0x0010);
+    // the return address points at the 4 byte BOUND instruction itself
+    assert_eq!(machine.memory().read_word(0x00FA), 0x0040);
+    assert_eq!(machine.memory().read_word(0x00FC), 0x0001);
+}
@@ -0,0 +1,162 @@
+use nvm::Machine;
+use nvm::instruction::Instruction;
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::Register;
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::IP = 0x0100)]
+#[machine_state(Register::SP = 0x0200)]
+fn test_call_near(mut machine: Machine) {
+    machine.run_instruction(Instruction::CallNear(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x0120);
+    assert_eq!(machine.get_register(Register::SP), 0x01FE);
+    assert_eq!(machine.memory().read_word(0x01FE), 0x0100);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0x0100)]
+#[machine_state(Register::SP = 0x0200)]
+fn test_call_near_backwards(mut machine: Machine) {
+    machine.run_instruction(Instruction::CallNear(-0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x00E0);
+    assert_eq!(machine.memory().read_word(0x01FE), 0x0100);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0x0100)]
+#[machine_state(Register::CS = 0x0001)]
+#[machine_state(Register::SP = 0x0200)]
+fn test_call_far(mut machine: Machine) {
+    machine.run_instruction(Instruction::CallFar(0x0002, 0x0030)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CS), 0x0002);
+    assert_eq!(machine.get_register(Register::IP), 0x0030);
+    assert_eq!(machine.get_register(Register::SP), 0x01FC);
+    assert_eq!(machine.memory().read_word(0x01FE), 0x0001);
+    assert_eq!(machine.memory().read_word(0x01FC), 0x0100);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x01FE)]
+#[machine_state(0x01FE = 0x34)]
+#[machine_state(0x01FE + 1 = 0x12)]
+fn test_ret(mut machine: Machine) {
+    machine.run_instruction(Instruction::Ret).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x1234);
+    assert_eq!(machine.get_register(Register::SP), 0x0200);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x01FE)]
+#[machine_state(0x01FE = 0x34)]
+#[machine_state(0x01FE + 1 = 0x12)]
+fn test_ret_imm(mut machine: Machine) {
+    machine.run_instruction(Instruction::RetImm(4)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x1234);
+    assert_eq!(machine.get_register(Register::SP), 0x0204);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x01FC)]
+#[machine_state(0x01FC = 0x34)]
+#[machine_state(0x01FC + 1 = 0x12)]
+#[machine_state(0x01FE = 0x02)]
+fn test_ret_far(mut machine: Machine) {
+    machine.run_instruction(Instruction::RetFar).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x1234);
+    assert_eq!(machine.get_register(Register::CS), 0x0002);
+    assert_eq!(machine.get_register(Register::SP), 0x0200);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x01FC)]
+#[machine_state(0x01FC = 0x34)]
+#[machine_state(0x01FC + 1 = 0x12)]
+#[machine_state(0x01FE = 0x02)]
+fn test_ret_far_imm(mut machine: Machine) {
+    machine.run_instruction(Instruction::RetFarImm(2)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x1234);
+    assert_eq!(machine.get_register(Register::CS), 0x0002);
+    assert_eq!(machine.get_register(Register::SP), 0x0202);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0x0100)]
+#[machine_state(Register::CS = 0x0001)]
+fn test_call_far_ret_far_roundtrip(mut machine: Machine) {
+    let sp = machine.get_register(Register::SP);
+
+    machine.run_instruction(Instruction::CallFar(0x0003, 0x0040)).unwrap();
+    machine.run_instruction(Instruction::RetFar).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x0100);
+    assert_eq!(machine.get_register(Register::CS), 0x0001);
+    assert_eq!(machine.get_register(Register::SP), sp);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0x0100)]
+#[machine_state(Register::SP = 0x0200)]
+#[machine_state(Register::AX = 0x0340)]
+fn test_call_near_indirect_register(mut machine: Machine) {
+    // CALL AX
+    machine.run_instruction(Instruction::CallNearIndirect(Operand::Register(Register::AX))).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x0340);
+    assert_eq!(machine.get_register(Register::SP), 0x01FE);
+    assert_eq!(machine.memory().read_word(0x01FE), 0x0100);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0x0100)]
+#[machine_state(Register::SP = 0x0200)]
+#[machine_state(Register::BX = 0x0004)]
+#[machine_state(0x14 = 0x40)]
+#[machine_state(0x15 = 0x03)]
+fn test_call_near_indirect_memory(mut machine: Machine) {
+    // CALL [BX + 0x10]
+    machine.run_instruction(Instruction::CallNearIndirect(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0x10,
+        displacement_size: 1,
+        segment: None,
+    }))).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x0340);
+    assert_eq!(machine.get_register(Register::SP), 0x01FE);
+    assert_eq!(machine.memory().read_word(0x01FE), 0x0100);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0x0100)]
+#[machine_state(Register::CS = 0x0001)]
+#[machine_state(Register::SP = 0x0200)]
+#[machine_state(0x20 = 0x30)]
+#[machine_state(0x21 = 0x00)]
+#[machine_state(0x22 = 0x02)]
+#[machine_state(0x23 = 0x00)]
+fn test_call_far_indirect(mut machine: Machine) {
+    // CALL FAR [0x0010], DS = 0x0001
+    machine.set_register(Register::DS, 0x0001);
+    machine.run_instruction(Instruction::CallFarIndirect(Operand::Memory(MemAddress {
+        base: None,
+        index: None,
+        displacement: 0x0010,
+        displacement_size: 2,
+        segment: None,
+    }))).unwrap();
+
+    assert_eq!(machine.get_register(Register::CS), 0x0002);
+    assert_eq!(machine.get_register(Register::IP), 0x0030);
+    assert_eq!(machine.get_register(Register::SP), 0x01FC);
+    assert_eq!(machine.memory().read_word(0x01FE), 0x0001);
+    assert_eq!(machine.memory().read_word(0x01FC), 0x0100);
+}
@@ -0,0 +1,116 @@
+use nvm::Machine;
+use nvm::instruction::Instruction;
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::AL = 0x0A)]
+fn test_cmp_acc_8_equal(mut machine: Machine) {
+    // CMP AL, 0x0A
+    machine.run_instruction(Instruction::CmpAcc8(0x0A)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x0A);
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x01)]
+fn test_cmp_acc_8_below(mut machine: Machine) {
+    // CMP AL, 0x02
+    machine.run_instruction(Instruction::CmpAcc8(0x02)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x01);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(!machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x8000)]
+fn test_cmp_acc_16_signed_less(mut machine: Machine) {
+    // CMP AX, 0x0001
+    machine.run_instruction(Instruction::CmpAcc16(0x0001)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x8000);
+    // -32768 < 1 signed, 0x8000 > 1 unsigned
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::OVERFLOW));
+    assert_ne!(machine.get_flag(Flag::SIGN), machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x11)]
+#[machine_state(Register::AL = 0x22)]
+#[machine_state(0x11 = 0x22)]
+fn test_cmp_8bit_mem_with_reg(mut machine: Machine) {
+    // CMP [BX], AL
+    machine.run_instruction(Instruction::Cmp(
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }),
+        Operand::Register(Register::AL),
+        true,
+    )).unwrap();
+
+    assert_eq!(machine.memory().data[0x11], 0x22);
+    assert!(machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x1000)]
+#[machine_state(Register::CX = 0x2000)]
+fn test_cmp_16bit_reg_with_reg(mut machine: Machine) {
+    // CMP AX, CX
+    machine.run_instruction(Instruction::Cmp(
+        Operand::Register(Register::AX),
+        Operand::Register(Register::CX),
+        false,
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x1000);
+    assert_eq!(machine.get_register(Register::CX), 0x2000);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::SI = 0x20)]
+#[machine_state(0x20 = 0x10)]
+fn test_cmp_16bit_mem_imm8(mut machine: Machine) {
+    // CMP WORD [SI], 0x10
+    machine.run_instruction(Instruction::Cmp(
+        Operand::Memory(MemAddress {
+            base: None,
+            index: Some(Register::SI),
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }),
+        Operand::Immediate8(0x10),
+        false,
+    )).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x20), 0x10);
+    assert!(machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::CX = 0x0000)]
+fn test_cmp_16bit_reg_imm8_sign_extended(mut machine: Machine) {
+    // CMP CX, -1
+    machine.run_instruction(Instruction::Cmp(
+        Operand::Register(Register::CX),
+        Operand::Immediate8(0xFF),
+        false,
+    )).unwrap();
+
+    // 0 < 0xFFFF unsigned, 0 > -1 signed
+    assert!(machine.get_flag(Flag::CARRY));
+    assert_eq!(machine.get_flag(Flag::SIGN), machine.get_flag(Flag::OVERFLOW));
+}
@@ -0,0 +1,89 @@
+use nvm::instruction::Instruction;
+use nvm::Machine;
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::AX = 0x1280)]
+fn test_cbw_negative(mut machine: Machine) {
+    // CBW
+    machine.run_instruction(Instruction::Cbw).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0xFF80);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0xFF7F)]
+fn test_cbw_positive(mut machine: Machine) {
+    // CBW
+    machine.run_instruction(Instruction::Cbw).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x007F);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x8000)]
+#[machine_state(Register::DX = 0x1234)]
+fn test_cwd_negative(mut machine: Machine) {
+    // CWD
+    machine.run_instruction(Instruction::Cwd).unwrap();
+
+    assert_eq!(machine.get_register(Register::DX), 0xFFFF);
+    assert_eq!(machine.get_register(Register::AX), 0x8000);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x7FFF)]
+#[machine_state(Register::DX = 0x1234)]
+fn test_cwd_positive(mut machine: Machine) {
+    // CWD
+    machine.run_instruction(Instruction::Cwd).unwrap();
+
+    assert_eq!(machine.get_register(Register::DX), 0x0000);
+}
+
+#[machine_test]
+#[machine_state(Flag::SIGN = true)]
+#[machine_state(Flag::CARRY = true)]
+#[machine_state(Flag::OVERFLOW = true)]
+#[machine_state(Register::AL = 0x11)]
+fn test_lahf(mut machine: Machine) {
+    // LAHF
+    machine.run_instruction(Instruction::Lahf).unwrap();
+
+    // reserved bit 1 always reads as 1
+    assert_eq!(machine.get_register(Register::AH), 0x83);
+    assert_eq!(machine.get_register(Register::AL), 0x11);
+}
+
+#[machine_test]
+#[machine_state(Register::AH = 0xFF)]
+#[machine_state(Flag::DIRECTION = true)]
+fn test_sahf(mut machine: Machine) {
+    // SAHF
+    machine.run_instruction(Instruction::Sahf).unwrap();
+
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+    assert!(machine.get_flag(Flag::PARITY));
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::DIRECTION));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+    // bits not backed by a flag are not loaded, reserved bit 1 stays set
+    assert_eq!(machine.get_register(Register::F) & 0x00FF, 0x00D7);
+}
+
+#[machine_test]
+#[machine_state(Register::AH = 0x00)]
+#[machine_state(Flag::ZERO = true)]
+#[machine_state(Flag::CARRY = true)]
+#[machine_state(Flag::OVERFLOW = true)]
+fn test_sahf_clears_flags(mut machine: Machine) {
+    // SAHF
+    machine.run_instruction(Instruction::Sahf).unwrap();
+
+    assert!(!machine.get_flag(Flag::ZERO));
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::OVERFLOW));
+}
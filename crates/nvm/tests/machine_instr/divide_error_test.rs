@@ -0,0 +1,121 @@
+use nvm::error::MachineError;
+use nvm::instruction::Instruction;
+use nvm::Machine;
+use nvm::modrm::Operand;
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+// INT 0 handler at 0x0200:0x0010
+const IVT_DIVIDE_ERROR: [u8; 4] = [0x10, 0x00, 0x00, 0x02];
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0010)]
+#[machine_state(Register::CL = 0x00)]
+fn test_div_8_by_zero_without_handler(mut machine: Machine) {
+    let result = machine.run_instruction(Instruction::Div8(Operand::Register(Register::CL)));
+
+    assert_eq!(result, Err(MachineError::UnhandledInterrupt(0)));
+    assert_eq!(machine.get_register(Register::AX), 0x0010);
+}
+
+#[machine_test]
+#[machine_state(Register::DX = 0x0001)]
+#[machine_state(Register::AX = 0x0000)]
+#[machine_state(Register::CX = 0x0000)]
+fn test_div_16_by_zero_without_handler(mut machine: Machine) {
+    let result = machine.run_instruction(Instruction::Div16(Operand::Register(Register::CX)));
+
+    assert_eq!(result, Err(MachineError::UnhandledInterrupt(0)));
+    assert_eq!(machine.get_register(Register::DX), 0x0001);
+    assert_eq!(machine.get_register(Register::AX), 0x0000);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0x0042)]
+#[machine_state(Register::CS = 0x0001)]
+#[machine_state(Register::SP = 0x0100)]
+#[machine_state(Register::AX = 0x0010)]
+#[machine_state(Register::CL = 0x00)]
+#[machine_state(Flag::INTERRUPT = true)]
+fn test_div_by_zero_delivers_int_0(mut machine: Machine) {
+    machine.memory_mut().data[0..4].copy_from_slice(&IVT_DIVIDE_ERROR);
+    let flags = machine.get_register(Register::F);
+
+    machine.run_instruction(Instruction::Div8(Operand::Register(Register::CL))).unwrap();
+
+    assert_eq!(machine.get_register(Register::CS), 0x0200);
+    assert_eq!(machine.get_register(Register::IP), 0x0010);
+    assert!(!machine.get_flag(Flag::INTERRUPT));
+
+    // FLAGS, CS and IP of the next instruction are pushed for the handler
+    assert_eq!(machine.get_register(Register::SP), 0x00FA);
+    assert_eq!(machine.memory().read_word(0x00FE), flags);
+    assert_eq!(machine.memory().read_word(0x00FC), 0x0001);
+    assert_eq!(machine.memory().read_word(0x00FA), 0x0042);
+
+    assert_eq!(machine.get_register(Register::AX), 0x0010);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x1000)]
+#[machine_state(Register::CL = 0x02)]
+fn test_div_8_quotient_overflow(mut machine: Machine) {
+    let result = machine.run_instruction(Instruction::Div8(Operand::Register(Register::CL)));
+
+    assert_eq!(result, Err(MachineError::UnhandledInterrupt(0)));
+    assert_eq!(machine.get_register(Register::AX), 0x1000);
+}
+
+#[machine_test]
+#[machine_state(Register::DX = 0x0010)]
+#[machine_state(Register::AX = 0x0000)]
+#[machine_state(Register::CX = 0x0010)]
+fn test_div_16_quotient_overflow(mut machine: Machine) {
+    machine.memory_mut().data[0..4].copy_from_slice(&IVT_DIVIDE_ERROR);
+
+    machine.run_instruction(Instruction::Div16(Operand::Register(Register::CX))).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x0010);
+    assert_eq!(machine.get_register(Register::DX), 0x0010);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0100)]
+#[machine_state(Register::CL = 0x00)]
+fn test_idiv_8_by_zero(mut machine: Machine) {
+    let result = machine.run_instruction(Instruction::Idiv8(Operand::Register(Register::CL)));
+
+    assert_eq!(result, Err(MachineError::UnhandledInterrupt(0)));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0100)]
+#[machine_state(Register::CL = 0x02)]
+fn test_idiv_8_quotient_overflow(mut machine: Machine) {
+    // 256 / 2 = 128 does not fit into a signed byte
+    let result = machine.run_instruction(Instruction::Idiv8(Operand::Register(Register::CL)));
+
+    assert_eq!(result, Err(MachineError::UnhandledInterrupt(0)));
+    assert_eq!(machine.get_register(Register::AX), 0x0100);
+}
+
+#[machine_test]
+#[machine_state(Register::DX = 0x8000)]
+#[machine_state(Register::AX = 0x0000)]
+#[machine_state(Register::CX = 0xFFFF)]
+fn test_idiv_16_min_by_minus_one_overflows(mut machine: Machine) {
+    let result = machine.run_instruction(Instruction::Idiv16(Operand::Register(Register::CX)));
+
+    assert_eq!(result, Err(MachineError::UnhandledInterrupt(0)));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0xFF80)]
+#[machine_state(Register::CL = 0x01)]
+fn test_idiv_8_min_quotient_fits(mut machine: Machine) {
+    // -128 / 1
+    machine.run_instruction(Instruction::Idiv8(Operand::Register(Register::CL))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x80);
+    assert_eq!(machine.get_register(Register::AH), 0x00);
+}
@@ -0,0 +1,62 @@
+use nvm::instruction::Instruction;
+use nvm::Machine;
+use nvm::register::Register;
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::SP = 0x0100)]
+#[machine_state(Register::BP = 0x0200)]
+fn test_enter_level_0(mut machine: Machine) {
+    // ENTER 0x10, 0
+    machine.run_instruction(Instruction::Enter(0x10, 0)).unwrap();
+
+    assert_eq!(machine.get_register(Register::BP), 0x00FE);
+    assert_eq!(machine.get_register(Register::SP), 0x00EE);
+    assert_eq!(machine.memory().read_word(0x00FE), 0x0200);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x0100)]
+#[machine_state(Register::BP = 0x0200)]
+fn test_enter_level_1(mut machine: Machine) {
+    // ENTER 0x04, 1
+    machine.run_instruction(Instruction::Enter(0x04, 1)).unwrap();
+
+    // old BP, then the new frame pointer
+    assert_eq!(machine.memory().read_word(0x00FE), 0x0200);
+    assert_eq!(machine.memory().read_word(0x00FC), 0x00FE);
+    assert_eq!(machine.get_register(Register::BP), 0x00FE);
+    assert_eq!(machine.get_register(Register::SP), 0x00F8);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x0100)]
+#[machine_state(Register::BP = 0x0200)]
+#[machine_state(0x01FE = 0x11)]
+#[machine_state(0x01FF = 0x11)]
+#[machine_state(0x01FC = 0x22)]
+#[machine_state(0x01FD = 0x22)]
+fn test_enter_nested_copies_enclosing_frame_pointers(mut machine: Machine) {
+    // ENTER 0x02, 3
+    machine.run_instruction(Instruction::Enter(0x02, 3)).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x00FE), 0x0200);
+    assert_eq!(machine.memory().read_word(0x00FC), 0x1111);
+    assert_eq!(machine.memory().read_word(0x00FA), 0x2222);
+    assert_eq!(machine.memory().read_word(0x00F8), 0x00FE);
+    assert_eq!(machine.get_register(Register::BP), 0x00FE);
+    assert_eq!(machine.get_register(Register::SP), 0x00F6);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x0100)]
+#[machine_state(Register::BP = 0x0200)]
+fn test_enter_leave_round_trip(mut machine: Machine) {
+    // ENTER 0x20, 0
+    // LEAVE
+    machine.run_instruction(Instruction::Enter(0x20, 0)).unwrap();
+    machine.run_instruction(Instruction::Leave).unwrap();
+
+    assert_eq!(machine.get_register(Register::SP), 0x0100);
+    assert_eq!(machine.get_register(Register::BP), 0x0200);
+}
@@ -0,0 +1,163 @@
+use nvm::instruction::Instruction;
+use nvm::Machine;
+use nvm::cpu::CpuModel;
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Flag::ZERO = true)]
+fn test_stc_clc(mut machine: Machine) {
+    // STC
+    machine.run_instruction(Instruction::Stc).unwrap();
+    assert!(machine.get_flag(Flag::CARRY));
+
+    // CLC
+    machine.run_instruction(Instruction::Clc).unwrap();
+    assert!(!machine.get_flag(Flag::CARRY));
+
+    assert!(machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+fn test_cmc(mut machine: Machine) {
+    // CMC
+    machine.run_instruction(Instruction::Cmc).unwrap();
+    assert!(machine.get_flag(Flag::CARRY));
+
+    // CMC
+    machine.run_instruction(Instruction::Cmc).unwrap();
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+fn test_std_cld(mut machine: Machine) {
+    // STD
+    machine.run_instruction(Instruction::Std).unwrap();
+    assert!(machine.get_flag(Flag::DIRECTION));
+
+    // CLD
+    machine.run_instruction(Instruction::Cld).unwrap();
+    assert!(!machine.get_flag(Flag::DIRECTION));
+}
+
+#[machine_test]
+fn test_sti_cli(mut machine: Machine) {
+    // STI
+    machine.run_instruction(Instruction::Sti).unwrap();
+    assert!(machine.get_flag(Flag::INTERRUPT));
+
+    // CLI
+    machine.run_instruction(Instruction::Cli).unwrap();
+    assert!(!machine.get_flag(Flag::INTERRUPT));
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x0100)]
+#[machine_state(Flag::CARRY = true)]
+#[machine_state(Flag::OVERFLOW = true)]
+fn test_pushf(mut machine: Machine) {
+    // PUSHF
+    machine.run_instruction(Instruction::Pushf).unwrap();
+
+    assert_eq!(machine.get_register(Register::SP), 0x00FE);
+    assert_eq!(machine.memory().read_word(0x00FE), 0xF803);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x00FE)]
+#[machine_state(0xFE = 0xC1)]
+#[machine_state(0xFF = 0x04)]
+fn test_popf(mut machine: Machine) {
+    // POPF
+    machine.run_instruction(Instruction::Popf).unwrap();
+
+    assert_eq!(machine.get_register(Register::SP), 0x0100);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(machine.get_flag(Flag::DIRECTION));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x00FE)]
+#[machine_state(0xFE = 0xFF)]
+#[machine_state(0xFF = 0xFF)]
+fn test_popf_masks_reserved_bits(mut machine: Machine) {
+    // POPF
+    machine.run_instruction(Instruction::Popf).unwrap();
+
+    assert_eq!(machine.get_register(Register::F), 0xFFD7);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x00FE)]
+#[machine_state(0xFE = 0xFF)]
+#[machine_state(0xFF = 0xFF)]
+fn test_popf_reserved_bits_on_8086(mut machine: Machine) {
+    machine.set_cpu_model(CpuModel::I8086);
+
+    // POPF
+    // PUSHF
+    machine.run_instruction(Instruction::Popf).unwrap();
+    machine.run_instruction(Instruction::Pushf).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x00FE), 0xFFD7);
+
+    // POPF
+    machine.memory_mut().write_word(0x00FE, 0x0000);
+    machine.run_instruction(Instruction::Popf).unwrap();
+
+    assert_eq!(machine.get_register(Register::F), 0xF002);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x00FC)]
+fn test_popf_reserved_bits_on_80186(mut machine: Machine) {
+    machine.memory_mut().write_word(0x00FC, 0x0000);
+
+    // POPF
+    // PUSHF
+    machine.run_instruction(Instruction::Popf).unwrap();
+    machine.run_instruction(Instruction::Pushf).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x00FC), 0xF002);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x00FE)]
+#[machine_state(0xFE = 0xFF)]
+#[machine_state(0xFF = 0xFF)]
+fn test_popf_iopl_and_nt_on_80386(mut machine: Machine) {
+    machine.set_cpu_model(CpuModel::I80386);
+
+    // POPF
+    machine.run_instruction(Instruction::Popf).unwrap();
+
+    assert_eq!(machine.get_register(Register::F), 0x7FD7);
+
+    // POPF
+    machine.memory_mut().write_word(0x00FE, 0x0000);
+    machine.set_register(Register::SP, 0x00FE);
+    machine.run_instruction(Instruction::Popf).unwrap();
+
+    assert_eq!(machine.get_register(Register::F), 0x0002);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x0100)]
+#[machine_state(Flag::PARITY = true)]
+#[machine_state(Flag::TRAP = true)]
+fn test_pushf_popf_round_trip(mut machine: Machine) {
+    let flags = machine.get_register(Register::F);
+
+    // PUSHF
+    // STC
+    // POPF
+    machine.run_instruction(Instruction::Pushf).unwrap();
+    machine.run_instruction(Instruction::Stc).unwrap();
+    machine.run_instruction(Instruction::Popf).unwrap();
+
+    assert_eq!(machine.get_register(Register::F), flags);
+    assert_eq!(machine.get_register(Register::SP), 0x0100);
+}
@@ -0,0 +1,15 @@
+use nvm::instruction::Instruction;
+use nvm::Machine;
+use nvm::register::Register;
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::IP = 0x0010)]
+fn test_hlt(mut machine: Machine) {
+    assert!(!machine.is_halted());
+
+    machine.run_instruction(Instruction::Hlt).unwrap();
+
+    assert!(machine.is_halted());
+    assert_eq!(machine.get_register(Register::IP), 0x0010);
+}
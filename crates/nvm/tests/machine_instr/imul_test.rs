@@ -0,0 +1,164 @@
+use nvm::instruction::Instruction;
+use nvm::Machine;
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::AL = 0xFE)]
+#[machine_state(Register::CL = 0x03)]
+fn test_imul_8_negative_fits(mut machine: Machine) {
+    // IMUL CL
+    machine.run_instruction(Instruction::Imul8(Operand::Register(Register::CL))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX) as i16, -6);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x80)]
+#[machine_state(Register::CL = 0x02)]
+fn test_imul_8_sets_carry_and_overflow_when_ah_significant(mut machine: Machine) {
+    // IMUL CL
+    machine.run_instruction(Instruction::Imul8(Operand::Register(Register::CL))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX) as i16, -256);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0xFFFF)]
+#[machine_state(Register::BX = 0x11)]
+#[machine_state(0x11 = 0xFF)]
+fn test_imul_8_with_mem_negative_by_negative(mut machine: Machine) {
+    // IMUL BYTE [BX]
+    machine.run_instruction(Instruction::Imul8(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        ..Default::default()
+    }))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 1);
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x7FFF)]
+#[machine_state(Register::CX = 0xFFFE)]
+fn test_imul_16_overflows_into_dx(mut machine: Machine) {
+    // IMUL CX
+    machine.run_instruction(Instruction::Imul16(Operand::Register(Register::CX))).unwrap();
+
+    let product = (machine.get_register(Register::DX) as u32) << 16 | machine.get_register(Register::AX) as u32;
+    assert_eq!(product as i32, 0x7FFF * -2);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0xFFF0)]
+#[machine_state(Register::CX = 0x0010)]
+#[machine_state(Register::DX = 0x1234)]
+fn test_imul_16_sign_extends_into_dx(mut machine: Machine) {
+    // IMUL CX
+    machine.run_instruction(Instruction::Imul16(Operand::Register(Register::CX))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX) as i16, -256);
+    assert_eq!(machine.get_register(Register::DX), 0xFFFF);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0xFFF9)]
+#[machine_state(Register::CL = 0x02)]
+fn test_idiv_8_rounds_towards_zero(mut machine: Machine) {
+    // IDIV CL, -7 / 2
+    machine.run_instruction(Instruction::Idiv8(Operand::Register(Register::CL))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL) as u8 as i8, -3);
+    assert_eq!(machine.get_register(Register::AH) as u8 as i8, -1);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0007)]
+#[machine_state(Register::CL = 0xFE)]
+fn test_idiv_8_negative_divisor(mut machine: Machine) {
+    // IDIV CL, 7 / -2
+    machine.run_instruction(Instruction::Idiv8(Operand::Register(Register::CL))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL) as u8 as i8, -3);
+    assert_eq!(machine.get_register(Register::AH) as u8 as i8, 1);
+}
+
+#[machine_test]
+#[machine_state(Register::DX = 0xFFFF)]
+#[machine_state(Register::AX = 0x0000)]
+#[machine_state(Register::BX = 0x11)]
+#[machine_state(0x11 = 0x00)]
+#[machine_state(0x11 + 1 = 0x01)]
+fn test_idiv_16_with_mem(mut machine: Machine) {
+    // IDIV WORD [BX], -65536 / 256
+    machine.run_instruction(Instruction::Idiv16(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        ..Default::default()
+    }))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX) as i16, -256);
+    assert_eq!(machine.get_register(Register::DX), 0);
+}
+
+#[machine_test]
+#[machine_state(Register::DX = 0xFFFF)]
+#[machine_state(Register::AX = 0xFFF3)]
+#[machine_state(Register::CX = 0x0004)]
+fn test_idiv_16_remainder_has_sign_of_dividend(mut machine: Machine) {
+    // IDIV CX, -13 / 4
+    machine.run_instruction(Instruction::Idiv16(Operand::Register(Register::CX))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX) as i16, -3);
+    assert_eq!(machine.get_register(Register::DX) as i16, -1);
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x0010)]
+#[machine_state(Register::DX = 0xAAAA)]
+fn test_imul_imm8_sign_extended(mut machine: Machine) {
+    // IMUL AX, BX, -3
+    machine.run_instruction(Instruction::ImulImm(
+        Register::AX,
+        Operand::Register(Register::BX),
+        Operand::Immediate8(0xFD),
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX) as i16, -48);
+    // only the destination is written, DX is left alone
+    assert_eq!(machine.get_register(Register::DX), 0xAAAA);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x0010)]
+#[machine_state(0x14 = 0x00)]
+#[machine_state(0x15 = 0x01)]
+fn test_imul_imm16_memory_overflow(mut machine: Machine) {
+    // IMUL CX, [BX + 0x04], 0x0100
+    machine.run_instruction(Instruction::ImulImm(
+        Register::CX,
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0x04,
+            displacement_size: 1,
+            segment: None,
+        }),
+        Operand::Immediate16(0x0100),
+    )).unwrap();
+
+    // 0x100 * 0x100 = 0x10000 is truncated to 16 bits
+    assert_eq!(machine.get_register(Register::CX), 0x0000);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::OVERFLOW));
+}
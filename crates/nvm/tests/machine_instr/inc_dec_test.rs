@@ -0,0 +1,86 @@
+use nvm::Machine;
+use nvm::instruction::Instruction;
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::AX = 0x12FF)]
+#[machine_state(Flag::CARRY = true)]
+fn test_inc_8bit_register_wraps_without_touching_carry(mut machine: Machine) {
+    // INC AL
+    machine.run_instruction(Instruction::Inc8(Operand::Register(Register::AL))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x1200);
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+    assert!(machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x10)]
+#[machine_state(0x10 = 0x7F)]
+#[machine_state(0x11 = 0xAA)]
+fn test_inc_8bit_memory(mut machine: Machine) {
+    // INC BYTE [BX]
+    machine.run_instruction(Instruction::Inc8(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0,
+        displacement_size: 0,
+        segment: None,
+    }))).unwrap();
+
+    assert_eq!(machine.memory().data[0x10], 0x80);
+    assert_eq!(machine.memory().data[0x11], 0xAA);
+    assert!(machine.get_flag(Flag::OVERFLOW));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x10)]
+#[machine_state(0x10 = 0xFF)]
+#[machine_state(0x11 = 0x00)]
+fn test_inc_16bit_memory(mut machine: Machine) {
+    // INC WORD [BX]
+    machine.run_instruction(Instruction::Inc16(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0,
+        displacement_size: 0,
+        segment: None,
+    }))).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x10), 0x0100);
+    assert!(!machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x10)]
+#[machine_state(0x10 = 0x00)]
+#[machine_state(0x11 = 0x00)]
+fn test_dec_16bit_memory_wraps_without_touching_carry(mut machine: Machine) {
+    // DEC WORD [BX]
+    machine.run_instruction(Instruction::Dec16(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0,
+        displacement_size: 0,
+        segment: None,
+    }))).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x10), 0xFFFF);
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::CX = 0x0101)]
+fn test_dec_8bit_register(mut machine: Machine) {
+    // DEC CH
+    machine.run_instruction(Instruction::Dec8(Operand::Register(Register::CH))).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 0x0001);
+    assert!(machine.get_flag(Flag::ZERO));
+}
@@ -0,0 +1,98 @@
+use nvm::error::MachineError;
+use nvm::instruction::Instruction;
+use nvm::Machine;
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+fn install_handler(machine: &mut Machine, vector: u8, segment: u16, offset: u16) {
+    let entry = vector as usize * 4;
+    machine.memory_mut().write_word(entry, offset);
+    machine.memory_mut().write_word(entry + 2, segment);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0x0042)]
+#[machine_state(Register::CS = 0x0001)]
+#[machine_state(Register::SP = 0x0100)]
+#[machine_state(Flag::INTERRUPT = true)]
+#[machine_state(Flag::TRAP = true)]
+#[machine_state(Flag::CARRY = true)]
+fn test_int_pushes_state_and_jumps_to_handler(mut machine: Machine) {
+    install_handler(&mut machine, 0x21, 0x0300, 0x0020);
+    let flags = machine.get_register(Register::F);
+
+    machine.run_instruction(Instruction::Int(0x21)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CS), 0x0300);
+    assert_eq!(machine.get_register(Register::IP), 0x0020);
+    assert_eq!(machine.get_register(Register::SP), 0x00FA);
+    assert_eq!(machine.memory().read_word(0x00FE), flags);
+    assert_eq!(machine.memory().read_word(0x00FC), 0x0001);
+    assert_eq!(machine.memory().read_word(0x00FA), 0x0042);
+
+    assert!(!machine.get_flag(Flag::INTERRUPT));
+    assert!(!machine.get_flag(Flag::TRAP));
+    assert!(machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+fn test_int_without_handler(mut machine: Machine) {
+    let sp = machine.get_register(Register::SP);
+
+    let result = machine.run_instruction(Instruction::Int(0x80));
+
+    assert_eq!(result, Err(MachineError::UnhandledInterrupt(0x80)));
+    assert_eq!(machine.get_register(Register::SP), sp);
+}
+
+#[machine_test]
+fn test_int3_uses_breakpoint_vector(mut machine: Machine) {
+    install_handler(&mut machine, 3, 0x0000, 0x0400);
+
+    machine.run_instruction(Instruction::Int3).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x0400);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0x0042)]
+#[machine_state(Flag::OVERFLOW = false)]
+fn test_into_without_overflow_does_nothing(mut machine: Machine) {
+    let sp = machine.get_register(Register::SP);
+
+    machine.run_instruction(Instruction::Into).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x0042);
+    assert_eq!(machine.get_register(Register::SP), sp);
+}
+
+#[machine_test]
+#[machine_state(Flag::OVERFLOW = true)]
+fn test_into_with_overflow_uses_overflow_vector(mut machine: Machine) {
+    install_handler(&mut machine, 4, 0x0010, 0x0004);
+
+    machine.run_instruction(Instruction::Into).unwrap();
+
+    assert_eq!(machine.get_register(Register::CS), 0x0010);
+    assert_eq!(machine.get_register(Register::IP), 0x0004);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0x0042)]
+#[machine_state(Register::CS = 0x0001)]
+#[machine_state(Flag::INTERRUPT = true)]
+#[machine_state(Flag::ZERO = true)]
+fn test_iret_restores_int_state(mut machine: Machine) {
+    install_handler(&mut machine, 0x10, 0x0300, 0x0020);
+    let sp = machine.get_register(Register::SP);
+    let flags = machine.get_register(Register::F);
+
+    machine.run_instruction(Instruction::Int(0x10)).unwrap();
+    machine.set_flag(Flag::ZERO, false);
+    machine.run_instruction(Instruction::Iret).unwrap();
+
+    assert_eq!(machine.get_register(Register::CS), 0x0001);
+    assert_eq!(machine.get_register(Register::IP), 0x0042);
+    assert_eq!(machine.get_register(Register::SP), sp);
+    assert_eq!(machine.get_register(Register::F), flags);
+}
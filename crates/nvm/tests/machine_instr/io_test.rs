@@ -0,0 +1,229 @@
+use nvm::instruction::{Instruction, IoPort, RepPrefix};
+use nvm::io::IoBus;
+use nvm::Machine;
+use nvm::register::Register;
+use nvm_test_utils::{machine_state, machine_test};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// Test device with 16 byte wide register file, written bytes can be read back
+struct RegisterFileDevice {
+    base: u16,
+    registers: Rc<RefCell<[u8; 16]>>,
+}
+
+impl RegisterFileDevice {
+    fn attach(machine: &mut Machine, base: u16) -> Rc<RefCell<[u8; 16]>> {
+        let registers = Rc::new(RefCell::new([0; 16]));
+        machine.attach_io_device(
+            base..=base + 15,
+            Box::new(RegisterFileDevice {
+                base,
+                registers: registers.clone(),
+            }),
+        );
+        registers
+    }
+}
+
+impl IoBus for RegisterFileDevice {
+    fn read_byte(&mut self, port: u16) -> u8 {
+        self.registers.borrow()[(port - self.base) as usize % 16]
+    }
+
+    fn write_byte(&mut self, port: u16, value: u8) {
+        self.registers.borrow_mut()[(port - self.base) as usize % 16] = value;
+    }
+}
+
+// Test device answering whole words on its own
+struct WordDevice;
+
+impl IoBus for WordDevice {
+    fn read_byte(&mut self, _port: u16) -> u8 {
+        0x11
+    }
+
+    fn write_byte(&mut self, _port: u16, _value: u8) {}
+
+    fn read_word(&mut self, port: u16) -> u16 {
+        port ^ 0xFFFF
+    }
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x42)]
+fn test_out_imm_8bit(mut machine: Machine) {
+    let registers = RegisterFileDevice::attach(&mut machine, 0x60);
+
+    // OUT 0x61, AL
+    machine.run_instruction(Instruction::Out(IoPort::Immediate(0x61), true)).unwrap();
+
+    assert_eq!(registers.borrow()[1], 0x42);
+}
+
+#[machine_test]
+#[machine_state(Register::DX = 0x03F8)]
+#[machine_state(Register::AX = 0xBEEF)]
+fn test_out_dx_16bit_splits_into_bytes(mut machine: Machine) {
+    let registers = RegisterFileDevice::attach(&mut machine, 0x03F8);
+
+    // OUT DX, AX
+    machine.run_instruction(Instruction::Out(IoPort::DX, false)).unwrap();
+
+    assert_eq!(registers.borrow()[0], 0xEF);
+    assert_eq!(registers.borrow()[1], 0xBE);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0xFFFF)]
+fn test_in_imm_8bit(mut machine: Machine) {
+    let registers = RegisterFileDevice::attach(&mut machine, 0x60);
+    registers.borrow_mut()[4] = 0x24;
+
+    // IN AL, 0x64
+    machine.run_instruction(Instruction::In(IoPort::Immediate(0x64), true)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0xFF24);
+}
+
+#[machine_test]
+#[machine_state(Register::DX = 0x0300)]
+fn test_in_dx_16bit_uses_device_word_access(mut machine: Machine) {
+    machine.attach_io_device(0x0300..=0x0301, Box::new(WordDevice));
+
+    // IN AX, DX
+    machine.run_instruction(Instruction::In(IoPort::DX, false)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0xFCFF);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0xBEEF)]
+fn test_out_16bit_across_two_devices(mut machine: Machine) {
+    let low_registers = RegisterFileDevice::attach(&mut machine, 0x60);
+    let high_registers = RegisterFileDevice::attach(&mut machine, 0x70);
+
+    // OUT 0x6F, AX
+    machine.run_instruction(Instruction::Out(IoPort::Immediate(0x6F), false)).unwrap();
+
+    assert_eq!(low_registers.borrow()[15], 0xEF);
+    assert_eq!(high_registers.borrow()[0], 0xBE);
+    assert_eq!(low_registers.borrow()[0], 0x00);
+}
+
+#[machine_test]
+#[machine_state(Register::DX = 0x0300)]
+fn test_in_16bit_with_unclaimed_high_port(mut machine: Machine) {
+    machine.attach_io_device(0x0300..=0x0300, Box::new(WordDevice));
+
+    // IN AX, DX
+    machine.run_instruction(Instruction::In(IoPort::DX, false)).unwrap();
+
+    // the device only answers the low byte, the high port floats
+    assert_eq!(machine.get_register(Register::AX), 0xFF11);
+}
+
+#[machine_test]
+#[machine_state(Register::DX = 0x1234)]
+fn test_in_from_unclaimed_port(mut machine: Machine) {
+    RegisterFileDevice::attach(&mut machine, 0x60);
+
+    // IN AL, DX
+    machine.run_instruction(Instruction::In(IoPort::DX, true)).unwrap();
+    assert_eq!(machine.get_register(Register::AL), 0xFF);
+
+    // IN AX, DX
+    machine.run_instruction(Instruction::In(IoPort::DX, false)).unwrap();
+    assert_eq!(machine.get_register(Register::AX), 0xFFFF);
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x42)]
+fn test_out_to_unclaimed_port_is_ignored(mut machine: Machine) {
+    let registers = RegisterFileDevice::attach(&mut machine, 0x60);
+
+    // OUT 0x80, AL
+    machine.run_instruction(Instruction::Out(IoPort::Immediate(0x80), true)).unwrap();
+
+    assert_eq!(*registers.borrow(), [0; 16]);
+}
+
+#[machine_test]
+fn test_first_attached_device_wins(mut machine: Machine) {
+    let registers = RegisterFileDevice::attach(&mut machine, 0x60);
+    machine.attach_io_device(0x60..=0x60, Box::new(WordDevice));
+    registers.borrow_mut()[0] = 0x77;
+
+    assert_eq!(machine.read_io_byte(0x60), 0x77);
+}
+
+#[machine_test]
+fn test_step_in_out_program(mut machine: Machine) {
+    let registers = RegisterFileDevice::attach(&mut machine, 0x60);
+    // MOV AL, 0x5A
+    // OUT 0x62, AL
+    // MOV DX, 0x0062
+    // IN AL, DX
+    // INC AX
+    // OUT DX, AL
+    machine.load_program_bytes(&[0xB0, 0x5A, 0xE6, 0x62, 0xBA, 0x62, 0x00, 0xEC, 0x40, 0xEE]).unwrap();
+
+    for _ in 0..6 {
+        machine.step().unwrap();
+    }
+
+    assert_eq!(registers.borrow()[2], 0x5B);
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 10);
+}
+
+#[machine_test]
+#[machine_state(Register::DX = 0x0060)]
+#[machine_state(Register::DI = 0x0100)]
+fn test_insb(mut machine: Machine) {
+    let registers = RegisterFileDevice::attach(&mut machine, 0x60);
+    registers.borrow_mut()[0] = 0xAB;
+
+    // INSB
+    machine.run_instruction(Instruction::Ins(None, None, true)).unwrap();
+
+    assert_eq!(machine.memory().data[0x0100], 0xAB);
+    assert_eq!(machine.get_register(Register::DI), 0x0101);
+}
+
+#[machine_test]
+#[machine_state(Register::DX = 0x0060)]
+#[machine_state(Register::SI = 0x0100)]
+#[machine_state(Register::CX = 0x0002)]
+#[machine_state(0x0100 = 0x34)]
+#[machine_state(0x0101 = 0x12)]
+#[machine_state(0x0102 = 0x78)]
+#[machine_state(0x0103 = 0x56)]
+fn test_rep_outsw(mut machine: Machine) {
+    let registers = RegisterFileDevice::attach(&mut machine, 0x60);
+
+    // REP OUTSW, executed until CX runs out
+    while machine.get_register(Register::CX) != 0 {
+        machine.run_instruction(Instruction::Outs(Some(RepPrefix::Rep), None, false)).unwrap();
+    }
+
+    // the second word overwrote the first one on the same port
+    assert_eq!(registers.borrow()[0], 0x78);
+    assert_eq!(registers.borrow()[1], 0x56);
+    assert_eq!(machine.get_register(Register::SI), 0x0104);
+}
+
+#[machine_test]
+#[machine_state(Register::DX = 0x0060)]
+#[machine_state(Register::SI = 0x0010)]
+#[machine_state(Register::ES = 0x0010)]
+#[machine_state(0x0110 = 0x42)]
+fn test_outsb_with_segment_override(mut machine: Machine) {
+    let registers = RegisterFileDevice::attach(&mut machine, 0x60);
+
+    // OUTSB ES:[SI]
+    machine.run_instruction(Instruction::Outs(None, Some(Register::ES), true)).unwrap();
+
+    assert_eq!(registers.borrow()[0], 0x42);
+    assert_eq!(machine.get_register(Register::SI), 0x0011);
+}
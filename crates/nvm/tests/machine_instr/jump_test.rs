@@ -1,5 +1,6 @@
 use nvm::Machine;
 use nvm::instruction::Instruction;
+use nvm::modrm::{MemAddress, Operand};
 use nvm::register::Flag;
 use nvm::register::Register;
 use nvm_test_utils::{machine_state, machine_test};
@@ -7,7 +8,7 @@ use nvm_test_utils::{machine_state, machine_test};
 #[machine_test]
 #[machine_state(Register::IP = 0xFF)]
 fn test_jmp_near_forward(mut machine: Machine) {
-    machine.run_instruction(Instruction::JmpNear(20));
+    machine.run_instruction(Instruction::JmpNear(20)).unwrap();
 
     assert_eq!(machine.get_register(Register::IP), 0xFF + 20);
 }
@@ -15,7 +16,7 @@ fn test_jmp_near_forward(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::IP = 0xFF)]
 fn test_jmp_near_backward(mut machine: Machine) {
-    machine.run_instruction(Instruction::JmpNear(-20));
+    machine.run_instruction(Instruction::JmpNear(-20)).unwrap();
 
     assert_eq!(machine.get_register(Register::IP), 0xFF - 20);
 }
@@ -23,7 +24,7 @@ fn test_jmp_near_backward(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::IP = 0xFF)]
 fn test_jmp_short_near_forward(mut machine: Machine) {
-    machine.run_instruction(Instruction::JmpShort(20));
+    machine.run_instruction(Instruction::JmpShort(20)).unwrap();
 
     assert_eq!(machine.get_register(Register::IP), 0xFF + 20);
 }
@@ -31,7 +32,7 @@ fn test_jmp_short_near_forward(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::IP = 0xFF)]
 fn test_jmp_short_near_backward(mut machine: Machine) {
-    machine.run_instruction(Instruction::JmpShort(-20));
+    machine.run_instruction(Instruction::JmpShort(-20)).unwrap();
 
     assert_eq!(machine.get_register(Register::IP), 0xFF - 20);
 }
@@ -39,7 +40,7 @@ fn test_jmp_short_near_backward(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::IP = 0xFF)]
 fn test_jmp_far(mut machine: Machine) {
-    machine.run_instruction(Instruction::JmpFar(0, 20));
+    machine.run_instruction(Instruction::JmpFar(0, 20)).unwrap();
 
     assert_eq!(machine.get_register(Register::IP), 20);
 }
@@ -47,7 +48,7 @@ fn test_jmp_far(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::IP = 0xFF)]
 fn test_jmp_far_with_segment(mut machine: Machine) {
-    machine.run_instruction(Instruction::JmpFar(2, 20));
+    machine.run_instruction(Instruction::JmpFar(2, 20)).unwrap();
 
     assert_eq!(machine.get_register(Register::IP), 20);
     assert_eq!(machine.get_register(Register::CS), 2);
@@ -57,7 +58,7 @@ fn test_jmp_far_with_segment(mut machine: Machine) {
 #[machine_state(Register::IP = 0xFF)]
 #[machine_state(Flag::ZERO = false)]
 fn test_jz_fail(mut machine: Machine) {
-    machine.run_instruction(Instruction::Jz(0x20));
+    machine.run_instruction(Instruction::Jz(0x20)).unwrap();
 
     assert_eq!(machine.get_register(Register::IP), 0xFF);
 }
@@ -66,7 +67,7 @@ fn test_jz_fail(mut machine: Machine) {
 #[machine_state(Register::IP = 0xFF)]
 #[machine_state(Flag::ZERO = true)]
 fn test_jz(mut machine: Machine) {
-    machine.run_instruction(Instruction::Jz(0x20));
+    machine.run_instruction(Instruction::Jz(0x20)).unwrap();
 
     assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
 }
@@ -75,7 +76,7 @@ fn test_jz(mut machine: Machine) {
 #[machine_state(Register::IP = 0xFF)]
 #[machine_state(Flag::ZERO = true)]
 fn test_jnz_fail(mut machine: Machine) {
-    machine.run_instruction(Instruction::Jnz(0x20));
+    machine.run_instruction(Instruction::Jnz(0x20)).unwrap();
 
     assert_eq!(machine.get_register(Register::IP), 0xFF);
 }
@@ -84,7 +85,334 @@ fn test_jnz_fail(mut machine: Machine) {
 #[machine_state(Register::IP = 0xFF)]
 #[machine_state(Flag::ZERO = false)]
 fn test_jnz(mut machine: Machine) {
-    machine.run_instruction(Instruction::Jnz(0x20));
+    machine.run_instruction(Instruction::Jnz(0x20)).unwrap();
 
     assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
 }
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::OVERFLOW = false)]
+fn test_jo_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jo(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::OVERFLOW = true)]
+fn test_jo(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jo(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::OVERFLOW = true)]
+fn test_jno_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jno(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::OVERFLOW = false)]
+fn test_jno(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jno(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::CARRY = false)]
+fn test_jc_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jc(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::CARRY = true)]
+fn test_jc(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jc(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::CARRY = true)]
+fn test_jnc_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jnc(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::CARRY = false)]
+fn test_jnc(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jnc(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::CARRY = false)]
+#[machine_state(Flag::ZERO = false)]
+fn test_jbe_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jbe(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::CARRY = false)]
+#[machine_state(Flag::ZERO = true)]
+fn test_jbe(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jbe(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::CARRY = true)]
+#[machine_state(Flag::ZERO = false)]
+fn test_ja_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Ja(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::CARRY = false)]
+#[machine_state(Flag::ZERO = false)]
+fn test_ja(mut machine: Machine) {
+    machine.run_instruction(Instruction::Ja(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::SIGN = false)]
+fn test_js_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Js(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::SIGN = true)]
+fn test_js(mut machine: Machine) {
+    machine.run_instruction(Instruction::Js(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::SIGN = true)]
+fn test_jns_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jns(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::SIGN = false)]
+fn test_jns(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jns(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::PARITY = false)]
+fn test_jp_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jp(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::PARITY = true)]
+fn test_jp(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jp(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::PARITY = true)]
+fn test_jnp_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jnp(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::PARITY = false)]
+fn test_jnp(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jnp(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::SIGN = true)]
+#[machine_state(Flag::OVERFLOW = true)]
+fn test_jl_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jl(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::SIGN = true)]
+#[machine_state(Flag::OVERFLOW = false)]
+fn test_jl(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jl(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::SIGN = false)]
+#[machine_state(Flag::OVERFLOW = true)]
+fn test_jge_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jge(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::SIGN = true)]
+#[machine_state(Flag::OVERFLOW = true)]
+fn test_jge(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jge(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::ZERO = false)]
+#[machine_state(Flag::SIGN = false)]
+#[machine_state(Flag::OVERFLOW = false)]
+fn test_jle_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jle(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::ZERO = true)]
+#[machine_state(Flag::SIGN = false)]
+#[machine_state(Flag::OVERFLOW = false)]
+fn test_jle(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jle(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::ZERO = false)]
+#[machine_state(Flag::SIGN = true)]
+#[machine_state(Flag::OVERFLOW = false)]
+fn test_jg_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jg(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::ZERO = false)]
+#[machine_state(Flag::SIGN = true)]
+#[machine_state(Flag::OVERFLOW = true)]
+fn test_jg(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jg(0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x20);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Flag::CARRY = true)]
+fn test_jc_backward(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jc(-0x20)).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0xFF - 0x20);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Register::BX = 0x0420)]
+fn test_jmp_near_indirect_register(mut machine: Machine) {
+    // JMP BX
+    machine.run_instruction(Instruction::JmpNearIndirect(Operand::Register(Register::BX))).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x0420);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Register::BX = 0x0010)]
+#[machine_state(Register::SI = 0x0002)]
+#[machine_state(0x12 = 0x34)]
+#[machine_state(0x13 = 0x02)]
+fn test_jmp_near_indirect_memory(mut machine: Machine) {
+    // JMP [BX + SI]
+    machine.run_instruction(Instruction::JmpNearIndirect(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: Some(Register::SI),
+        displacement: 0,
+        displacement_size: 0,
+        segment: None,
+    }))).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x0234);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Register::BX = 0x0010)]
+#[machine_state(0x10 = 0x34)]
+#[machine_state(0x11 = 0x02)]
+#[machine_state(0x12 = 0x00)]
+#[machine_state(0x13 = 0x10)]
+fn test_jmp_far_indirect(mut machine: Machine) {
+    // JMP FAR [BX]
+    machine.run_instruction(Instruction::JmpFarIndirect(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0,
+        displacement_size: 0,
+        segment: None,
+    }))).unwrap();
+
+    assert_eq!(machine.get_register(Register::IP), 0x0234);
+    assert_eq!(machine.get_register(Register::CS), 0x1000);
+}
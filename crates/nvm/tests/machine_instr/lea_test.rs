@@ -0,0 +1,79 @@
+use nvm::instruction::Instruction;
+use nvm::Machine;
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::Register;
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::BX = 0x0100)]
+#[machine_state(Register::SI = 0x0020)]
+#[machine_state(Register::DS = 0x1000)]
+fn test_lea(mut machine: Machine) {
+    // LEA AX, [BX + SI + 0x05]
+    machine.run_instruction(Instruction::Lea(Register::AX, Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: Some(Register::SI),
+        displacement: 0x05,
+        displacement_size: 1,
+        segment: None,
+    }))).unwrap();
+
+    // offset only, DS is not applied
+    assert_eq!(machine.get_register(Register::AX), 0x0125);
+}
+
+#[machine_test]
+#[machine_state(Register::BP = 0xFFFF)]
+fn test_lea_wraps_offset(mut machine: Machine) {
+    // LEA DI, [BP + 0x02]
+    machine.run_instruction(Instruction::Lea(Register::DI, Operand::Memory(MemAddress {
+        base: Some(Register::BP),
+        index: None,
+        displacement: 0x02,
+        displacement_size: 1,
+        segment: None,
+    }))).unwrap();
+
+    assert_eq!(machine.get_register(Register::DI), 0x0001);
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x0010)]
+#[machine_state(0x10 = 0x34)]
+#[machine_state(0x11 = 0x12)]
+#[machine_state(0x12 = 0x00)]
+#[machine_state(0x13 = 0xB8)]
+fn test_lds(mut machine: Machine) {
+    // LDS SI, [BX]
+    machine.run_instruction(Instruction::Lds(Register::SI, Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0,
+        displacement_size: 0,
+        segment: None,
+    }))).unwrap();
+
+    assert_eq!(machine.get_register(Register::SI), 0x1234);
+    assert_eq!(machine.get_register(Register::DS), 0xB800);
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x0010)]
+#[machine_state(0x10 = 0x78)]
+#[machine_state(0x11 = 0x56)]
+#[machine_state(0x12 = 0x00)]
+#[machine_state(0x13 = 0xA0)]
+fn test_les_into_base_register(mut machine: Machine) {
+    // LES BX, [BX]
+    machine.run_instruction(Instruction::Les(Register::BX, Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0,
+        displacement_size: 0,
+        segment: None,
+    }))).unwrap();
+
+    assert_eq!(machine.get_register(Register::BX), 0x5678);
+    assert_eq!(machine.get_register(Register::ES), 0xA000);
+    assert_eq!(machine.get_register(Register::DS), 0x0000);
+}
@@ -0,0 +1,132 @@
+use nvm::Machine;
+use nvm::instruction::Instruction;
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Register::CX = 3)]
+fn test_loop_jumps_while_cx_not_zero(mut machine: Machine) {
+    machine.run_instruction(Instruction::Loop(-0x10)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 2);
+    assert_eq!(machine.get_register(Register::IP), 0xFF - 0x10);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Register::CX = 1)]
+fn test_loop_falls_through_when_cx_reaches_zero(mut machine: Machine) {
+    machine.run_instruction(Instruction::Loop(-0x10)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 0);
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Register::CX = 0)]
+fn test_loop_wraps_cx_from_zero(mut machine: Machine) {
+    machine.run_instruction(Instruction::Loop(-0x10)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 0xFFFF);
+    assert_eq!(machine.get_register(Register::IP), 0xFF - 0x10);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Register::CX = 1)]
+#[machine_state(Flag::ZERO = false)]
+#[machine_state(Flag::CARRY = true)]
+fn test_loop_does_not_touch_flags(mut machine: Machine) {
+    machine.run_instruction(Instruction::Loop(0x10)).unwrap();
+
+    assert!(!machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Register::CX = 2)]
+#[machine_state(Flag::ZERO = true)]
+fn test_loopz_pass(mut machine: Machine) {
+    machine.run_instruction(Instruction::Loopz(0x10)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 1);
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x10);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Register::CX = 2)]
+#[machine_state(Flag::ZERO = false)]
+fn test_loopz_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Loopz(0x10)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 1);
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Register::CX = 0)]
+#[machine_state(Flag::ZERO = true)]
+fn test_loopz_wraps_cx_from_zero(mut machine: Machine) {
+    machine.run_instruction(Instruction::Loopz(0x10)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 0xFFFF);
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x10);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Register::CX = 2)]
+#[machine_state(Flag::ZERO = false)]
+fn test_loopnz_pass(mut machine: Machine) {
+    machine.run_instruction(Instruction::Loopnz(0x10)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 1);
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x10);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Register::CX = 2)]
+#[machine_state(Flag::ZERO = true)]
+fn test_loopnz_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Loopnz(0x10)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 1);
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Register::CX = 1)]
+#[machine_state(Flag::ZERO = false)]
+fn test_loopnz_falls_through_when_cx_reaches_zero(mut machine: Machine) {
+    machine.run_instruction(Instruction::Loopnz(0x10)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 0);
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Register::CX = 0)]
+fn test_jcxz_pass(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jcxz(0x10)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 0);
+    assert_eq!(machine.get_register(Register::IP), 0xFF + 0x10);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0xFF)]
+#[machine_state(Register::CX = 1)]
+fn test_jcxz_fail(mut machine: Machine) {
+    machine.run_instruction(Instruction::Jcxz(0x10)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 1);
+    assert_eq!(machine.get_register(Register::IP), 0xFF);
+}
@@ -1,4 +1,5 @@
 use nvm::instruction::Instruction;
+use nvm::modrm::{MemAddress, Operand};
 use nvm::Machine;
 use nvm::register::Register;
 use nvm_test_utils::{machine_test, machine_state};
@@ -8,7 +9,7 @@ use nvm_test_utils::{machine_test, machine_state};
 #[machine_state(Register::AX = 0xFFBB)]
 fn test_push(mut machine: Machine) {
     // PUSH AX
-    machine.run_instruction(Instruction::Push(Register::AX));
+    machine.run_instruction(Instruction::Push(Register::AX)).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0xFFBB);
     assert_eq!(machine.get_register(Register::SP), 0xAA - 2);
@@ -22,7 +23,7 @@ fn test_push(mut machine: Machine) {
 #[machine_state(0xAA + 1 = 0xAA)]
 fn test_pop(mut machine: Machine) {
     // POP AX
-    machine.run_instruction(Instruction::Pop(Register::AX));
+    machine.run_instruction(Instruction::Pop(Register::AX)).unwrap();
 
     assert_eq!(machine.get_register(Register::SP), 0xAA + 2);
     assert_eq!(machine.get_register(Register::AX), 0xAABB);
@@ -36,11 +37,108 @@ fn test_pop(mut machine: Machine) {
 fn test_push_pop(mut machine: Machine) {
     // PUSH AX
     // POP AX
-    machine.run_instruction(Instruction::Push(Register::AX));
-    machine.run_instruction(Instruction::Pop(Register::AX));
+    machine.run_instruction(Instruction::Push(Register::AX)).unwrap();
+    machine.run_instruction(Instruction::Pop(Register::AX)).unwrap();
 
     assert_eq!(machine.get_register(Register::SP), 0xAA);
     assert_eq!(machine.get_register(Register::AX), 0xFFBB);
     assert_eq!(machine.memory().data[0xAA - 2], 0xBB);
     assert_eq!(machine.memory().data[0xAA - 1], 0xFF);
-}
\ No newline at end of file
+}
+#[machine_test]
+#[machine_state(Register::SP = 0xAA)]
+#[machine_state(Register::BX = 0x10)]
+#[machine_state(0x10 = 0xBB)]
+#[machine_state(0x11 = 0xFF)]
+fn test_push_mem(mut machine: Machine) {
+    // PUSH [BX]
+    machine.run_instruction(Instruction::PushRm(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0,
+        displacement_size: 0,
+        segment: None,
+    }))).unwrap();
+
+    assert_eq!(machine.get_register(Register::SP), 0xA8);
+    assert_eq!(machine.memory().read_word(0xA8), 0xFFBB);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0xAA)]
+#[machine_state(Register::CX = 0x1234)]
+fn test_push_rm_register(mut machine: Machine) {
+    // PUSH CX (FF encoding)
+    machine.run_instruction(Instruction::PushRm(Operand::Register(Register::CX))).unwrap();
+
+    assert_eq!(machine.get_register(Register::SP), 0xA8);
+    assert_eq!(machine.memory().read_word(0xA8), 0x1234);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0xAA)]
+fn test_push_imm(mut machine: Machine) {
+    // PUSH 0x1234
+    // PUSH -2 (imm8)
+    machine.run_instruction(Instruction::PushImm16(0x1234)).unwrap();
+    machine.run_instruction(Instruction::PushImm8(0xFE)).unwrap();
+
+    assert_eq!(machine.get_register(Register::SP), 0xA6);
+    assert_eq!(machine.memory().read_word(0xA8), 0x1234);
+    assert_eq!(machine.memory().read_word(0xA6), 0xFFFE);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x0100)]
+#[machine_state(Register::AX = 0x1111)]
+#[machine_state(Register::CX = 0x2222)]
+#[machine_state(Register::DX = 0x3333)]
+#[machine_state(Register::BX = 0x4444)]
+#[machine_state(Register::BP = 0x5555)]
+#[machine_state(Register::SI = 0x6666)]
+#[machine_state(Register::DI = 0x7777)]
+fn test_pusha(mut machine: Machine) {
+    // PUSHA
+    machine.run_instruction(Instruction::Pusha).unwrap();
+
+    assert_eq!(machine.get_register(Register::SP), 0x00F0);
+    assert_eq!(machine.memory().read_word(0x00FE), 0x1111);
+    assert_eq!(machine.memory().read_word(0x00FC), 0x2222);
+    assert_eq!(machine.memory().read_word(0x00FA), 0x3333);
+    assert_eq!(machine.memory().read_word(0x00F8), 0x4444);
+    assert_eq!(machine.memory().read_word(0x00F6), 0x0100);
+    assert_eq!(machine.memory().read_word(0x00F4), 0x5555);
+    assert_eq!(machine.memory().read_word(0x00F2), 0x6666);
+    assert_eq!(machine.memory().read_word(0x00F0), 0x7777);
+}
+
+#[machine_test]
+#[machine_state(Register::SP = 0x0100)]
+#[machine_state(Register::AX = 0x1111)]
+#[machine_state(Register::CX = 0x2222)]
+#[machine_state(Register::DX = 0x3333)]
+#[machine_state(Register::BX = 0x4444)]
+#[machine_state(Register::BP = 0x5555)]
+#[machine_state(Register::SI = 0x6666)]
+#[machine_state(Register::DI = 0x7777)]
+fn test_pusha_popa_round_trip_skips_sp(mut machine: Machine) {
+    // PUSHA
+    machine.run_instruction(Instruction::Pusha).unwrap();
+    for reg in [Register::AX, Register::CX, Register::DX, Register::BX, Register::BP, Register::SI, Register::DI] {
+        machine.set_register(reg, 0);
+    }
+    // the saved SP is ignored by POPA
+    machine.memory_mut().write_word(0x00F6, 0xAAAA);
+
+    // POPA
+    machine.run_instruction(Instruction::Popa).unwrap();
+
+    assert_eq!(machine.get_register(Register::SP), 0x0100);
+    assert_eq!(machine.get_register(Register::AX), 0x1111);
+    assert_eq!(machine.get_register(Register::CX), 0x2222);
+    assert_eq!(machine.get_register(Register::DX), 0x3333);
+    assert_eq!(machine.get_register(Register::BX), 0x4444);
+    assert_eq!(machine.get_register(Register::BP), 0x5555);
+    assert_eq!(machine.get_register(Register::SI), 0x6666);
+    assert_eq!(machine.get_register(Register::DI), 0x7777);
+}
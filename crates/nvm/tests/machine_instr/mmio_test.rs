@@ -0,0 +1,91 @@
+use nvm::bus::{CallbackDevice, Rom};
+use nvm::instruction::Instruction;
+use nvm::Machine;
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::Register;
+use nvm_test_utils::{machine_state, machine_test};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[machine_test]
+#[machine_state(Register::ES = 0xB800)]
+#[machine_state(Register::DI = 0x0002)]
+#[machine_state(Register::AX = 0x0741)]
+fn test_mov_to_mapped_device(mut machine: Machine) {
+    let writes = Rc::new(RefCell::new(Vec::new()));
+    let log = writes.clone();
+    machine.map_memory_device(0xB8000..0xB8FA0, Box::new(CallbackDevice::new(
+        |_| 0,
+        move |address, value| log.borrow_mut().push((address, value)),
+    )));
+
+    // MOV ES:[DI], AX
+    machine.run_instruction(Instruction::Mov(Operand::Memory(MemAddress {
+        base: None,
+        index: Some(Register::DI),
+        displacement: 0,
+        displacement_size: 0,
+        segment: Some(Register::ES),
+    }), Operand::Register(Register::AX))).unwrap();
+
+    assert_eq!(*writes.borrow(), vec![(0x2, 0x41), (0x3, 0x07)]);
+    assert_eq!(machine.memory().data[0xB8002], 0x00);
+}
+
+#[machine_test]
+#[machine_state(Register::DS = 0xF000)]
+fn test_mov_from_rom(mut machine: Machine) {
+    machine.map_memory_device(0xF0000..0x100000, Box::new(Rom::new(vec![0x34, 0x12])));
+
+    // MOV AX, [0x0000]
+    machine.run_instruction(Instruction::Mov(Operand::Register(Register::AX), Operand::Memory(MemAddress {
+        base: None,
+        index: None,
+        displacement: 0,
+        displacement_size: 2,
+        segment: None,
+    }))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x1234);
+}
+
+#[machine_test]
+#[machine_state(Register::DS = 0xF000)]
+#[machine_state(Register::BX = 0x0001)]
+#[machine_state(Register::CL = 0xAA)]
+fn test_mov_to_rom_is_ignored(mut machine: Machine) {
+    machine.map_memory_device(0xF0000..0x100000, Box::new(Rom::new(vec![0x34, 0x12])));
+
+    // MOV [BX], CL
+    machine.run_instruction(Instruction::Mov(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0,
+        displacement_size: 0,
+        segment: None,
+    }), Operand::Register(Register::CL))).unwrap();
+
+    assert_eq!(machine.read_byte_at(Register::DS, 0x0001).unwrap(), 0x12);
+    assert_eq!(machine.memory().data[0xF0001], 0x00);
+}
+
+#[machine_test]
+fn test_step_does_not_fetch_past_instruction(mut machine: Machine) {
+    let reads = Rc::new(RefCell::new(Vec::new()));
+    let log = reads.clone();
+    // MOV AX, 0x1234 right in front of a mapped device
+    machine.load_program_bytes(&[0xB8, 0x34, 0x12]).unwrap();
+    let device_start = Machine::DEFAULT_LOAD_OFFSET as usize + 3;
+    machine.map_memory_device(device_start..device_start + 0x10, Box::new(CallbackDevice::new(
+        move |address| {
+            log.borrow_mut().push(address);
+            0x90
+        },
+        |_, _| {},
+    )));
+
+    machine.step().unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x1234);
+    assert!(reads.borrow().is_empty());
+}
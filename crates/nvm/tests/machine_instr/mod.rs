@@ -6,4 +6,34 @@ pub mod and_test;
 pub mod or_test;
 pub mod mul_test;
 pub mod div_test;
-pub mod jump_test;
\ No newline at end of file
+pub mod jump_test;
+pub mod xor_test;
+pub mod adc_test;
+pub mod sbb_test;
+pub mod cmp_test;
+pub mod test_test;
+pub mod neg_test;
+pub mod not_test;
+pub mod shift_test;
+pub mod rotate_test;
+pub mod call_test;
+pub mod loop_test;
+pub mod string_test;
+pub mod segment_test;
+pub mod imul_test;
+pub mod divide_error_test;
+pub mod interrupt_test;
+pub mod io_test;
+pub mod mmio_test;
+pub mod hlt_test;
+pub mod lea_test;
+pub mod xchg_test;
+pub mod xlat_test;
+pub mod convert_test;
+pub mod flag_control_test;
+pub mod inc_dec_test;
+pub mod bcd_test;
+pub mod enter_leave_test;
+pub mod bound_test;
+pub mod alu32_test;
+pub mod movx_test;
@@ -8,8 +8,8 @@ use nvm_test_utils::{machine_test, machine_state};
 fn test_mov_8bit(mut machine: Machine) {
     // MOV AL, 0xFF
     // MOV AH, 0x10
-    machine.run_instruction(Instruction::MovImm8(Register::AL, 0xFF));
-    machine.run_instruction(Instruction::MovImm8(Register::AH, 0x10));
+    machine.run_instruction(Instruction::MovImm8(Register::AL, 0xFF)).unwrap();
+    machine.run_instruction(Instruction::MovImm8(Register::AH, 0x10)).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0xFF);
     assert_eq!(machine.get_register(Register::AH), 0x10);
@@ -20,7 +20,7 @@ fn test_mov_8bit(mut machine: Machine) {
 #[machine_test]
 fn test_mov_16bit(mut machine: Machine) {
     // MOV AX, 0xFF10
-    machine.run_instruction(Instruction::MovImm16(Register::AX, 0xFF10));
+    machine.run_instruction(Instruction::MovImm16(Register::AX, 0xFF10)).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0xFF10);
 }
@@ -34,8 +34,8 @@ fn test_mov_reg_to_reg(mut machine: Machine) {
     // 8 bit
     // MOV BL, CL
     // MOV AL, DL
-    machine.run_instruction(Instruction::Mov(Operand::Register(Register::BL), Operand::Register(Register::CL)));
-    machine.run_instruction(Instruction::Mov(Operand::Register(Register::AL), Operand::Register(Register::DL)));
+    machine.run_instruction(Instruction::Mov(Operand::Register(Register::BL), Operand::Register(Register::CL))).unwrap();
+    machine.run_instruction(Instruction::Mov(Operand::Register(Register::AL), Operand::Register(Register::DL))).unwrap();
 
     assert_eq!(machine.get_register(Register::CL), 0xAA);
     assert_eq!(machine.get_register(Register::BL), 0xAA);
@@ -45,8 +45,8 @@ fn test_mov_reg_to_reg(mut machine: Machine) {
     // 16 bit
     // MOV BX, CX
     // MOV AX, DX
-    machine.run_instruction(Instruction::Mov(Operand::Register(Register::BX), Operand::Register(Register::CX)));
-    machine.run_instruction(Instruction::Mov(Operand::Register(Register::AX), Operand::Register(Register::DX)));
+    machine.run_instruction(Instruction::Mov(Operand::Register(Register::BX), Operand::Register(Register::CX))).unwrap();
+    machine.run_instruction(Instruction::Mov(Operand::Register(Register::AX), Operand::Register(Register::DX))).unwrap();
 
     assert_eq!(machine.get_register(Register::CX), 0xAAAA);
     assert_eq!(machine.get_register(Register::BX), 0xAAAA);
@@ -65,7 +65,8 @@ fn test_mov_8bit_reg_to_mem(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0,
         displacement_size: 0,
-    }), Operand::Register(Register::CL)));
+        segment: None,
+    }), Operand::Register(Register::CL))).unwrap();
 
     assert_eq!(machine.get_register(Register::BX), 0xA);
     assert_eq!(machine.get_register(Register::SI), 0xB);
@@ -84,7 +85,8 @@ fn test_mov_16bit_reg_to_mem(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0,
         displacement_size: 0,
-    }), Operand::Register(Register::CX)));
+        segment: None,
+    }), Operand::Register(Register::CX))).unwrap();
 
     assert_eq!(machine.get_register(Register::BX), 0xA);
     assert_eq!(machine.get_register(Register::SI), 0xB);
@@ -104,7 +106,8 @@ fn test_mov_8bit_reg_to_mem_with_1byte_displacement(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0x0C,
         displacement_size: 1,
-    }), Operand::Register(Register::CL)));
+        segment: None,
+    }), Operand::Register(Register::CL))).unwrap();
 
     assert_eq!(machine.get_register(Register::BX), 0xA);
     assert_eq!(machine.get_register(Register::SI), 0xB);
@@ -123,7 +126,8 @@ fn test_mov_16bit_reg_to_mem_with_1byte_displacement(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0x0C,
         displacement_size: 1,
-    }), Operand::Register(Register::CX)));
+        segment: None,
+    }), Operand::Register(Register::CX))).unwrap();
 
     assert_eq!(machine.get_register(Register::BX), 0xA);
     assert_eq!(machine.get_register(Register::SI), 0xB);
@@ -143,7 +147,8 @@ fn test_mov_8bit_reg_to_mem_with_2byte_displacement(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0xD0C,
         displacement_size: 2,
-    }), Operand::Register(Register::CL)));
+        segment: None,
+    }), Operand::Register(Register::CL))).unwrap();
 
     assert_eq!(machine.get_register(Register::BX), 0xA);
     assert_eq!(machine.get_register(Register::SI), 0xB);
@@ -162,7 +167,8 @@ fn test_mov_16bit_reg_to_mem_with_2byte_displacement(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0xD0C,
         displacement_size: 2,
-    }), Operand::Register(Register::CX)));
+        segment: None,
+    }), Operand::Register(Register::CX))).unwrap();
 
     assert_eq!(machine.get_register(Register::BX), 0xA);
     assert_eq!(machine.get_register(Register::SI), 0xB);
@@ -182,7 +188,8 @@ fn test_mov_8bit_mem_to_reg(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0,
         displacement_size: 0,
-    })));
+        segment: None,
+    }))).unwrap();
 
     assert_eq!(machine.get_register(Register::BX), 0xA);
     assert_eq!(machine.get_register(Register::SI), 0xB);
@@ -202,7 +209,8 @@ fn test_mov_16bit_mem_to_reg(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0,
         displacement_size: 0,
-    })));
+        segment: None,
+    }))).unwrap();
 
     assert_eq!(machine.get_register(Register::BX), 0xA);
     assert_eq!(machine.get_register(Register::SI), 0xB);
@@ -222,7 +230,8 @@ fn test_mov_8bit_mem_to_reg_with_1byte_displacement(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0x0C,
         displacement_size: 1,
-    })));
+        segment: None,
+    }))).unwrap();
 
     assert_eq!(machine.get_register(Register::BX), 0xA);
     assert_eq!(machine.get_register(Register::SI), 0xB);
@@ -242,7 +251,8 @@ fn test_mov_16bit_mem_to_reg_1byte_displacement(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0x0C,
         displacement_size: 1,
-    })));
+        segment: None,
+    }))).unwrap();
 
     assert_eq!(machine.get_register(Register::BX), 0xA);
     assert_eq!(machine.get_register(Register::SI), 0xB);
@@ -262,7 +272,8 @@ fn test_mov_8bit_mem_to_reg_with_2byte_displacement(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0xD0C,
         displacement_size: 2,
-    })));
+        segment: None,
+    }))).unwrap();
 
     assert_eq!(machine.get_register(Register::BX), 0xA);
     assert_eq!(machine.get_register(Register::SI), 0xB);
@@ -282,7 +293,8 @@ fn test_mov_16bit_mem_to_reg_2byte_displacement(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0xD0C,
         displacement_size: 2,
-    })));
+        segment: None,
+    }))).unwrap();
 
     assert_eq!(machine.get_register(Register::BX), 0xA);
     assert_eq!(machine.get_register(Register::SI), 0xB);
@@ -300,32 +312,36 @@ fn test_mov_8bit_mem_to_reg_and_backwards_keeping_all_values_same(mut machine: M
     // MOV CL, [BX + SI + 0xD0C]
     // MOV [BX + SI + 0xD0C], CL
     // MOV [BX + SI + 0xD0C], CL
-    machine.run_instruction(Instruction::MovImm16(Register::BX, 0xA));
-    machine.run_instruction(Instruction::MovImm16(Register::SI, 0xB));
+    machine.run_instruction(Instruction::MovImm16(Register::BX, 0xA)).unwrap();
+    machine.run_instruction(Instruction::MovImm16(Register::SI, 0xB)).unwrap();
     machine.run_instruction(Instruction::Mov(Operand::Register(Register::CL), Operand::Memory(MemAddress {
         base: Some(Register::BX),
         index: Some(Register::SI),
         displacement: 0xD0C,
         displacement_size: 2,
-    })));
+        segment: None,
+    }))).unwrap();
     machine.run_instruction(Instruction::Mov(Operand::Register(Register::CL), Operand::Memory(MemAddress {
         base: Some(Register::BX),
         index: Some(Register::SI),
         displacement: 0xD0C,
         displacement_size: 2,
-    })));
+        segment: None,
+    }))).unwrap();
     machine.run_instruction(Instruction::Mov(Operand::Memory(MemAddress {
         base: Some(Register::BX),
         index: Some(Register::SI),
         displacement: 0xD0C,
         displacement_size: 2,
-    }), Operand::Register(Register::CL)));
+        segment: None,
+    }), Operand::Register(Register::CL))).unwrap();
     machine.run_instruction(Instruction::Mov(Operand::Memory(MemAddress {
         base: Some(Register::BX),
         index: Some(Register::SI),
         displacement: 0xD0C,
         displacement_size: 2,
-    }), Operand::Register(Register::CL)));
+        segment: None,
+    }), Operand::Register(Register::CL))).unwrap();
 
     assert_eq!(machine.get_register(Register::BX), 0xA);
     assert_eq!(machine.get_register(Register::SI), 0xB);
@@ -343,32 +359,36 @@ fn test_mov_16bit_mem_to_reg_and_backwards_keeping_all_values_same(mut machine:
     // MOV CX, [BX + SI + 0xD0C]
     // MOV [BX + SI + 0xD0C], CX
     // MOV [BX + SI + 0xD0C], CX
-    machine.run_instruction(Instruction::MovImm16(Register::BX, 0xA));
-    machine.run_instruction(Instruction::MovImm16(Register::SI, 0xB));
+    machine.run_instruction(Instruction::MovImm16(Register::BX, 0xA)).unwrap();
+    machine.run_instruction(Instruction::MovImm16(Register::SI, 0xB)).unwrap();
     machine.run_instruction(Instruction::Mov(Operand::Register(Register::CX), Operand::Memory(MemAddress {
         base: Some(Register::BX),
         index: Some(Register::SI),
         displacement: 0xD0C,
         displacement_size: 2,
-    })));
+        segment: None,
+    }))).unwrap();
     machine.run_instruction(Instruction::Mov(Operand::Register(Register::CX), Operand::Memory(MemAddress {
         base: Some(Register::BX),
         index: Some(Register::SI),
         displacement: 0xD0C,
         displacement_size: 2,
-    })));
+        segment: None,
+    }))).unwrap();
     machine.run_instruction(Instruction::Mov(Operand::Memory(MemAddress {
         base: Some(Register::BX),
         index: Some(Register::SI),
         displacement: 0xD0C,
         displacement_size: 2,
-    }), Operand::Register(Register::CX)));
+        segment: None,
+    }), Operand::Register(Register::CX))).unwrap();
     machine.run_instruction(Instruction::Mov(Operand::Memory(MemAddress {
         base: Some(Register::BX),
         index: Some(Register::SI),
         displacement: 0xD0C,
         displacement_size: 2,
-    }), Operand::Register(Register::CX)));
+        segment: None,
+    }), Operand::Register(Register::CX))).unwrap();
 
     assert_eq!(machine.get_register(Register::BX), 0xA);
     assert_eq!(machine.get_register(Register::SI), 0xB);
@@ -381,7 +401,7 @@ fn test_mov_16bit_mem_to_reg_and_backwards_keeping_all_values_same(mut machine:
 #[machine_state(0x01BB = 0xCC)]
 fn test_mov_acc_mem_to_8bit_reg(mut machine: Machine) {
     // MOV AL, [0x01BB]
-    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::Register(Register::AL), MovMemOperand::MemoryPtr(0x01BB)));
+    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::Register(Register::AL), MovMemOperand::MemoryPtr(0x01BB))).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0xCC);
 }
@@ -391,7 +411,7 @@ fn test_mov_acc_mem_to_8bit_reg(mut machine: Machine) {
 #[machine_state(0x01BB + 1 = 0xFF)]
 fn test_mov_acc_mem_to_16bit_reg(mut machine: Machine) {
     // MOV AX, [0x01BB]
-    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::Register(Register::AX), MovMemOperand::MemoryPtr(0x01BB)));
+    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::Register(Register::AX), MovMemOperand::MemoryPtr(0x01BB))).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0xFFCC);
 }
@@ -400,7 +420,7 @@ fn test_mov_acc_mem_to_16bit_reg(mut machine: Machine) {
 #[machine_state(Register::AL = 0xFF)]
 fn test_mov_8bit_reg_to_acc_mem(mut machine: Machine) {
     // MOV [0x01BB], AL
-    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::MemoryPtr(0x01BB), MovMemOperand::Register(Register::AL)));
+    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::MemoryPtr(0x01BB), MovMemOperand::Register(Register::AL))).unwrap();
 
     assert_eq!(machine.memory().data[0x01BB], 0xFF);
 }
@@ -409,7 +429,7 @@ fn test_mov_8bit_reg_to_acc_mem(mut machine: Machine) {
 #[machine_state(Register::AX = 0xFFAA)]
 fn test_mov_16bit_reg_to_acc_mem(mut machine: Machine) {
     // MOV [0x01BB], AX
-    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::MemoryPtr(0x01BB), MovMemOperand::Register(Register::AX)));
+    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::MemoryPtr(0x01BB), MovMemOperand::Register(Register::AX))).unwrap();
 
     assert_eq!(machine.memory().data[0x01BB], 0xAA);
     assert_eq!(machine.memory().data[0x01BB + 1], 0xFF);
@@ -422,10 +442,10 @@ fn test_mov_8bit_acc_mem_to_reg_and_backwards_keeping_all_values_same(mut machin
     // MOV [0x01BB], AL
     // MOV AL, [0x01BB]
     // MOV AL, [0x01BB]
-    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::MemoryPtr(0x01BB), MovMemOperand::Register(Register::AL)));
-    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::MemoryPtr(0x01BB), MovMemOperand::Register(Register::AL)));
-    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::Register(Register::AL), MovMemOperand::MemoryPtr(0x01BB)));
-    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::Register(Register::AL), MovMemOperand::MemoryPtr(0x01BB)));
+    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::MemoryPtr(0x01BB), MovMemOperand::Register(Register::AL))).unwrap();
+    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::MemoryPtr(0x01BB), MovMemOperand::Register(Register::AL))).unwrap();
+    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::Register(Register::AL), MovMemOperand::MemoryPtr(0x01BB))).unwrap();
+    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::Register(Register::AL), MovMemOperand::MemoryPtr(0x01BB))).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0xAA);
     assert_eq!(machine.memory().data[0x01BB], 0xAA);
@@ -438,12 +458,75 @@ fn test_mov_16bit_acc_mem_to_reg_and_backwards_keeping_all_values_same(mut machi
     // MOV [0x01BB], AX
     // MOV AX, [0x01BB]
     // MOV AX, [0x01BB]
-    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::MemoryPtr(0x01BB), MovMemOperand::Register(Register::AX)));
-    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::MemoryPtr(0x01BB), MovMemOperand::Register(Register::AX)));
-    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::Register(Register::AX), MovMemOperand::MemoryPtr(0x01BB)));
-    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::Register(Register::AX), MovMemOperand::MemoryPtr(0x01BB)));
+    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::MemoryPtr(0x01BB), MovMemOperand::Register(Register::AX))).unwrap();
+    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::MemoryPtr(0x01BB), MovMemOperand::Register(Register::AX))).unwrap();
+    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::Register(Register::AX), MovMemOperand::MemoryPtr(0x01BB))).unwrap();
+    machine.run_instruction(Instruction::MovAccMem(MovMemOperand::Register(Register::AX), MovMemOperand::MemoryPtr(0x01BB))).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0xFFAA);
     assert_eq!(machine.memory().data[0x01BB], 0xAA);
     assert_eq!(machine.memory().data[0x01BB + 1], 0xFF);
-}
\ No newline at end of file
+}
+#[machine_test]
+#[machine_state(Register::BX = 0x20)]
+#[machine_state(0x20 = 0x11)]
+#[machine_state(0x21 = 0x22)]
+fn test_mov_8bit_imm_to_mem(mut machine: Machine) {
+    // MOV BYTE [BX], 0xAA
+    machine.run_instruction(Instruction::Mov(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0,
+        displacement_size: 0,
+        segment: None,
+    }), Operand::Immediate8(0xAA))).unwrap();
+
+    assert_eq!(machine.memory().data[0x20], 0xAA);
+    assert_eq!(machine.memory().data[0x21], 0x22);
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x20)]
+fn test_mov_16bit_imm_to_mem(mut machine: Machine) {
+    // MOV WORD [BX + 0x02], 0xAABB
+    machine.run_instruction(Instruction::Mov(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0x02,
+        displacement_size: 1,
+        segment: None,
+    }), Operand::Immediate16(0xAABB))).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x22), 0xAABB);
+}
+
+#[machine_test]
+#[machine_state(Register::CX = 0x1234)]
+fn test_mov_imm_to_reg_through_mod_rm(mut machine: Machine) {
+    // MOV CL, 0xAA (C6 encoding)
+    // MOV DX, 0xBBCC (C7 encoding)
+    machine.run_instruction(Instruction::Mov(Operand::Register(Register::CL), Operand::Immediate8(0xAA))).unwrap();
+    machine.run_instruction(Instruction::Mov(Operand::Register(Register::DX), Operand::Immediate16(0xBBCC))).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 0x12AA);
+    assert_eq!(machine.get_register(Register::DX), 0xBBCC);
+}
+
+#[machine_test]
+#[machine_state(Register::BP = 0x0100)]
+fn test_mov_mem_to_reg_with_negative_displacement(mut machine: Machine) {
+    // MOV AX, [BP - 2]
+    let instr = Instruction::from_bytes(0x8B, &[0x46, 0xFE]).unwrap();
+    assert_eq!(instr, Instruction::Mov(Operand::Register(Register::AX), Operand::Memory(MemAddress {
+        base: Some(Register::BP),
+        index: None,
+        displacement: 0xFFFE,
+        displacement_size: 1,
+        segment: None,
+    })));
+
+    machine.memory_mut().write_word(0x00FE, 0xAABB);
+    machine.run_instruction(instr).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0xAABB);
+}
@@ -0,0 +1,70 @@
+use nvm::Machine;
+use nvm::instruction::Instruction;
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::Register;
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::BL = 0x80)]
+#[machine_state(Register::ECX = 0xFFFFFFFF)]
+fn test_movzx_8_to_32(mut machine: Machine) {
+    // MOVZX ECX, BL
+    machine.run_instruction(Instruction::Movzx(Register::ECX, Operand::Register(Register::BL), true)).unwrap();
+
+    assert_eq!(machine.get_register32(Register::ECX), 0x00000080);
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x8000)]
+fn test_movzx_16_to_32(mut machine: Machine) {
+    // MOVZX EAX, BX
+    machine.run_instruction(Instruction::Movzx(Register::EAX, Operand::Register(Register::BX), false)).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0x00008000);
+}
+
+#[machine_test]
+#[machine_state(Register::EAX = 0x12345678)]
+#[machine_state(Register::BH = 0xAB)]
+fn test_movzx_8_to_16_keeps_upper_word(mut machine: Machine) {
+    // MOVZX AX, BH
+    machine.run_instruction(Instruction::Movzx(Register::AX, Operand::Register(Register::BH), true)).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0x123400AB);
+}
+
+#[machine_test]
+#[machine_state(Register::BL = 0x80)]
+fn test_movsx_8_to_32(mut machine: Machine) {
+    // MOVSX EDX, BL
+    machine.run_instruction(Instruction::Movsx(Register::EDX, Operand::Register(Register::BL), true)).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EDX), 0xFFFFFF80);
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x0100)]
+#[machine_state(0x0100 = 0x00)]
+#[machine_state(0x0101 = 0x80)]
+fn test_movsx_16_to_32_from_memory(mut machine: Machine) {
+    // MOVSX EAX, WORD [BX]
+    machine.run_instruction(Instruction::Movsx(
+        Register::EAX,
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            ..Default::default()
+        }),
+        false,
+    )).unwrap();
+
+    assert_eq!(machine.get_register32(Register::EAX), 0xFFFF8000);
+}
+
+#[machine_test]
+#[machine_state(Register::CL = 0x7F)]
+fn test_movsx_8_to_16_positive(mut machine: Machine) {
+    // MOVSX AX, CL
+    machine.run_instruction(Instruction::Movsx(Register::AX, Operand::Register(Register::CL), true)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x007F);
+}
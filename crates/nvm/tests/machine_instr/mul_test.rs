@@ -1,7 +1,7 @@
 use nvm::instruction::Instruction;
 use nvm::Machine;
 use nvm::modrm::{MemAddress, Operand};
-use nvm::register::Register;
+use nvm::register::{Flag, Register};
 use nvm_test_utils::{machine_state, machine_test};
 
 #[machine_test]
@@ -9,7 +9,7 @@ use nvm_test_utils::{machine_state, machine_test};
 #[machine_state(Register::CL = 0x04)]
 fn test_mul_with_8bit_reg(mut machine: Machine) {
     // MUL BYTE CL
-    machine.run_instruction(Instruction::Mul8(Operand::Register(Register::CL)));
+    machine.run_instruction(Instruction::Mul8(Operand::Register(Register::CL))).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x05 * 0x04);
 }
@@ -19,7 +19,7 @@ fn test_mul_with_8bit_reg(mut machine: Machine) {
 #[machine_state(Register::CL = 0xBB)]
 fn test_mul_with_8bit_reg_overflow(mut machine: Machine) {
     // MUL BYTE CL
-    machine.run_instruction(Instruction::Mul8(Operand::Register(Register::CL)));
+    machine.run_instruction(Instruction::Mul8(Operand::Register(Register::CL))).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x2E);
     assert_eq!(machine.get_register(Register::AH), 0x7C);
@@ -37,7 +37,8 @@ fn test_mul_8_with_mem(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0xFF,
         displacement_size: 1,
-    })));
+        segment: None,
+    }))).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x14);
 }
@@ -54,7 +55,8 @@ fn test_mul_8_with_mem_overflow(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0xFF,
         displacement_size: 1,
-    })));
+        segment: None,
+    }))).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x4E);
     assert_eq!(machine.get_register(Register::AH), 0x07);
@@ -65,7 +67,7 @@ fn test_mul_8_with_mem_overflow(mut machine: Machine) {
 #[machine_state(Register::CX = 0x02)]
 fn test_mul_with_16bit_reg(mut machine: Machine) {
     // MUL WORD CX
-    machine.run_instruction(Instruction::Mul16(Operand::Register(Register::CX)));
+    machine.run_instruction(Instruction::Mul16(Operand::Register(Register::CX))).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x0AAA * 0x02);
 }
@@ -75,7 +77,7 @@ fn test_mul_with_16bit_reg(mut machine: Machine) {
 #[machine_state(Register::CX = 0x0B)]
 fn test_mul_with_16bit_reg_overflow(mut machine: Machine) {
     // MUL WORD CL
-    machine.run_instruction(Instruction::Mul16(Operand::Register(Register::CX)));
+    machine.run_instruction(Instruction::Mul16(Operand::Register(Register::CX))).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x554e);
     assert_eq!(machine.get_register(Register::DX), 0x0007);
@@ -94,7 +96,8 @@ fn test_mul_16_with_mem(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0xFF,
         displacement_size: 1,
-    })));
+        segment: None,
+    }))).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x0AAA * 0x02);
 }
@@ -111,8 +114,46 @@ fn test_mul_16_with_mem_overflow(mut machine: Machine) {
         index: Some(Register::SI),
         displacement: 0xFF,
         displacement_size: 1,
-    })));
+        segment: None,
+    }))).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x554e);
     assert_eq!(machine.get_register(Register::DX), 0x0007);
 }
+
+#[machine_test]
+#[machine_state(Register::AL = 0x10)]
+#[machine_state(Register::CL = 0x0F)]
+fn test_mul_8_clears_carry_and_overflow_when_high_half_empty(mut machine: Machine) {
+    // MUL BYTE CL
+    machine.run_instruction(Instruction::Mul8(Operand::Register(Register::CL))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0xF0);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x10)]
+#[machine_state(Register::CL = 0x10)]
+fn test_mul_8_sets_carry_and_overflow_when_high_half_used(mut machine: Machine) {
+    // MUL BYTE CL
+    machine.run_instruction(Instruction::Mul8(Operand::Register(Register::CL))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0100);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x8000)]
+#[machine_state(Register::CX = 0x0002)]
+fn test_mul_16_sets_carry_and_overflow_when_high_half_used(mut machine: Machine) {
+    // MUL WORD CX
+    machine.run_instruction(Instruction::Mul16(Operand::Register(Register::CX))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0000);
+    assert_eq!(machine.get_register(Register::DX), 0x0001);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::OVERFLOW));
+}
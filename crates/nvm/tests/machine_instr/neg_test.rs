@@ -0,0 +1,56 @@
+use nvm::Machine;
+use nvm::instruction::Instruction;
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::AL = 0x01)]
+fn test_neg_8bit_reg(mut machine: Machine) {
+    // NEG AL
+    machine.run_instruction(Instruction::Neg8(Operand::Register(Register::AL))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0xFF);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::SIGN));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0000)]
+#[machine_state(Flag::CARRY = true)]
+fn test_neg_16bit_zero_clears_carry(mut machine: Machine) {
+    // NEG AX
+    machine.run_instruction(Instruction::Neg16(Operand::Register(Register::AX))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0000);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x8000)]
+fn test_neg_16bit_min_value_overflows(mut machine: Machine) {
+    // NEG AX
+    machine.run_instruction(Instruction::Neg16(Operand::Register(Register::AX))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x8000);
+    assert!(machine.get_flag(Flag::OVERFLOW));
+    assert!(machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x11)]
+#[machine_state(0x11 = 0x05)]
+fn test_neg_8bit_mem(mut machine: Machine) {
+    // NEG BYTE [BX]
+    machine.run_instruction(Instruction::Neg8(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0,
+        displacement_size: 0,
+        segment: None,
+    }))).unwrap();
+
+    assert_eq!(machine.memory().data[0x11], 0xFB);
+    assert_eq!(machine.memory().data[0x12], 0x00);
+}
@@ -0,0 +1,45 @@
+use nvm::Machine;
+use nvm::instruction::Instruction;
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::AX = 0x1234)]
+fn test_not_8bit_reg(mut machine: Machine) {
+    // NOT AL
+    machine.run_instruction(Instruction::Not8(Operand::Register(Register::AL))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x12CB);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0000)]
+#[machine_state(Flag::ZERO = true)]
+#[machine_state(Flag::CARRY = true)]
+fn test_not_16bit_reg_keeps_flags(mut machine: Machine) {
+    // NOT AX
+    machine.run_instruction(Instruction::Not16(Operand::Register(Register::AX))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0xFFFF);
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::SIGN));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x11)]
+#[machine_state(0x11 = 0x0F)]
+#[machine_state(0x11 + 1 = 0xF0)]
+fn test_not_16bit_mem(mut machine: Machine) {
+    // NOT WORD [BX]
+    machine.run_instruction(Instruction::Not16(Operand::Memory(MemAddress {
+        base: Some(Register::BX),
+        index: None,
+        displacement: 0,
+        displacement_size: 0,
+        segment: None,
+    }))).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x11), 0x0FF0);
+}
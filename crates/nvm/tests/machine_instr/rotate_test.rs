@@ -0,0 +1,117 @@
+use nvm::Machine;
+use nvm::instruction::{Instruction, ShiftCount};
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::AL = 0b1000_0001)]
+fn test_rol_8bit_by_one(mut machine: Machine) {
+    // ROL AL, 1
+    machine.run_instruction(Instruction::Rol(Operand::Register(Register::AL), ShiftCount::One, true)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0b0000_0011);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x1234)]
+#[machine_state(Register::CL = 4)]
+#[machine_state(Flag::ZERO = true)]
+fn test_rol_16bit_by_cl_keeps_result_flags(mut machine: Machine) {
+    // ROL AX, CL
+    machine.run_instruction(Instruction::Rol(Operand::Register(Register::AX), ShiftCount::CL, false)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x2341);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0b0000_0001)]
+fn test_ror_8bit_by_one(mut machine: Machine) {
+    // ROR AL, 1
+    machine.run_instruction(Instruction::Ror(Operand::Register(Register::AL), ShiftCount::One, true)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0b1000_0000);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x11)]
+#[machine_state(0x11 = 0x34)]
+#[machine_state(0x11 + 1 = 0x12)]
+fn test_ror_16bit_mem_by_imm(mut machine: Machine) {
+    // ROR WORD [BX], 8
+    machine.run_instruction(Instruction::Ror(
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }),
+        ShiftCount::Immediate(8),
+        false,
+    )).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x11), 0x3412);
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0b1000_0000)]
+#[machine_state(Flag::CARRY = false)]
+fn test_rcl_8bit_shifts_through_carry(mut machine: Machine) {
+    // RCL AL, 1
+    machine.run_instruction(Instruction::Rcl(Operand::Register(Register::AL), ShiftCount::One, true)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0b0000_0000);
+    assert!(machine.get_flag(Flag::CARRY));
+
+    // RCL AL, 1
+    machine.run_instruction(Instruction::Rcl(Operand::Register(Register::AL), ShiftCount::One, true)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0b0000_0001);
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0001)]
+#[machine_state(Register::CL = 17)]
+fn test_rcl_16bit_full_cycle(mut machine: Machine) {
+    // RCL AX, CL
+    machine.run_instruction(Instruction::Rcl(Operand::Register(Register::AX), ShiftCount::CL, false)).unwrap();
+
+    // 17 bit rotation through CF restores the original value
+    assert_eq!(machine.get_register(Register::AX), 0x0001);
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0b0000_0001)]
+#[machine_state(Flag::CARRY = true)]
+fn test_rcr_8bit_shifts_through_carry(mut machine: Machine) {
+    // RCR AL, 1
+    machine.run_instruction(Instruction::Rcr(Operand::Register(Register::AL), ShiftCount::One, true)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0b1000_0000);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::DX = 0x0003)]
+#[machine_state(Register::AX = 0x0000)]
+fn test_rcr_32bit_shift_with_shr(mut machine: Machine) {
+    // DX:AX >>= 1
+    // SHR DX, 1
+    // RCR AX, 1
+    machine.run_instruction(Instruction::Shr(Operand::Register(Register::DX), ShiftCount::One, false)).unwrap();
+    machine.run_instruction(Instruction::Rcr(Operand::Register(Register::AX), ShiftCount::One, false)).unwrap();
+
+    assert_eq!(machine.get_register(Register::DX), 0x0001);
+    assert_eq!(machine.get_register(Register::AX), 0x8000);
+}
@@ -0,0 +1,98 @@
+use nvm::Machine;
+use nvm::instruction::Instruction;
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::AL = 0x0A)]
+fn test_sbb_acc_8_without_borrow(mut machine: Machine) {
+    // SBB AL, 0x02
+    machine.run_instruction(Instruction::SbbAcc8(0x02)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x0A - 0x02);
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x0A)]
+#[machine_state(Flag::CARRY = true)]
+fn test_sbb_acc_8_with_borrow(mut machine: Machine) {
+    // SBB AL, 0x02
+    machine.run_instruction(Instruction::SbbAcc8(0x02)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x0A - 0x02 - 1);
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0000)]
+#[machine_state(Flag::CARRY = true)]
+fn test_sbb_acc_16_with_borrow_wrapping(mut machine: Machine) {
+    // SBB AX, 0x0000
+    machine.run_instruction(Instruction::SbbAcc16(0x0000)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0xFFFF);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::SIGN));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0005)]
+#[machine_state(Flag::CARRY = true)]
+fn test_sbb_acc_16_borrow_equal_operands(mut machine: Machine) {
+    // SBB AX, 0x0005
+    machine.run_instruction(Instruction::SbbAcc16(0x0005)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0xFFFF);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x11)]
+#[machine_state(Register::CX = 0x0302)]
+#[machine_state(0x11 = 0x05)]
+#[machine_state(0x11 + 1 = 0x02)]
+#[machine_state(Flag::CARRY = true)]
+fn test_sbb_16bit_mem_to_reg(mut machine: Machine) {
+    // SBB CX, [BX]
+    machine.run_instruction(Instruction::Sbb(
+        Operand::Register(Register::CX),
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }),
+        false,
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 0x0302 - 0x0205 - 1);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0000)]
+#[machine_state(Register::DX = 0x0002)]
+#[machine_state(Register::CX = 0x0001)]
+#[machine_state(Register::BX = 0x0000)]
+fn test_sbb_32bit_subtraction_with_sub(mut machine: Machine) {
+    // DX:AX -= BX:CX
+    // SUB AX, CX
+    // SBB DX, BX
+    machine.run_instruction(Instruction::Sub(
+        Operand::Register(Register::AX),
+        Operand::Register(Register::CX),
+        false,
+    )).unwrap();
+    machine.run_instruction(Instruction::Sbb(
+        Operand::Register(Register::DX),
+        Operand::Register(Register::BX),
+        false,
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0xFFFF);
+    assert_eq!(machine.get_register(Register::DX), 0x0001);
+    assert!(!machine.get_flag(Flag::CARRY));
+}
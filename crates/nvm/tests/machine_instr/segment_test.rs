@@ -0,0 +1,255 @@
+use nvm::Machine;
+use nvm::instruction::{Instruction, MovMemOperand};
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::Register;
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::DS = 0x0100)]
+#[machine_state(Register::BX = 0x0010)]
+#[machine_state(0x1010 = 0x42)]
+fn test_mov_reads_from_data_segment(mut machine: Machine) {
+    // MOV AL, [BX]
+    machine.run_instruction(Instruction::Mov(
+        Operand::Register(Register::AL),
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            ..Default::default()
+        }),
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x42);
+}
+
+#[machine_test]
+#[machine_state(Register::DS = 0x0100)]
+#[machine_state(Register::SS = 0x0200)]
+#[machine_state(Register::BP = 0x0010)]
+#[machine_state(Register::AX = 0x1234)]
+fn test_mov_bp_based_writes_to_stack_segment(mut machine: Machine) {
+    // MOV [BP + 2], AX
+    machine.run_instruction(Instruction::Mov(
+        Operand::Memory(MemAddress {
+            base: Some(Register::BP),
+            displacement: 2,
+            displacement_size: 1,
+            ..Default::default()
+        }),
+        Operand::Register(Register::AX),
+    )).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x2012), 0x1234);
+    assert_eq!(machine.memory().read_word(0x1012), 0x0000);
+}
+
+#[machine_test]
+#[machine_state(Register::DS = 0x0100)]
+#[machine_state(Register::AX = 0xBEEF)]
+fn test_mov_acc_mem_uses_data_segment(mut machine: Machine) {
+    // MOV [0x0020], AX
+    machine.run_instruction(Instruction::MovAccMem(
+        MovMemOperand::MemoryPtr(0x0020),
+        MovMemOperand::Register(Register::AX),
+    )).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x1020), 0xBEEF);
+}
+
+#[machine_test]
+#[machine_state(Register::DS = 0x0100)]
+#[machine_state(Register::BX = 0xFFFF)]
+#[machine_state(0x1000 = 0x01)]
+fn test_add_effective_address_wraps_within_segment(mut machine: Machine) {
+    // ADD AL, [BX + 1]
+    machine.run_instruction(Instruction::Add(
+        Operand::Register(Register::AL),
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            displacement: 1,
+            displacement_size: 1,
+            ..Default::default()
+        }),
+        true,
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x01);
+}
+
+#[machine_test]
+#[machine_state(Register::DS = 0x0100)]
+#[machine_state(0x1000 + 0xFFFF = 0x34)]
+#[machine_state(0x1000 = 0x12)]
+fn test_word_read_at_segment_end_wraps_to_segment_start(mut machine: Machine) {
+    // MOV AX, [0xFFFF]
+    machine.run_instruction(Instruction::Mov(
+        Operand::Register(Register::AX),
+        Operand::Memory(MemAddress {
+            displacement: 0xFFFF,
+            displacement_size: 2,
+            ..Default::default()
+        }),
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x1234);
+}
+
+#[machine_test]
+#[machine_state(Register::SS = 0x0300)]
+#[machine_state(Register::SP = 0x0010)]
+#[machine_state(Register::AX = 0xABCD)]
+fn test_push_pop_use_stack_segment(mut machine: Machine) {
+    machine.run_instruction(Instruction::Push(Register::AX)).unwrap();
+
+    assert_eq!(machine.get_register(Register::SP), 0x000E);
+    assert_eq!(machine.memory().read_word(0x300E), 0xABCD);
+
+    machine.run_instruction(Instruction::Pop(Register::BX)).unwrap();
+
+    assert_eq!(machine.get_register(Register::BX), 0xABCD);
+    assert_eq!(machine.get_register(Register::SP), 0x0010);
+}
+
+#[machine_test]
+#[machine_state(Register::SS = 0x0300)]
+#[machine_state(Register::SP = 0x0000)]
+#[machine_state(Register::AX = 0xABCD)]
+fn test_push_wraps_sp_within_stack_segment(mut machine: Machine) {
+    machine.run_instruction(Instruction::Push(Register::AX)).unwrap();
+
+    assert_eq!(machine.get_register(Register::SP), 0xFFFE);
+    assert_eq!(machine.memory().read_word(0x3000 + 0xFFFE), 0xABCD);
+}
+
+#[machine_test]
+#[machine_state(Register::DS = 0x0100)]
+#[machine_state(Register::ES = 0x0200)]
+#[machine_state(Register::SI = 0x0004)]
+#[machine_state(Register::DI = 0x0008)]
+#[machine_state(0x1004 = 0x77)]
+fn test_movsb_copies_from_ds_to_es(mut machine: Machine) {
+    machine.run_instruction(Instruction::Movs(None, None, true)).unwrap();
+
+    assert_eq!(machine.memory().data[0x2008], 0x77);
+    assert_eq!(machine.memory().data[0x1008], 0x00);
+}
+
+#[machine_test]
+#[machine_state(Register::DS = 0xFFFF)]
+#[machine_state(Register::AL = 0x5A)]
+fn test_physical_address_wraps_at_1mib(mut machine: Machine) {
+    // MOV [0x0020], AL
+    machine.run_instruction(Instruction::MovAccMem(
+        MovMemOperand::MemoryPtr(0x0020),
+        MovMemOperand::Register(Register::AL),
+    )).unwrap();
+
+    // 0xFFFF0 + 0x20 wraps around to 0x00010
+    assert_eq!(machine.memory().data[0x00010], 0x5A);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x1000)]
+fn test_mov_sreg_from_register(mut machine: Machine) {
+    // MOV DS, AX
+    machine.run_instruction(Instruction::Mov(
+        Operand::Register(Register::DS),
+        Operand::Register(Register::AX),
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::DS), 0x1000);
+}
+
+#[machine_test]
+#[machine_state(Register::ES = 0xB800)]
+#[machine_state(Register::BX = 0x0010)]
+fn test_mov_sreg_to_memory(mut machine: Machine) {
+    // MOV [BX], ES
+    machine.run_instruction(Instruction::Mov(
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            ..Default::default()
+        }),
+        Operand::Register(Register::ES),
+    )).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x0010), 0xB800);
+}
+
+#[machine_test]
+#[machine_state(Register::CS = 0x1234)]
+fn test_push_pop_sreg(mut machine: Machine) {
+    // PUSH CS
+    // POP DS
+    machine.run_instruction(Instruction::Push(Register::CS)).unwrap();
+    machine.run_instruction(Instruction::Pop(Register::DS)).unwrap();
+
+    assert_eq!(machine.get_register(Register::DS), 0x1234);
+}
+
+#[machine_test]
+#[machine_state(Register::ES = 0x0200)]
+#[machine_state(Register::BP = 0x0010)]
+#[machine_state(0x2010 = 0x42)]
+fn test_segment_override_replaces_default_segment(mut machine: Machine) {
+    // MOV AL, ES:[BP]
+    machine.run_instruction(Instruction::Mov(
+        Operand::Register(Register::AL),
+        Operand::Memory(MemAddress {
+            base: Some(Register::BP),
+            displacement_size: 1,
+            segment: Some(Register::ES),
+            ..Default::default()
+        }),
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x42);
+}
+
+#[machine_test]
+#[machine_state(Register::ES = 0x0200)]
+#[machine_state(Register::AX = 0xBEEF)]
+fn test_mov_acc_mem_with_segment_override(mut machine: Machine) {
+    // MOV ES:[0x0020], AX
+    machine.run_instruction(Instruction::MovAccMem(
+        MovMemOperand::SegmentedMemoryPtr(Register::ES, 0x0020),
+        MovMemOperand::Register(Register::AX),
+    )).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x2020), 0xBEEF);
+}
+
+#[machine_test]
+#[machine_state(Register::CS = 0x0100)]
+#[machine_state(Register::SI = 0x0004)]
+#[machine_state(Register::DI = 0x0008)]
+#[machine_state(0x1004 = 0x77)]
+fn test_movsb_with_segment_override_on_source(mut machine: Machine) {
+    // MOVSB CS:[SI]
+    machine.run_instruction(Instruction::Movs(None, Some(Register::CS), true)).unwrap();
+
+    assert_eq!(machine.memory().data[0x0008], 0x77);
+}
+
+#[machine_test]
+fn test_step_with_segment_override_prefix(mut machine: Machine) {
+    // MOV AX, 0x0200
+    // MOV ES, AX
+    // MOV ES:[0x0010], AX
+    // MOV BX, ES:[0x0010]
+    machine.load_program_bytes(&[
+        0xB8, 0x00, 0x02, 0x8E, 0xC0, 0x26, 0xA3, 0x10, 0x00, 0x26, 0x8B, 0x1E, 0x10, 0x00,
+    ]).unwrap();
+
+    machine.step().unwrap();
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::ES), 0x0200);
+
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 9);
+    assert_eq!(machine.memory().read_word(0x2010), 0x0200);
+    assert_eq!(machine.memory().read_word(0x0010), 0x0000);
+
+    machine.step().unwrap();
+    assert_eq!(machine.get_register(Register::IP), Machine::DEFAULT_LOAD_OFFSET + 14);
+    assert_eq!(machine.get_register(Register::BX), 0x0200);
+}
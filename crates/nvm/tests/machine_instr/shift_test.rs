@@ -0,0 +1,155 @@
+use nvm::Machine;
+use nvm::cpu::CpuModel;
+use nvm::instruction::{Instruction, ShiftCount};
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::AL = 0b0100_0001)]
+fn test_shl_8bit_by_one(mut machine: Machine) {
+    // SHL AL, 1
+    machine.run_instruction(Instruction::Shl(Operand::Register(Register::AL), ShiftCount::One, true)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0b1000_0010);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::OVERFLOW));
+    assert!(machine.get_flag(Flag::SIGN));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0b1100_0000)]
+fn test_shl_8bit_carry_out(mut machine: Machine) {
+    // SHL AL, 1
+    machine.run_instruction(Instruction::Shl(Operand::Register(Register::AL), ShiftCount::One, true)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0b1000_0000);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0001)]
+#[machine_state(Register::CL = 4)]
+fn test_shl_16bit_by_cl(mut machine: Machine) {
+    // SHL AX, CL
+    machine.run_instruction(Instruction::Shl(Operand::Register(Register::AX), ShiftCount::CL, false)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0010);
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0001)]
+#[machine_state(Register::CL = 33)]
+fn test_shl_count_masked_to_5_bits(mut machine: Machine) {
+    // SHL AX, CL
+    machine.run_instruction(Instruction::Shl(Operand::Register(Register::AX), ShiftCount::CL, false)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0002);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0001)]
+#[machine_state(Register::CL = 33)]
+fn test_shl_count_not_masked_on_8086(mut machine: Machine) {
+    machine.set_cpu_model(CpuModel::I8086);
+
+    // SHL AX, CL
+    machine.run_instruction(Instruction::Shl(Operand::Register(Register::AX), ShiftCount::CL, false)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0000);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x8001)]
+#[machine_state(Register::CL = 16)]
+fn test_shl_16bit_by_width_clears_operand(mut machine: Machine) {
+    // SHL AX, CL
+    machine.run_instruction(Instruction::Shl(Operand::Register(Register::AX), ShiftCount::CL, false)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0000);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x1234)]
+#[machine_state(Register::CL = 0)]
+#[machine_state(Flag::CARRY = true)]
+#[machine_state(Flag::ZERO = true)]
+fn test_shl_by_zero_keeps_flags(mut machine: Machine) {
+    // SHL AX, CL
+    machine.run_instruction(Instruction::Shl(Operand::Register(Register::AX), ShiftCount::CL, false)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x1234);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x11)]
+#[machine_state(0x11 = 0x03)]
+fn test_shl_8bit_mem_by_imm(mut machine: Machine) {
+    // SHL BYTE [BX], 3
+    machine.run_instruction(Instruction::Shl(
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }),
+        ShiftCount::Immediate(3),
+        true,
+    )).unwrap();
+
+    assert_eq!(machine.memory().data[0x11], 0x18);
+    assert_eq!(machine.memory().data[0x12], 0x00);
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0b1000_0011)]
+fn test_shr_8bit_by_one(mut machine: Machine) {
+    // SHR AL, 1
+    machine.run_instruction(Instruction::Shr(Operand::Register(Register::AL), ShiftCount::One, true)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0b0100_0001);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::OVERFLOW));
+    assert!(!machine.get_flag(Flag::SIGN));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0100)]
+#[machine_state(Register::CL = 8)]
+fn test_shr_16bit_by_cl(mut machine: Machine) {
+    // SHR AX, CL
+    machine.run_instruction(Instruction::Shr(Operand::Register(Register::AX), ShiftCount::CL, false)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x0001);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0b1000_0010)]
+fn test_sar_8bit_keeps_sign(mut machine: Machine) {
+    // SAR AL, 1
+    machine.run_instruction(Instruction::Sar(Operand::Register(Register::AL), ShiftCount::One, true)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0b1100_0001);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+    assert!(machine.get_flag(Flag::SIGN));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0xFFF0)]
+#[machine_state(Register::CL = 2)]
+fn test_sar_16bit_divides_signed(mut machine: Machine) {
+    // SAR AX, CL
+    machine.run_instruction(Instruction::Sar(Operand::Register(Register::AX), ShiftCount::CL, false)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX) as i16, -16 / 4);
+}
@@ -0,0 +1,212 @@
+use nvm::Machine;
+use nvm::instruction::{Instruction, RepPrefix};
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+fn run_until_ip(machine: &mut Machine, ip: u16) {
+    while machine.get_register(Register::IP) != ip {
+        machine.step().unwrap();
+    }
+}
+
+#[machine_test]
+#[machine_state(Register::SI = 0x200)]
+#[machine_state(Register::DI = 0x300)]
+#[machine_state(0x200 = 0xAB)]
+fn test_movsb(mut machine: Machine) {
+    machine.run_instruction(Instruction::Movs(None, None, true)).unwrap();
+
+    assert_eq!(machine.memory().data[0x300], 0xAB);
+    assert_eq!(machine.get_register(Register::SI), 0x201);
+    assert_eq!(machine.get_register(Register::DI), 0x301);
+}
+
+#[machine_test]
+#[machine_state(Register::SI = 0x200)]
+#[machine_state(Register::DI = 0x300)]
+#[machine_state(0x200 = 0x34)]
+#[machine_state(0x200 + 1 = 0x12)]
+#[machine_state(Flag::DIRECTION = true)]
+fn test_movsw_backwards(mut machine: Machine) {
+    machine.run_instruction(Instruction::Movs(None, None, false)).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x300), 0x1234);
+    assert_eq!(machine.get_register(Register::SI), 0x1FE);
+    assert_eq!(machine.get_register(Register::DI), 0x2FE);
+}
+
+#[machine_test]
+#[machine_state(Register::DI = 0x300)]
+#[machine_state(Register::AX = 0x1234)]
+fn test_stosw(mut machine: Machine) {
+    machine.run_instruction(Instruction::Stos(None, None, false)).unwrap();
+
+    assert_eq!(machine.memory().read_word(0x300), 0x1234);
+    assert_eq!(machine.get_register(Register::DI), 0x302);
+}
+
+#[machine_test]
+#[machine_state(Register::SI = 0x200)]
+#[machine_state(Register::AX = 0xFFFF)]
+#[machine_state(0x200 = 0x42)]
+fn test_lodsb(mut machine: Machine) {
+    machine.run_instruction(Instruction::Lods(None, None, true)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0xFF42);
+    assert_eq!(machine.get_register(Register::SI), 0x201);
+}
+
+#[machine_test]
+#[machine_state(Register::SI = 0x200)]
+#[machine_state(Register::DI = 0x300)]
+#[machine_state(0x200 = 0x01)]
+#[machine_state(0x300 = 0x02)]
+fn test_cmpsb_sets_flags_of_si_minus_di(mut machine: Machine) {
+    machine.run_instruction(Instruction::Cmps(None, None, true)).unwrap();
+
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(!machine.get_flag(Flag::ZERO));
+    assert_eq!(machine.memory().data[0x200], 0x01);
+    assert_eq!(machine.get_register(Register::SI), 0x201);
+    assert_eq!(machine.get_register(Register::DI), 0x301);
+}
+
+#[machine_test]
+#[machine_state(Register::DI = 0x300)]
+#[machine_state(Register::AL = 0x42)]
+#[machine_state(0x300 = 0x42)]
+fn test_scasb_match(mut machine: Machine) {
+    machine.run_instruction(Instruction::Scas(None, None, true)).unwrap();
+
+    assert!(machine.get_flag(Flag::ZERO));
+    assert_eq!(machine.get_register(Register::DI), 0x301);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0x10)]
+#[machine_state(Register::CX = 0)]
+#[machine_state(Register::DI = 0x300)]
+#[machine_state(Register::AL = 0x42)]
+fn test_rep_with_zero_cx_does_nothing(mut machine: Machine) {
+    machine.run_instruction(Instruction::Stos(Some(RepPrefix::Rep), None, true)).unwrap();
+
+    assert_eq!(machine.memory().data[0x300], 0x00);
+    assert_eq!(machine.get_register(Register::DI), 0x300);
+    assert_eq!(machine.get_register(Register::CX), 0);
+    assert_eq!(machine.get_register(Register::IP), 0x10);
+}
+
+#[machine_test]
+#[machine_state(Register::IP = 0x12)]
+#[machine_state(Register::CX = 2)]
+#[machine_state(Register::DI = 0x300)]
+#[machine_state(Register::AL = 0x42)]
+fn test_rep_rewinds_ip_to_restart(mut machine: Machine) {
+    machine.run_instruction(Instruction::Stos(Some(RepPrefix::Rep), None, true)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 1);
+    assert_eq!(machine.get_register(Register::IP), 0x10);
+
+    machine.set_register(Register::IP, 0x12);
+    machine.run_instruction(Instruction::Stos(Some(RepPrefix::Rep), None, true)).unwrap();
+
+    assert_eq!(machine.get_register(Register::CX), 0);
+    assert_eq!(machine.get_register(Register::IP), 0x12);
+    assert_eq!(machine.memory().data[0x300], 0x42);
+    assert_eq!(machine.memory().data[0x301], 0x42);
+}
+
+#[machine_test]
+#[machine_state(Register::CX = 4)]
+#[machine_state(Register::SI = 0x200)]
+#[machine_state(Register::DI = 0x300)]
+#[machine_state(0x200 = 0x11)]
+#[machine_state(0x200 + 1 = 0x22)]
+#[machine_state(0x200 + 2 = 0x33)]
+#[machine_state(0x200 + 3 = 0x44)]
+fn test_rep_movsb_program(mut machine: Machine) {
+    // REP MOVSB
+    // NOP
+    machine.load_program_bytes(&[0xF3, 0xA4, 0x90]).unwrap();
+
+    run_until_ip(&mut machine, Machine::DEFAULT_LOAD_OFFSET + 2);
+
+    assert_eq!(machine.memory().data[0x300..0x304], [0x11, 0x22, 0x33, 0x44]);
+    assert_eq!(machine.get_register(Register::CX), 0);
+    assert_eq!(machine.get_register(Register::SI), 0x204);
+    assert_eq!(machine.get_register(Register::DI), 0x304);
+}
+
+#[machine_test]
+#[machine_state(Register::CX = 3)]
+#[machine_state(Register::DI = 0x300)]
+#[machine_state(Register::AX = 0xBEEF)]
+fn test_rep_stosw_program(mut machine: Machine) {
+    // REP STOSW
+    machine.load_program_bytes(&[0xF3, 0xAB]).unwrap();
+
+    run_until_ip(&mut machine, Machine::DEFAULT_LOAD_OFFSET + 2);
+
+    for i in 0..3 {
+        assert_eq!(machine.memory().read_word(0x300 + i * 2), 0xBEEF);
+    }
+    assert_eq!(machine.memory().read_word(0x306), 0x0000);
+    assert_eq!(machine.get_register(Register::DI), 0x306);
+}
+
+#[machine_test]
+#[machine_state(Register::CX = 4)]
+#[machine_state(Register::SI = 0x200)]
+#[machine_state(Register::DI = 0x300)]
+#[machine_state(0x200 = 0x11)]
+#[machine_state(0x200 + 1 = 0x22)]
+#[machine_state(0x200 + 2 = 0x33)]
+#[machine_state(0x300 = 0x11)]
+#[machine_state(0x300 + 1 = 0x22)]
+#[machine_state(0x300 + 2 = 0x99)]
+fn test_repe_cmpsb_stops_on_mismatch(mut machine: Machine) {
+    // REPE CMPSB
+    machine.load_program_bytes(&[0xF3, 0xA6]).unwrap();
+
+    run_until_ip(&mut machine, Machine::DEFAULT_LOAD_OFFSET + 2);
+
+    assert!(!machine.get_flag(Flag::ZERO));
+    assert_eq!(machine.get_register(Register::CX), 1);
+    assert_eq!(machine.get_register(Register::SI), 0x203);
+}
+
+#[machine_test]
+#[machine_state(Register::CX = 8)]
+#[machine_state(Register::DI = 0x300)]
+#[machine_state(Register::AL = 0x33)]
+#[machine_state(0x300 = 0x11)]
+#[machine_state(0x300 + 1 = 0x22)]
+#[machine_state(0x300 + 2 = 0x33)]
+fn test_repne_scasb_finds_byte(mut machine: Machine) {
+    // REPNE SCASB
+    machine.load_program_bytes(&[0xF2, 0xAE]).unwrap();
+
+    run_until_ip(&mut machine, Machine::DEFAULT_LOAD_OFFSET + 2);
+
+    assert!(machine.get_flag(Flag::ZERO));
+    assert_eq!(machine.get_register(Register::CX), 5);
+    // DI points past the match
+    assert_eq!(machine.get_register(Register::DI), 0x303);
+}
+
+#[machine_test]
+#[machine_state(Register::CX = 3)]
+#[machine_state(Register::SI = 0x200)]
+#[machine_state(0x200 = 0x11)]
+#[machine_state(0x200 + 1 = 0x22)]
+#[machine_state(0x200 + 2 = 0x33)]
+fn test_rep_lodsb_leaves_last_element(mut machine: Machine) {
+    // REP LODSB
+    machine.load_program_bytes(&[0xF3, 0xAC]).unwrap();
+
+    run_until_ip(&mut machine, Machine::DEFAULT_LOAD_OFFSET + 2);
+
+    assert_eq!(machine.get_register(Register::AL), 0x33);
+    assert_eq!(machine.get_register(Register::SI), 0x203);
+}
@@ -8,7 +8,7 @@ use nvm_test_utils::{machine_state, machine_test};
 #[machine_state(Register::AL = 0x0A)]
 fn test_sub_acc_8(mut machine: Machine) {
     // SUB AL, 0x02
-    machine.run_instruction(Instruction::SubAcc8(0x02));
+    machine.run_instruction(Instruction::SubAcc8(0x02)).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x0A - 0x02);
 }
@@ -17,7 +17,7 @@ fn test_sub_acc_8(mut machine: Machine) {
 #[machine_state(Register::AL = 0x00)]
 fn test_sub_acc_8_wrapping(mut machine: Machine) {
     // SUB AL, 0x02
-    machine.run_instruction(Instruction::SubAcc8(0x02));
+    machine.run_instruction(Instruction::SubAcc8(0x02)).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0xFE);
 }
@@ -26,7 +26,7 @@ fn test_sub_acc_8_wrapping(mut machine: Machine) {
 #[machine_state(Register::AX = 0x2211)]
 fn test_sub_acc_16(mut machine: Machine) {
     // SUB AX, 0x2211
-    machine.run_instruction(Instruction::SubAcc16(0x1122));
+    machine.run_instruction(Instruction::SubAcc16(0x1122)).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x2211 - 0x1122);
 }
@@ -35,7 +35,7 @@ fn test_sub_acc_16(mut machine: Machine) {
 #[machine_state(Register::AX = 0x3333)]
 fn test_sub_acc_16_wrapping(mut machine: Machine) {
     // SUB AX, 0x2211
-    machine.run_instruction(Instruction::SubAcc16(0x3335));
+    machine.run_instruction(Instruction::SubAcc16(0x3335)).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0xFFFE);
 }
@@ -53,10 +53,11 @@ fn test_sub_8bit_reg_to_mem(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         Operand::Register(Register::AL),
         true,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.memory().data[0x11 + 0x22], 0x55 - 0x22);
 }
@@ -74,10 +75,11 @@ fn test_sub_8bit_reg_to_mem_wrapping(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         Operand::Register(Register::AL),
         true,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.memory().data[0x11 + 0x22], 0xFE);
 }
@@ -96,10 +98,11 @@ fn test_sub_16bit_reg_to_mem(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         Operand::Register(Register::AX),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.memory().read_word(0x11 + 0x22), 0x4422 - 0x1111);
 }
@@ -118,10 +121,11 @@ fn test_sub_16bit_reg_to_mem_wrapping(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         Operand::Register(Register::AX),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.memory().read_word(0x11 + 0x22), 0xFFFE);
 }
@@ -140,9 +144,10 @@ fn test_sub_mem_to_8bit_reg(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         true,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x22 - 0x11);
 }
@@ -161,9 +166,10 @@ fn test_sub_mem_to_8bit_reg_wrapping(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         true,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0xFE);
 }
@@ -182,9 +188,10 @@ fn test_sub_mem_to_16bit_reg(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x2233 - 0x11);
 }
@@ -204,9 +211,10 @@ fn test_sub_mem_to_16bit_reg_wrapping(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0,
             displacement_size: 0,
+            segment: None,
         }),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0xFFFE);
 }
@@ -225,9 +233,10 @@ fn test_sub_mem_to_16bit_reg_1byte_displacement(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0x33,
             displacement_size: 1,
+            segment: None,
         }),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x2233 - 0x11);
 }
@@ -246,9 +255,10 @@ fn test_sub_mem_to_16bit_reg_2byte_displacement(mut machine: Machine) {
             index: Some(Register::SI),
             displacement: 0x3333,
             displacement_size: 2,
+            segment: None,
         }),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x2233 - 0x11);
 }
@@ -262,7 +272,7 @@ fn test_sub_8bit_reg_to_8bit_reg(mut machine: Machine) {
         Operand::Register(Register::AL),
         Operand::Register(Register::CL),
         true,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0x22 - 0x11);
 }
@@ -276,7 +286,7 @@ fn test_sub_8bit_reg_to_8bit_reg_wrapping(mut machine: Machine) {
         Operand::Register(Register::AL),
         Operand::Register(Register::CL),
         true,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AL), 0xFE);
 }
@@ -290,7 +300,7 @@ fn test_sub_16bit_reg_to_16bit_reg(mut machine: Machine) {
         Operand::Register(Register::AX),
         Operand::Register(Register::CX),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0x2233 - 0x11);
 }
@@ -304,7 +314,7 @@ fn test_sub_16bit_reg_to_16bit_reg_wrapping(mut machine: Machine) {
         Operand::Register(Register::AX),
         Operand::Register(Register::CX),
         false,
-    ));
+    )).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0xFFFE);
 }
@@ -315,8 +325,8 @@ fn test_sub_16bit_reg_to_16bit_reg_wrapping(mut machine: Machine) {
 fn test_dec_reg(mut machine: Machine) {
     // DEC AX
     // DEC CX
-    machine.run_instruction(Instruction::Dec(Register::AX));
-    machine.run_instruction(Instruction::Dec(Register::CX));
+    machine.run_instruction(Instruction::Dec(Register::AX)).unwrap();
+    machine.run_instruction(Instruction::Dec(Register::CX)).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0xFF);
     assert_eq!(machine.get_register(Register::CX), 0x0000);
@@ -326,7 +336,7 @@ fn test_dec_reg(mut machine: Machine) {
 #[machine_state(Register::AX = 0x0000)]
 fn test_dec_reg_wrapping(mut machine: Machine) {
     // DEC AX
-    machine.run_instruction(Instruction::Dec(Register::AX));
+    machine.run_instruction(Instruction::Dec(Register::AX)).unwrap();
 
     assert_eq!(machine.get_register(Register::AX), 0xFFFF);
 }
@@ -338,7 +348,7 @@ pub fn test_sub_updating_flags_true(mut machine: Machine) {
         Operand::Register(Register::AX),
         Operand::Register(Register::AX),
         false,
-    ));
+    )).unwrap();
 
     assert!(machine.get_flag(Flag::ZERO));
 }
@@ -351,7 +361,7 @@ pub fn test_sub_updating_flags_false(mut machine: Machine) {
         Operand::Register(Register::AX),
         Operand::Register(Register::CX),
         false,
-    ));
+    )).unwrap();
 
     assert!(!machine.get_flag(Flag::ZERO));
 }
@@ -359,7 +369,7 @@ pub fn test_sub_updating_flags_false(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0x01)]
 pub fn test_sub_acc_8bit_updating_flags_true(mut machine: Machine) {
-    machine.run_instruction(Instruction::SubAcc8(1));
+    machine.run_instruction(Instruction::SubAcc8(1)).unwrap();
 
     assert!(machine.get_flag(Flag::ZERO));
 }
@@ -367,7 +377,7 @@ pub fn test_sub_acc_8bit_updating_flags_true(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0x10)]
 pub fn test_sub_acc_8bit_updating_flags_false(mut machine: Machine) {
-    machine.run_instruction(Instruction::SubAcc8(1));
+    machine.run_instruction(Instruction::SubAcc8(1)).unwrap();
 
     assert!(!machine.get_flag(Flag::ZERO));
 }
@@ -375,7 +385,7 @@ pub fn test_sub_acc_8bit_updating_flags_false(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0x01)]
 pub fn test_sub_acc_16bit_updating_flags_true(mut machine: Machine) {
-    machine.run_instruction(Instruction::SubAcc16(1));
+    machine.run_instruction(Instruction::SubAcc16(1)).unwrap();
 
     assert!(machine.get_flag(Flag::ZERO));
 }
@@ -383,7 +393,7 @@ pub fn test_sub_acc_16bit_updating_flags_true(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0x10)]
 pub fn test_sub_acc_16bit_updating_flags_false(mut machine: Machine) {
-    machine.run_instruction(Instruction::SubAcc16(1));
+    machine.run_instruction(Instruction::SubAcc16(1)).unwrap();
 
     assert!(!machine.get_flag(Flag::ZERO));
 }
@@ -391,7 +401,7 @@ pub fn test_sub_acc_16bit_updating_flags_false(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0x01)]
 pub fn test_dec_updating_flags_true(mut machine: Machine) {
-    machine.run_instruction(Instruction::Dec(Register::AX));
+    machine.run_instruction(Instruction::Dec(Register::AX)).unwrap();
 
     assert!(machine.get_flag(Flag::ZERO));
 }
@@ -399,7 +409,100 @@ pub fn test_dec_updating_flags_true(mut machine: Machine) {
 #[machine_test]
 #[machine_state(Register::AX = 0x10)]
 pub fn test_dec_updating_flags_false(mut machine: Machine) {
-    machine.run_instruction(Instruction::Dec(Register::AX));
+    machine.run_instruction(Instruction::Dec(Register::AX)).unwrap();
 
     assert!(!machine.get_flag(Flag::ZERO));
 }
+
+#[machine_test]
+#[machine_state(Register::AL = 0x00)]
+pub fn test_sub_acc_8bit_borrow_flags(mut machine: Machine) {
+    // SUB AL, 0x01
+    machine.run_instruction(Instruction::SubAcc8(0x01)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0xFF);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+    assert!(machine.get_flag(Flag::PARITY));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+    assert!(!machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x80)]
+pub fn test_sub_acc_8bit_overflow_flag(mut machine: Machine) {
+    // SUB AL, 0x01
+    machine.run_instruction(Instruction::SubAcc8(0x01)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x7F);
+    assert!(machine.get_flag(Flag::OVERFLOW));
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::SIGN));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0100)]
+pub fn test_sub_acc_16bit_no_borrow_flags(mut machine: Machine) {
+    // SUB AX, 0x0001
+    machine.run_instruction(Instruction::SubAcc16(0x01)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x00FF);
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::SIGN));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+    assert!(machine.get_flag(Flag::AUXILIARY));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0001)]
+#[machine_state(Register::CX = 0x0002)]
+pub fn test_sub_16bit_signed_result_flags(mut machine: Machine) {
+    // SUB AX, CX
+    machine.run_instruction(Instruction::Sub(
+        Operand::Register(Register::AX),
+        Operand::Register(Register::CX),
+        false,
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0xFFFF);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x0000)]
+#[machine_state(Flag::CARRY = true)]
+pub fn test_dec_preserves_carry_flag(mut machine: Machine) {
+    // DEC AX
+    machine.run_instruction(Instruction::Dec(Register::AX)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0xFFFF);
+    assert!(machine.get_flag(Flag::CARRY));
+    assert!(machine.get_flag(Flag::SIGN));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x8000)]
+pub fn test_dec_overflow_flag(mut machine: Machine) {
+    // DEC AX
+    machine.run_instruction(Instruction::Dec(Register::AX)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x7FFF);
+    assert!(machine.get_flag(Flag::OVERFLOW));
+    assert!(!machine.get_flag(Flag::CARRY));
+}
+
+#[machine_test]
+#[machine_state(Register::DX = 0x1234)]
+fn test_sub_16bit_reg_imm16(mut machine: Machine) {
+    // SUB DX, 0x0234
+    machine.run_instruction(Instruction::Sub(
+        Operand::Register(Register::DX),
+        Operand::Immediate16(0x0234),
+        false,
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::DX), 0x1000);
+}
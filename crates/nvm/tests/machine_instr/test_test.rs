@@ -0,0 +1,85 @@
+use nvm::Machine;
+use nvm::instruction::Instruction;
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::AL = 0x0A)]
+#[machine_state(Flag::CARRY = true)]
+#[machine_state(Flag::OVERFLOW = true)]
+fn test_test_acc_8_no_common_bits(mut machine: Machine) {
+    // TEST AL, 0x05
+    machine.run_instruction(Instruction::TestAcc8(0x05)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x0A);
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x8001)]
+fn test_test_acc_16_sign(mut machine: Machine) {
+    // TEST AX, 0x8000
+    machine.run_instruction(Instruction::TestAcc16(0x8000)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x8001);
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(!machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x11)]
+#[machine_state(Register::AL = 0x0F)]
+#[machine_state(0x11 = 0xF1)]
+fn test_test_8bit_mem_with_reg(mut machine: Machine) {
+    // TEST [BX], AL
+    machine.run_instruction(Instruction::Test(
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: None,
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }),
+        Operand::Register(Register::AL),
+        true,
+    )).unwrap();
+
+    assert_eq!(machine.memory().data[0x11], 0xF1);
+    assert!(!machine.get_flag(Flag::ZERO));
+    assert!(!machine.get_flag(Flag::PARITY));
+}
+
+#[machine_test]
+#[machine_state(Register::CX = 0x0000)]
+fn test_test_16bit_reg_with_itself(mut machine: Machine) {
+    // TEST CX, CX
+    machine.run_instruction(Instruction::Test(
+        Operand::Register(Register::CX),
+        Operand::Register(Register::CX),
+        false,
+    )).unwrap();
+
+    assert!(machine.get_flag(Flag::ZERO));
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x11)]
+#[machine_state(0x11 = 0x80)]
+fn test_test_8bit_mem_imm(mut machine: Machine) {
+    // TEST BYTE [BX], 0x80
+    machine.run_instruction(Instruction::Test(
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            ..Default::default()
+        }),
+        Operand::Immediate8(0x80),
+        true,
+    )).unwrap();
+
+    assert!(!machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::SIGN));
+    assert_eq!(machine.memory().data[0x11], 0x80);
+}
@@ -0,0 +1,56 @@
+use nvm::instruction::Instruction;
+use nvm::Machine;
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::Register;
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::CL = 0x11)]
+#[machine_state(Register::DH = 0x22)]
+fn test_xchg_8bit_reg(mut machine: Machine) {
+    // XCHG CL, DH
+    machine.run_instruction(Instruction::Xchg(
+        Operand::Register(Register::CL),
+        Operand::Register(Register::DH),
+        true,
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::CL), 0x22);
+    assert_eq!(machine.get_register(Register::DH), 0x11);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0xAABB)]
+#[machine_state(Register::SI = 0x0020)]
+#[machine_state(0x20 = 0x34)]
+#[machine_state(0x21 = 0x12)]
+fn test_xchg_16bit_mem(mut machine: Machine) {
+    // XCHG [SI], AX
+    machine.run_instruction(Instruction::Xchg(
+        Operand::Register(Register::AX),
+        Operand::Memory(MemAddress {
+            base: None,
+            index: Some(Register::SI),
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }),
+        false,
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x1234);
+    assert_eq!(machine.memory().read_word(0x20), 0xAABB);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x1111)]
+#[machine_state(Register::DI = 0x2222)]
+#[machine_state(Register::F = 0x00D5)]
+fn test_xchg_acc(mut machine: Machine) {
+    // XCHG AX, DI
+    machine.run_instruction(Instruction::XchgAcc(Register::DI)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x2222);
+    assert_eq!(machine.get_register(Register::DI), 0x1111);
+    assert_eq!(machine.get_register(Register::F), 0x00D5);
+}
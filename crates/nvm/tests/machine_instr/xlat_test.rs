@@ -0,0 +1,27 @@
+use nvm::instruction::Instruction;
+use nvm::Machine;
+use nvm::register::Register;
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::BX = 0x0100)]
+#[machine_state(Register::AL = 0x05)]
+#[machine_state(0x105 = 0x42)]
+fn test_xlat(mut machine: Machine) {
+    // XLAT
+    machine.run_instruction(Instruction::Xlat(None)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x42);
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x0000)]
+#[machine_state(Register::AL = 0xFF)]
+#[machine_state(Register::ES = 0x0010)]
+#[machine_state(0x1FF = 0x24)]
+fn test_xlat_with_segment_override(mut machine: Machine) {
+    // ES: XLAT
+    machine.run_instruction(Instruction::Xlat(Some(Register::ES))).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x24);
+}
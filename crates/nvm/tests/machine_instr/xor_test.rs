@@ -0,0 +1,98 @@
+use nvm::Machine;
+use nvm::instruction::Instruction;
+use nvm::modrm::{MemAddress, Operand};
+use nvm::register::{Flag, Register};
+use nvm_test_utils::{machine_state, machine_test};
+
+#[machine_test]
+#[machine_state(Register::AL = 0x0A)]
+fn test_xor_acc_8(mut machine: Machine) {
+    // XOR AL, 0x02
+    machine.run_instruction(Instruction::XorAcc8(0x02)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0x0A ^ 0x02);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0x1122)]
+fn test_xor_acc_16(mut machine: Machine) {
+    // XOR AX, 0x2211
+    machine.run_instruction(Instruction::XorAcc16(0x2211)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x1122 ^ 0x2211);
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x11)]
+#[machine_state(Register::SI = 0x22)]
+#[machine_state(Register::AL = 0x22)]
+#[machine_state(0x11 + 0x22 = 0x11)]
+fn test_xor_8bit_reg_to_mem(mut machine: Machine) {
+    // XOR [BX + SI], AL
+    machine.run_instruction(Instruction::Xor(
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: Some(Register::SI),
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }),
+        Operand::Register(Register::AL),
+        true,
+    )).unwrap();
+
+    assert_eq!(machine.memory().data[0x11 + 0x22], 0x22 ^ 0x11);
+}
+
+#[machine_test]
+#[machine_state(Register::BX = 0x11)]
+#[machine_state(Register::SI = 0x22)]
+#[machine_state(Register::AX = 0x2233)]
+#[machine_state(0x11 + 0x22 = 0x11)]
+fn test_xor_mem_to_16bit_reg(mut machine: Machine) {
+    // XOR AX, [BX + SI]
+    machine.run_instruction(Instruction::Xor(
+        Operand::Register(Register::AX),
+        Operand::Memory(MemAddress {
+            base: Some(Register::BX),
+            index: Some(Register::SI),
+            displacement: 0,
+            displacement_size: 0,
+            segment: None,
+        }),
+        false,
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0x2233 ^ 0x11);
+}
+
+#[machine_test]
+#[machine_state(Register::AX = 0xBEEF)]
+#[machine_state(Flag::CARRY = true)]
+#[machine_state(Flag::OVERFLOW = true)]
+fn test_xor_reg_with_itself_zeroes_register(mut machine: Machine) {
+    // XOR AX, AX
+    machine.run_instruction(Instruction::Xor(
+        Operand::Register(Register::AX),
+        Operand::Register(Register::AX),
+        false,
+    )).unwrap();
+
+    assert_eq!(machine.get_register(Register::AX), 0);
+    assert!(machine.get_flag(Flag::ZERO));
+    assert!(machine.get_flag(Flag::PARITY));
+    assert!(!machine.get_flag(Flag::CARRY));
+    assert!(!machine.get_flag(Flag::OVERFLOW));
+    assert!(!machine.get_flag(Flag::SIGN));
+}
+
+#[machine_test]
+#[machine_state(Register::AL = 0x0F)]
+fn test_xor_acc_8bit_sign_flag(mut machine: Machine) {
+    // XOR AL, 0xF0
+    machine.run_instruction(Instruction::XorAcc8(0xF0)).unwrap();
+
+    assert_eq!(machine.get_register(Register::AL), 0xFF);
+    assert!(machine.get_flag(Flag::SIGN));
+    assert!(!machine.get_flag(Flag::ZERO));
+}
@@ -63,4 +63,11 @@ fn test_write_word_read_word() {
     assert_eq!(memory.data[10], 0xBB);
     assert_eq!(memory.data[11], 0xAA);
     assert_eq!(memory.read_word(10), 0xAABB);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_linear_memory_with_custom_size() {
+    let memory = LinearMemory::new(64 * 1024);
+
+    assert_eq!(memory.data.len(), 64 * 1024);
+}
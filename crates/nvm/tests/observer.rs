@@ -0,0 +1,169 @@
+use nvm::cpu::CpuModel;
+use nvm::instruction::Instruction;
+use nvm::observer::Observer;
+use nvm::register::Register;
+use nvm::{Machine, MachineBuilder};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Before(u16, u16, Instruction),
+    After(u16, u16, Instruction),
+    Read(usize, u8),
+    Write(usize, u8),
+    Register(Register, u16),
+    Register32(Register, u32),
+    Interrupt(u8),
+}
+
+struct Recorder {
+    events: Rc<RefCell<Vec<Event>>>,
+}
+
+impl Observer for Recorder {
+    fn before_instruction(&mut self, segment: u16, offset: u16, instruction: &Instruction) {
+        self.events.borrow_mut().push(Event::Before(segment, offset, instruction.clone()));
+    }
+
+    fn after_instruction(&mut self, segment: u16, offset: u16, instruction: &Instruction) {
+        self.events.borrow_mut().push(Event::After(segment, offset, instruction.clone()));
+    }
+
+    fn memory_read(&mut self, address: usize, value: u8) {
+        self.events.borrow_mut().push(Event::Read(address, value));
+    }
+
+    fn memory_write(&mut self, address: usize, value: u8) {
+        self.events.borrow_mut().push(Event::Write(address, value));
+    }
+
+    fn register_write(&mut self, register: Register, value: u16) {
+        self.events.borrow_mut().push(Event::Register(register, value));
+    }
+
+    fn register_write32(&mut self, register: Register, value: u32) {
+        self.events.borrow_mut().push(Event::Register32(register, value));
+    }
+
+    fn interrupt(&mut self, vector: u8) {
+        self.events.borrow_mut().push(Event::Interrupt(vector));
+    }
+}
+
+fn record(machine: &mut Machine) -> Rc<RefCell<Vec<Event>>> {
+    let events = Rc::new(RefCell::new(Vec::new()));
+    machine.attach_observer(Box::new(Recorder { events: events.clone() }));
+    events
+}
+
+#[test]
+fn test_observer_sees_instruction_and_register_write() {
+    // MOV AL, 0x42
+    let mut machine = MachineBuilder::new().program([0xB0, 0x42]).build().unwrap();
+    let events = record(&mut machine);
+
+    machine.step().unwrap();
+
+    assert_eq!(*events.borrow(), vec![
+        Event::Before(0, 0x0100, Instruction::MovImm8(Register::AL, 0x42)),
+        Event::Register(Register::IP, 0x0100 + 2),
+        Event::Register(Register::AL, 0x42),
+        Event::After(0, 0x0100, Instruction::MovImm8(Register::AL, 0x42)),
+    ]);
+}
+
+#[test]
+fn test_observer_sees_32bit_register_write() {
+    // MOV EAX, 0x12345678
+    let mut machine = MachineBuilder::new()
+        .program([0x66, 0xB8, 0x78, 0x56, 0x34, 0x12])
+        .cpu_model(CpuModel::I80386)
+        .build()
+        .unwrap();
+    let events = record(&mut machine);
+
+    machine.step().unwrap();
+
+    assert_eq!(*events.borrow(), vec![
+        Event::Before(0, 0x0100, Instruction::MovImm32(Register::EAX, 0x12345678)),
+        Event::Register(Register::IP, 0x0100 + 6),
+        Event::Register32(Register::EAX, 0x12345678),
+        Event::After(0, 0x0100, Instruction::MovImm32(Register::EAX, 0x12345678)),
+    ]);
+}
+
+#[test]
+fn test_observer_sees_memory_accesses() {
+    // MOV [0x0200], AX
+    // MOV BX, [0x0200]
+    let mut machine = MachineBuilder::new()
+        .program([0xA3, 0x00, 0x02, 0x8B, 0x1E, 0x00, 0x02])
+        .register(Register::AX, 0xBEEF)
+        .build()
+        .unwrap();
+    let events = record(&mut machine);
+
+    machine.step().unwrap();
+    machine.step().unwrap();
+
+    let accesses: Vec<_> = events
+        .borrow_mut()
+        .drain(..)
+        .filter(|event| matches!(event, Event::Read(..) | Event::Write(..)))
+        .collect();
+    assert_eq!(accesses, vec![
+        Event::Write(0x200, 0xEF),
+        Event::Write(0x201, 0xBE),
+        Event::Read(0x200, 0xEF),
+        Event::Read(0x201, 0xBE),
+    ]);
+}
+
+#[test]
+fn test_observer_sees_interrupt() {
+    // INT 0x21, handler at 0x0000:0x0200
+    let mut machine = MachineBuilder::new()
+        .program([0xCD, 0x21])
+        .load_address(0x0010, 0x0000)
+        .build()
+        .unwrap();
+    machine.memory_mut().write_word(0x21 * 4, 0x0200);
+    let events = record(&mut machine);
+
+    machine.step().unwrap();
+
+    // the observer hears about the interrupt once its handler was looked up
+    let events = events.borrow();
+    let position = |event: Event| events.iter().position(|recorded| *recorded == event).unwrap();
+    assert!(position(Event::Read(0x84, 0x00)) < position(Event::Interrupt(0x21)));
+    assert!(position(Event::Read(0x85, 0x02)) < position(Event::Interrupt(0x21)));
+}
+
+#[test]
+fn test_observer_after_instruction_skipped_on_error() {
+    // INT 0x80 without handler
+    let mut machine = MachineBuilder::new()
+        .program([0xCD, 0x80])
+        .load_address(0x0010, 0x0000)
+        .build()
+        .unwrap();
+    let events = record(&mut machine);
+
+    assert!(machine.step().is_err());
+
+    assert!(!events.borrow().contains(&Event::Interrupt(0x80)));
+    assert!(!events.borrow().iter().any(|event| matches!(event, Event::After(..))));
+}
+
+#[test]
+fn test_multiple_observers() {
+    let mut machine = MachineBuilder::new().program([0x90]).build().unwrap();
+    let first = record(&mut machine);
+    let second = record(&mut machine);
+
+    machine.step().unwrap();
+
+    assert_eq!(first.borrow().len(), 3);
+    assert_eq!(*first.borrow(), *second.borrow());
+}
@@ -1,3 +1,4 @@
+use nvm::error::DecodeError;
 use nvm::register::Register;
 
 #[test]
@@ -24,12 +25,29 @@ fn test_16bit_register_from_code() {
     assert_eq!(Register::from_register_code(7, false), Ok(Register::DI));
 }
 
+#[test]
+fn test_32bit_register_conversion() {
+    assert_eq!(Register::AX.to_32bit(), Register::EAX);
+    assert_eq!(Register::SP.to_32bit(), Register::ESP);
+    assert_eq!(Register::EDI.to_16bit(), Register::DI);
+    assert_eq!(Register::EBX.to_16bit(), Register::BX);
+
+    // registers without a 32bit extension are returned as is
+    assert_eq!(Register::AL.to_32bit(), Register::AL);
+    assert_eq!(Register::DS.to_32bit(), Register::DS);
+    assert_eq!(Register::AX.to_16bit(), Register::AX);
+
+    assert!(Register::ECX.is_32bit());
+    assert!(!Register::CX.is_32bit());
+    assert!(!Register::ECX.is_8bit());
+}
+
 #[test]
 fn test_8bit_register_from_invalid_code() {
-    assert!(Register::from_register_code(0x0F, true).is_err());
+    assert_eq!(Register::from_register_code(0x0F, true), Err(DecodeError::InvalidRegisterCode(0x0F)));
 }
 
 #[test]
 fn test_16bit_register_from_invalid_code() {
-    assert!(Register::from_register_code(0xFF, false).is_err());
+    assert_eq!(Register::from_register_code(0xFF, false), Err(DecodeError::InvalidRegisterCode(0xFF)));
 }
@@ -1,40 +1,44 @@
 use std::env;
-use std::fs::File;
-use std::io::BufReader;
-use nvm::machine::Machine;
+use std::fs;
+use std::process;
+use nvm::machine::StopReason;
+use nvm::observer::StdoutTracer;
+use nvm::MachineBuilder;
 
 #[cfg(not(tarpaulin_include))]
 fn main() {
-    let args: Vec<_> = env::args().collect();
+    let mut args: Vec<_> = env::args().collect();
+    let trace = args.iter().any(|arg| arg == "--trace");
+    args.retain(|arg| arg != "--trace");
     if args.len() < 2 {
-        panic!("Usage: nvm <file>");
+        panic!("Usage: nvm [--trace] <file> [max steps]");
     }
 
     let path = &args[1];
-    let file = File::open(path).expect("File not found");
-    let buffer = BufReader::new(file);
+    let program = fs::read(path).expect("File not found");
+    let max_steps = args.get(2).map(|steps| steps.parse::<usize>().expect("Invalid step count"));
 
-    let mut machine = Machine::default();
-    machine.load_program(buffer);
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.step();
-    machine.dump_self();
+    let mut machine = match MachineBuilder::new().program(program).build() {
+        Ok(machine) => machine,
+        Err(err) => {
+            eprintln!("Cannot load {}: {}", path, err);
+            process::exit(1);
+        }
+    };
+
+    if trace {
+        machine.attach_observer(Box::new(StdoutTracer));
+    }
+
+    // without a step limit the program runs until it executes HLT
+    let reason = match max_steps {
+        Some(steps) => machine.run_for(steps),
+        None => machine.run(),
+    };
+    println!("{}", machine);
+
+    if reason != StopReason::Halted {
+        eprintln!("Machine stopped: {}", reason);
+        process::exit(1);
+    }
 }
@@ -12,3 +12,4 @@ end:
     INC AX
     MOV [0x1000], AX  ; ulož výsledek do paměti
     NOP
+    HLT               ; zastav stroj